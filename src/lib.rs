@@ -21,14 +21,12 @@
 //
 // *****************************************************************************
 
-#![feature(box_syntax)]
-
 extern crate byteorder;
 extern crate regex;
 extern crate encoding;
 extern crate walkdir;
 extern crate elementtree;
-extern crate failure;
+#[macro_use] extern crate failure;
 extern crate lazy_static;
 extern crate charon_parsers;
 
@@ -21,25 +21,4015 @@
 //
 // *****************************************************************************
 
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::rc::Rc;
+use failure::Error;
+
 use st::ast::*;
 use st::runtime::*;
+use st::symbols::*;
+use st::Diagnostic;
+
+/// Size of a reference (used for VAR_IN_OUT) in task memory.
+const PTR_SIZE: usize = 8;
+/// Type of addresses at runtime.
+const PTR_TYPE: ValType = ValType::ULInt;
+/// Size of the class id at the start of function block instances that
+/// take part in dynamic dispatch.
+const CLASS_SIZE: usize = 4;
+/// Time literals are given in nanoseconds, TIME and TIME_OF_DAY values are in
+/// milliseconds.
+const NS_PER_MS: i64 = 1_000_000;
+
+/// A resolved data type.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Ty {
+    Bool,
+    SInt,
+    Int,
+    DInt,
+    LInt,
+    USInt,
+    UInt,
+    UDInt,
+    ULInt,
+    Byte,
+    Word,
+    DWord,
+    LWord,
+    Real,
+    LReal,
+    Time,
+    LTime,
+    Date,
+    Tod,
+    Dt,
+    String(usize),
+    WString(usize),
+    Array(Box<Ty>, i64, i64),
+    /// Structs and unions.
+    Struct(String),
+    FBlock(String),
+    /// Interfaces, stored as the address of the implementing instance.
+    Interface(String),
+    /// References, which are dereferenced implicitly.
+    Ref(Box<Ty>),
+    Pointer(Box<Ty>),
+    /// Subranges of integer types, with their bounds.  Values loaded from
+    /// them have the base type.
+    Subrange(Box<Ty>, i64, i64),
+}
+
+impl Ty {
+    fn elementary(name: &str) -> Option<Ty> {
+        Some(match &name.to_ascii_uppercase()[..] {
+            "BOOL" => Ty::Bool,
+            "SINT" => Ty::SInt,
+            "INT" => Ty::Int,
+            "DINT" => Ty::DInt,
+            "LINT" => Ty::LInt,
+            "USINT" => Ty::USInt,
+            "UINT" => Ty::UInt,
+            "UDINT" => Ty::UDInt,
+            "ULINT" => Ty::ULInt,
+            "BYTE" => Ty::Byte,
+            "WORD" => Ty::Word,
+            "DWORD" => Ty::DWord,
+            "LWORD" => Ty::LWord,
+            "REAL" => Ty::Real,
+            "LREAL" => Ty::LReal,
+            "TIME" => Ty::Time,
+            "LTIME" => Ty::LTime,
+            "DATE" => Ty::Date,
+            "TIME_OF_DAY" | "TOD" => Ty::Tod,
+            "DATE_AND_TIME" | "DT" => Ty::Dt,
+            _ => return None
+        })
+    }
+
+    fn is_compound(&self) -> bool {
+        matches!(*self, Ty::Array(..) | Ty::Struct(_) | Ty::FBlock(_))
+    }
+
+    /// Runtime type of an elementary type.
+    fn elementary_val_type(&self) -> Option<ValType> {
+        Some(match *self {
+            Ty::Bool => ValType::Bool,
+            Ty::SInt => ValType::SInt,
+            Ty::Int => ValType::Int,
+            Ty::DInt => ValType::DInt,
+            Ty::LInt => ValType::LInt,
+            Ty::USInt => ValType::USInt,
+            Ty::UInt => ValType::UInt,
+            Ty::UDInt => ValType::UDInt,
+            Ty::ULInt => ValType::ULInt,
+            Ty::Byte => ValType::Byte,
+            Ty::Word => ValType::Word,
+            Ty::DWord => ValType::DWord,
+            Ty::LWord => ValType::LWord,
+            Ty::Real => ValType::Real,
+            Ty::LReal => ValType::LReal,
+            Ty::Time => ValType::Time,
+            Ty::LTime => ValType::LTime,
+            Ty::Date => ValType::Date,
+            Ty::Tod => ValType::Tod,
+            Ty::Dt => ValType::Dt,
+            Ty::String(len) => ValType::String(len),
+            Ty::WString(len) => ValType::WString(len),
+            Ty::Ref(_) | Ty::Pointer(_) | Ty::Interface(_) => PTR_TYPE,
+            Ty::Subrange(ref base, ..) => return base.elementary_val_type(),
+            _ => return None
+        })
+    }
+
+    /// The type of values loaded from a location of this type.
+    fn value_type(self) -> Ty {
+        match self {
+            Ty::Subrange(base, ..) => *base,
+            ty => ty,
+        }
+    }
+
+    fn from_val_type(typ: ValType) -> Ty {
+        match typ {
+            ValType::Bool => Ty::Bool,
+            ValType::SInt => Ty::SInt,
+            ValType::Int => Ty::Int,
+            ValType::DInt => Ty::DInt,
+            ValType::LInt => Ty::LInt,
+            ValType::USInt => Ty::USInt,
+            ValType::UInt => Ty::UInt,
+            ValType::UDInt => Ty::UDInt,
+            ValType::ULInt => Ty::ULInt,
+            ValType::Byte => Ty::Byte,
+            ValType::Word => Ty::Word,
+            ValType::DWord => Ty::DWord,
+            ValType::LWord => Ty::LWord,
+            ValType::Real => Ty::Real,
+            ValType::LReal => Ty::LReal,
+            ValType::Time => Ty::Time,
+            ValType::LTime => Ty::LTime,
+            ValType::Date => Ty::Date,
+            ValType::Tod => Ty::Tod,
+            ValType::Dt => Ty::Dt,
+            ValType::String(len) => Ty::String(len),
+            ValType::WString(len) => Ty::WString(len),
+            ValType::Raw(_) => panic!("no type for raw values"),
+        }
+    }
+}
+
+/// Determine the type both operands of a binary operation are converted to.
+fn common_type(a: &Ty, b: &Ty) -> Option<Ty> {
+    if a == b {
+        return Some(a.clone());
+    }
+    let (ta, tb) = match (a.elementary_val_type(), b.elementary_val_type()) {
+        (Some(ta), Some(tb)) => (ta, tb),
+        _ => return None,
+    };
+    let res = match (ta, tb) {
+        (ValType::String(m), ValType::String(n)) => ValType::String(m.max(n)),
+        (ValType::WString(m), ValType::WString(n)) => ValType::WString(m.max(n)),
+        (ValType::Time, ValType::LTime) | (ValType::LTime, ValType::Time) => ValType::LTime,
+        (ta, tb) if ta.is_time() && (tb.is_integer() || tb.is_time()) => ta,
+        (ta, tb) if tb.is_time() && ta.is_integer() => tb,
+        (ta, tb) if ta.is_float() || tb.is_float() => {
+            if ta == ValType::LReal || tb == ValType::LReal { ValType::LReal } else { ValType::Real }
+        }
+        (ta, tb) if ta.is_integer() && tb.is_integer() => {
+            let (sa, sb) = (ta.size(), tb.size());
+            if ta.is_signed() == tb.is_signed() {
+                if sa >= sb { ta } else { tb }
+            } else {
+                let (signed, unsigned) = if ta.is_signed() { (ta, tb) } else { (tb, ta) };
+                if signed.size() > unsigned.size() {
+                    signed
+                } else {
+                    match unsigned.size() {
+                        1 => ValType::Int,
+                        2 => ValType::DInt,
+                        _ => ValType::LInt,
+                    }
+                }
+            }
+        }
+        _ => return None
+    };
+    Some(Ty::from_val_type(res))
+}
+
+/// A name as a map key.  Like all identifiers, it is compared
+/// case-insensitively, but keeps its spelling for display.
+#[derive(Clone, Copy, Debug)]
+struct Name<S>(S);
+
+impl<S: AsRef<str>> PartialEq for Name<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_ref().eq_ignore_ascii_case(other.0.as_ref())
+    }
+}
+
+impl<S: AsRef<str>> Eq for Name<S> {}
+
+impl<S: AsRef<str>> Hash for Name<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for b in self.0.as_ref().bytes() {
+            state.write_u8(b.to_ascii_uppercase());
+        }
+        state.write_u8(0xff);
+    }
+}
+
+impl<S: AsRef<str>> PartialOrd for Name<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: AsRef<str>> Ord for Name<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.as_ref().to_ascii_uppercase().cmp(&other.0.as_ref().to_ascii_uppercase())
+    }
+}
+
+/// Key of a method or property accessor: the class (or interface) and the
+/// method name.
+type MethodKey<'a> = (Name<&'a str>, Name<String>);
+
+/// Memory ranges, as start and length.
+type Ranges = Vec<(usize, usize)>;
+
+/// Memory layout of a structured type or POU instance.
+struct Layout<'a> {
+    size: usize,
+    align: usize,
+    fields: Vec<Field<'a>>,
+}
+
+#[derive(Clone)]
+struct Field<'a> {
+    name: &'a str,
+    offset: usize,
+    ty: Ty,
+    default: Option<&'a Expr>,
+    /// True for VAR_INPUT variables.
+    input: bool,
+    flags: VarFlags,
+}
+
+/// A field to be added to a layout: name, type, default, if it is an input
+/// and the modifiers of its section.
+type FieldDef<'a> = (&'a str, Ty, Option<&'a Expr>, bool, VarFlags);
+
+/// Key of a global variable or global variable list: the namespace of the
+/// library defining it, if any, and its name.
+type GlobalKey<'a> = (Option<Name<&'a str>>, Name<&'a str>);
+
+/// A global or VAR_STAT variable, which has a fixed address.
+#[derive(Clone)]
+struct StaticVar<'a> {
+    name: &'a str,
+    offset: usize,
+    ty: Ty,
+    default: Option<&'a Expr>,
+    flags: VarFlags,
+    /// Source of the declaring POU, to locate errors in the default.
+    source: Option<&'a Source>,
+}
+
+impl<'a> Layout<'a> {
+    fn new() -> Self {
+        Layout { size: 0, align: 1, fields: vec![] }
+    }
+
+    fn field(&self, name: &str) -> Option<&Field<'a>> {
+        self.fields.iter().find(|f| f.name.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Where a value lives in task memory.
+#[derive(Clone, Copy, Debug)]
+enum Place {
+    /// Absolute offset in task memory.
+    Abs(usize),
+    /// Offset relative to the base of the current instance.
+    Rel(usize),
+    /// Offset relative to an address pushed onto the stack.
+    Ind(usize),
+    /// Offset relative to an address stored at an absolute offset.
+    Deref(usize, usize),
+}
+
+impl Place {
+    fn add(self, n: usize) -> Place {
+        match self {
+            Place::Abs(o) => Place::Abs(o + n),
+            Place::Rel(o) => Place::Rel(o + n),
+            Place::Ind(o) => Place::Ind(o + n),
+            Place::Deref(p, o) => Place::Deref(p, o + n),
+        }
+    }
+}
+
+/// A resolved assignable expression.
+struct LValue {
+    place: Place,
+    /// For bit accesses, this is the type of the containing variable.
+    ty: Ty,
+    bit: Option<usize>,
+}
+
+impl LValue {
+    fn new(place: Place, ty: Ty) -> Self {
+        LValue { place, ty, bit: None }
+    }
+
+    fn value_ty(&self) -> Ty {
+        if self.bit.is_some() { Ty::Bool } else { self.ty.clone() }
+    }
+}
+
+/// The POU whose body is being compiled.
+struct Scope<'a> {
+    /// Name of the POU, also for its methods and actions.
+    name: &'a str,
+    layout: Rc<Layout<'a>>,
+    /// Static frame address, None for function blocks.
+    frame: Option<usize>,
+    /// For methods: layout of the function block instance, whose variables
+    /// are accessed relative to the base.
+    instance: Option<Rc<Layout<'a>>>,
+}
+
+/// State while compiling a single function body.
+struct Context<'a> {
+    code: Vec<Instr>,
+    scope: Scope<'a>,
+    /// Jumps out of the enclosing loops, innermost last.
+    loops: Vec<LoopJumps>,
+    /// Jumps emitted by RETURN, to be patched when the body is done.
+    returns: Vec<usize>,
+    /// Source of the POU the code comes from, to locate errors.
+    source: Option<&'a Source>,
+}
+
+/// Jumps emitted by EXIT and CONTINUE, to be patched when the loop is done.
+#[derive(Default)]
+struct LoopJumps {
+    exits: Vec<usize>,
+    continues: Vec<usize>,
+}
+
+/// A method or property accessor of a function block, or the prototype of
+/// one in an interface.
+#[derive(Clone)]
+struct MethodImpl<'a> {
+    /// Not used for interface methods.
+    func: Func,
+    /// The class or interface that first declared the method; callers pass
+    /// arguments in a frame shared by all methods implementing that
+    /// declaration.
+    root: &'a str,
+    /// Static frame of this implementation.
+    frame: usize,
+    /// Return value first, then parameters, then locals.
+    layout: Rc<Layout<'a>>,
+    returns: bool,
+    /// Number of fields that make up the signature.
+    params: usize,
+}
+
+/// A property access: the expression for the instance (None for the current
+/// one), the function block or interface type and the property name.
+type PropertyRef<'a> = (Option<&'a Expr>, Ty, &'a str);
+
+/// Something with a body that is compiled into a function.
+enum Body<'a> {
+    /// Program, function or function block.
+    Pou(&'a str, &'a [Stmt]),
+    Method(&'a str, String, &'a [VarBlock], &'a [Stmt]),
+    Action(&'a str, &'a Action),
+}
+
+struct Compiler<'a> {
+    /// POUs by name, which is qualified with the namespace for POUs of
+    /// libraries (see `pou_key`).
+    pous: HashMap<Name<&'a str>, &'a POU>,
+    /// Qualified names of library POUs, by their unqualified name.
+    library_pous: HashMap<Name<&'a str>, Vec<&'a str>>,
+    layouts: HashMap<Name<&'a str>, Rc<Layout<'a>>>,
+    /// Global variables that can be accessed unqualified.
+    globals: HashMap<GlobalKey<'a>, StaticVar<'a>>,
+    /// Variables of named global variable lists.
+    gvls: HashMap<GlobalKey<'a>, Vec<StaticVar<'a>>>,
+    /// VAR_STAT variables, by POU.
+    statics: HashMap<Name<&'a str>, Vec<StaticVar<'a>>>,
+    /// All global and VAR_STAT variables, in order of allocation.
+    static_vars: Vec<StaticVar<'a>>,
+    frames: HashMap<Name<&'a str>, usize>,
+    funcs: HashMap<Name<&'a str>, Func>,
+    vars: Vec<VarAlloc>,
+    var_index: HashMap<(usize, ValType), Var>,
+    var_names: HashMap<Var, String>,
+    memsize: usize,
+    /// Ids of function blocks that need dynamic dispatch, stored at the
+    /// start of each of their instances.
+    classes: HashMap<Name<&'a str>, u32>,
+    /// Methods and property accessors, by class (or interface) and name.
+    methods: HashMap<MethodKey<'a>, MethodImpl<'a>>,
+    /// Frames for passing arguments to methods, by root class (or interface)
+    /// and name.
+    method_frames: HashMap<MethodKey<'a>, usize>,
+    /// Numbers of method names, used for dynamic dispatch.
+    slots: HashMap<Name<String>, usize>,
+    /// Actions of programs and function blocks.
+    actions: HashMap<(Name<&'a str>, Name<&'a str>), Func>,
+    /// Namespaces of the referenced libraries, which can qualify names.
+    namespaces: HashSet<Name<&'a str>>,
+    /// Types whose layout is being computed, outermost first.
+    laying_out: Vec<&'a str>,
+}
+
+/// Compile a whole project into a runtime program.
+pub fn compile_project(project: &Project) -> Result<Program, Error> {
+    let keys = project.pous.iter().map(pou_key).collect::<Vec<_>>();
+    let pous = keys.iter().map(|key| &key[..]).zip(&project.pous).collect::<Vec<_>>();
+    let mut c = Compiler {
+        pous: HashMap::new(),
+        library_pous: HashMap::new(),
+        layouts: HashMap::new(),
+        globals: HashMap::new(),
+        gvls: HashMap::new(),
+        statics: HashMap::new(),
+        static_vars: vec![],
+        frames: HashMap::new(),
+        funcs: HashMap::new(),
+        vars: vec![],
+        var_index: HashMap::new(),
+        var_names: HashMap::new(),
+        // offset 0 is reserved for null pointers
+        memsize: PTR_SIZE,
+        classes: HashMap::new(),
+        methods: HashMap::new(),
+        method_frames: HashMap::new(),
+        slots: HashMap::new(),
+        actions: HashMap::new(),
+        namespaces: project.libraries.iter().map(|lib| Name(&lib.namespace[..])).collect(),
+        laying_out: vec![],
+    };
+    for &(key, pou) in &pous {
+        if let POUType::Globals { .. } = pou.kind {
+            continue;
+        }
+        if c.pous.insert(Name(key), pou).is_some() {
+            bail!("Duplicate definition of {}", key);
+        }
+        if pou.namespace.is_some() {
+            c.library_pous.entry(Name(&pou.name)).or_default().push(key);
+        }
+    }
+
+    // function blocks with methods or inheritance, and all their bases, get
+    // a class id for dynamic dispatch
+    for &(key, pou) in &pous {
+        if let POUType::FBlock { ref extends, ref implements, ref methods,
+                                 ref properties, .. } = pou.kind {
+            if extends.is_some() || !implements.is_empty() || !methods.is_empty() ||
+                !properties.is_empty()
+            {
+                for class in c.ancestors(key)? {
+                    let id = c.classes.len() as u32 + 1;
+                    c.classes.entry(Name(class)).or_insert(id);
+                }
+            }
+        }
+    }
+
+    // allocate global variables
+    for &(key, pou) in &pous {
+        if let POUType::Globals { ref vars, .. } = pou.kind {
+            let qualified_only = find_attribute(&pou.attrs, "qualified_only").is_some();
+            let list = c.alloc_statics(key, pou, vars, VarType::Global)?;
+            let namespace = pou.namespace.as_ref().map(|ns| Name(&ns[..]));
+            for var in &list {
+                // variables of qualified_only lists can only be accessed
+                // through the list's name
+                if !qualified_only &&
+                    c.globals.insert((namespace, Name(var.name)), var.clone()).is_some()
+                {
+                    bail!("Duplicate definition of global variable {}", var.name);
+                }
+            }
+            if !pou.name.is_empty() {
+                if c.gvls.insert((namespace, Name(&pou.name)), list).is_some() {
+                    bail!("Duplicate definition of global variable list {}", key);
+                }
+            } else if qualified_only {
+                bail!("Unnamed global variable list cannot be qualified_only");
+            }
+        }
+    }
+
+    // allocate VAR_STAT variables, and check that VAR_EXTERNAL variables
+    // match a global variable
+    for &(key, pou) in &pous {
+        match pou.kind {
+            POUType::Program { ref vars, .. } |
+            POUType::FBlock { ref vars, .. } |
+            POUType::Function { ref vars, .. } => {
+                let list = c.alloc_statics(key, pou, vars, VarType::Static)?;
+                c.statics.insert(Name(key), list);
+            }
+            _ => {}
+        }
+        for blocks in var_blocks(&pou.kind) {
+            c.check_externals(blocks)?;
+        }
+    }
+
+    // allocate static frames for programs and functions, and assign function
+    // numbers to everything that has a body
+    let mut bodies = vec![];
+    for &(key, pou) in &pous {
+        match pou.kind {
+            POUType::Program { ref body, .. } |
+            POUType::Function { ref body, .. } => {
+                let layout = c.layout(key)?;
+                let frame = c.alloc(layout.size, layout.align);
+                for field in &layout.fields {
+                    c.name_var(frame + field.offset, &field.ty,
+                               format!("{}.{}", key, field.name))?;
+                }
+                c.frames.insert(Name(key), frame);
+                c.funcs.insert(Name(key), bodies.len());
+                bodies.push(Body::Pou(key, body));
+            }
+            POUType::FBlock { ref body, ref methods, ref properties, .. } => {
+                c.funcs.insert(Name(key), bodies.len());
+                bodies.push(Body::Pou(key, body));
+                c.add_methods(key, methods, properties, Some(&mut bodies))?;
+            }
+            POUType::Interface { ref methods, ref properties, .. } => {
+                c.interface_ancestors(key)?;
+                c.add_methods(key, methods, properties, None)?;
+            }
+            _ => continue,
+        }
+        match pou.kind {
+            POUType::Program { ref actions, .. } | POUType::FBlock { ref actions, .. } => {
+                for action in actions {
+                    if c.actions.insert((Name(key), Name(&action.name)), bodies.len()).is_some() {
+                        bail!("Duplicate definition of action {}.{}", key, action.name);
+                    }
+                    bodies.push(Body::Action(key, action));
+                }
+            }
+            _ => {}
+        }
+    }
+    c.allocate_method_frames()?;
+    c.check_interfaces()?;
+
+    let mut functions = vec![];
+    let mut func_names = HashMap::new();
+    let mut programs = vec![];
+    for body in bodies {
+        let mut method = None;
+        let owner = match body {
+            Body::Pou(name, _) | Body::Method(name, ..) | Body::Action(name, _) => name,
+        };
+        let (name, scope, stmts, locals) = match body {
+            Body::Pou(name, stmts) => {
+                let locals = match c.pous[&Name(name)].kind {
+                    POUType::Function { ref vars, .. } => Some((&vars[..], false)),
+                    POUType::FBlock { ref vars, .. } => Some((&vars[..], true)),
+                    POUType::Program { ref vars, .. } => {
+                        programs.push(functions.len());
+                        Some((&vars[..], true))
+                    }
+                    _ => None,
+                };
+                (name.to_string(), c.pou_scope(name)?, stmts, locals)
+            }
+            Body::Method(class, name, vars, stmts) => {
+                let imp = c.methods[&(Name(class), Name(name.clone()))].clone();
+                let shared = c.method_frames[&(Name(imp.root), Name(name.clone()))];
+                let scope = Scope {
+                    name: class,
+                    layout: imp.layout.clone(),
+                    frame: Some(imp.frame),
+                    instance: Some(c.layout(class)?),
+                };
+                method = Some((imp, shared));
+                (format!("{}.{}", class, name), scope, stmts, Some((vars, false)))
+            }
+            Body::Action(pou, action) =>
+                (format!("{}.{}", pou, action.name), c.pou_scope(pou)?, &action.body[..], None),
+        };
+        let mut ctx = Context::new(scope);
+        ctx.source = c.pous[&Name(owner)].source.as_ref();
+        if let Some((ref imp, shared)) = method {
+            c.copy_params(&mut ctx, imp, shared, true)?;
+        }
+        if let Some((vars, temps_only)) = locals {
+            c.init_locals(&mut ctx, vars, temps_only)?;
+        }
+        c.compile_body(&mut ctx, stmts)?;
+        ctx.patch_returns();
+        if let Some((ref imp, shared)) = method {
+            c.copy_params(&mut ctx, imp, shared, false)?;
+        }
+        ctx.emit(Instr::Return);
+        func_names.insert(functions.len(), name);
+        functions.push(Function { code: ctx.code, frame: ctx.scope.frame.unwrap_or(0) });
+    }
+
+    // the implementation of each method, as seen from each class
+    let mut methods = HashMap::new();
+    for (&Name(class), &id) in &c.classes {
+        for base in c.ancestors(class)? {
+            for (&(Name(owner), ref name), method) in &c.methods {
+                if owner == base {
+                    methods.entry((id, c.slots[name])).or_insert(method.func);
+                }
+            }
+        }
+    }
+
+    check_recursion(&functions, &func_names, &methods)?;
+
+    let init = functions.len();
+    let code = c.compile_init(&pous)?;
+    func_names.insert(init, "__INIT".into());
+    functions.push(Function { code, frame: 0 });
+
+    let symbols = c.symbols()?;
+    let (retain, persistent) = c.retained(&pous)?;
 
-struct Context {
-    blocks: Vec<Block>,
+    Ok(Program {
+        vars: c.vars,
+        functions,
+        var_names: c.var_names,
+        func_names,
+        init,
+        programs,
+        symbols,
+        methods,
+        retain,
+        persistent,
+        memsize: c.memsize,
+    })
+}
+
+/// Reject recursive calls of functions and methods, since their variables and
+/// arguments live in a single static frame that a nested call would clobber.
+///
+/// Dynamic method calls are assumed to reach every implementation.
+fn check_recursion(functions: &[Function], names: &HashMap<Func, String>,
+                   methods: &HashMap<(u32, usize), Func>) -> Result<(), Error> {
+    let callees = functions.iter().map(|function| {
+        let mut callees = vec![];
+        for instr in &function.code {
+            match *instr {
+                Instr::Call(func) | Instr::CallInst(func) => callees.push(func),
+                Instr::CallMethod(slot) => callees.extend(
+                    methods.iter().filter(|m| (m.0).1 == slot).map(|m| *m.1)),
+                _ => {}
+            }
+        }
+        callees
+    }).collect::<Vec<_>>();
+    for (start, function) in functions.iter().enumerate() {
+        if function.frame == 0 {
+            continue;
+        }
+        // depth-first search for a path back to the start
+        let mut path = vec![(start, 0)];
+        let mut seen = HashSet::new();
+        while let Some(&mut (func, ref mut next)) = path.last_mut() {
+            match callees[func].get(*next) {
+                None => { path.pop(); }
+                Some(&callee) => {
+                    *next += 1;
+                    if callee == start {
+                        let cycle = path.iter().map(|&(f, _)| &names[&f][..])
+                                               .chain(Some(&names[&start][..]))
+                                               .collect::<Vec<_>>();
+                        bail!("Recursive call of {} is not supported: {}",
+                              names[&start], cycle.join(" -> "));
+                    }
+                    if seen.insert(callee) {
+                        path.push((callee, 0));
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
 }
 
-struct Block {
-    
+impl<'a> Context<'a> {
+    fn new(scope: Scope<'a>) -> Self {
+        Context { code: vec![], scope, loops: vec![], returns: vec![], source: None }
+    }
+
+    fn emit(&mut self, instr: Instr) -> usize {
+        self.code.push(instr);
+        self.code.len() - 1
+    }
+
+    fn here(&self) -> usize {
+        self.code.len()
+    }
+
+    /// Set the target of a previously emitted jump instruction.
+    fn patch(&mut self, at: usize, target: usize) {
+        self.code[at] = match self.code[at] {
+            Instr::Jump(_) => Instr::Jump(target),
+            Instr::JumpIf(_) => Instr::JumpIf(target),
+            Instr::JumpIfNot(_) => Instr::JumpIfNot(target),
+            _ => panic!("patching a non-jump instruction")
+        };
+    }
+
+    /// Set the target of the RETURN jumps to the current position.
+    fn patch_returns(&mut self) {
+        let here = self.here();
+        for jump in mem::take(&mut self.returns) {
+            self.patch(jump, here);
+        }
+    }
+
+    /// Set the targets of the EXIT and CONTINUE jumps of a loop.
+    fn patch_loop(&mut self, jumps: LoopJumps, next: usize, end: usize) {
+        for jump in jumps.continues {
+            self.patch(jump, next);
+        }
+        for jump in jumps.exits {
+            self.patch(jump, end);
+        }
+    }
 }
 
-fn compile_body(stmts: &[Stmt]) {
-    let mut code = vec![];
-    for stmt in stmts {
-        match stmt {
-            Stmt::Empty => {},
-            Stmt::Exit => {
-                
+impl<'a> Compiler<'a> {
+    /// Reserve memory in the task image.
+    fn alloc(&mut self, size: usize, align: usize) -> usize {
+        let offset = align_up(self.memsize, align);
+        self.memsize = offset + size;
+        offset
+    }
+
+    /// Get the variable allocation for a memory location.
+    fn var(&mut self, offset: usize, typ: ValType) -> Var {
+        let vars = &mut self.vars;
+        *self.var_index.entry((offset, typ)).or_insert_with(|| {
+            vars.push(VarAlloc { offset, typ });
+            vars.len() - 1
+        })
+    }
+
+    fn name_var(&mut self, offset: usize, ty: &Ty, name: String) -> Result<(), Error> {
+        let typ = self.val_type(ty)?;
+        let var = self.var(offset, typ);
+        self.var_names.entry(var).or_insert(name);
+        Ok(())
+    }
+
+    /// Runtime type of values of the given type.
+    fn val_type(&mut self, ty: &Ty) -> Result<ValType, Error> {
+        match ty.elementary_val_type() {
+            Some(typ) => Ok(typ),
+            None => Ok(ValType::Raw(self.size_align(ty)?.0)),
+        }
+    }
+
+    fn resolve_type(&self, typ: &Type) -> Result<Ty, Error> {
+        Ok(match *typ {
+            Type::String(len) => Ty::String(len),
+            Type::WString(len) => Ty::WString(len),
+            Type::Array(ref inner, ref dims) => {
+                // multi-dimensional arrays are arrays of arrays
+                let mut ty = self.resolve_type(inner)?;
+                for &(lo, hi) in dims.iter().rev() {
+                    if hi < lo {
+                        bail!("Invalid array bounds {}..{}", lo, hi);
+                    }
+                    ty = Ty::Array(Box::new(ty), lo, hi);
+                }
+                ty
+            }
+            Type::Pointer(ref inner) => Ty::Pointer(Box::new(self.resolve_type(inner)?)),
+            Type::Reference(ref inner) => Ty::Ref(Box::new(self.resolve_type(inner)?)),
+            Type::Subrange(ref base, lo, hi) => {
+                let ty = self.resolve_type(base)?;
+                if !ty.elementary_val_type().is_some_and(|t| t.is_integer()) || hi < lo {
+                    bail!("Invalid subrange type {:?}({}..{})", ty, lo, hi);
+                }
+                Ty::Subrange(Box::new(ty.value_type()), lo, hi)
+            }
+            Type::Simple(ref name) => {
+                if let Some(ty) = Ty::elementary(name) {
+                    return Ok(ty);
+                }
+                // types are named as declared, to compare equal however
+                // they are spelled
+                let (key, pou) = match self.find_pou(name)? {
+                    Some(pou) => pou,
+                    None => bail!("Unknown type {}", name),
+                };
+                match pou.kind {
+                    POUType::Struct { .. } |
+                    POUType::Union { .. } => Ty::Struct(key.into()),
+                    POUType::FBlock { .. } => Ty::FBlock(key.into()),
+                    POUType::Interface { .. } => Ty::Interface(key.into()),
+                    POUType::Typedef { ref alias } => self.resolve_type(alias)?,
+                    POUType::Enum { ref base, .. } => self.enum_base(key, base)?,
+                    _ => bail!("Unknown type {}", name)
+                }
+            }
+        })
+    }
+
+    /// Get the type that values of an enum are stored as.
+    fn enum_base(&self, name: &str, base: &Option<Type>) -> Result<Ty, Error> {
+        let ty = match *base {
+            Some(ref base) => self.resolve_type(base)?,
+            None => Ty::Int,
+        };
+        match ty.elementary_val_type() {
+            Some(ref typ) if typ.is_integer() => Ok(ty),
+            _ => bail!("Base type of enum {} must be an integer type", name),
+        }
+    }
+
+    /// Resolve an enum member, given as `E.Member` or just `Member`.
+    fn enum_value(&self, ctx: &Context<'a>, expr: &Expr) -> Result<Option<(Value, Ty)>, Error> {
+        let (qual, name) = match expr.kind {
+            ExprKind::Member(ref base, ref name) => match base.kind {
+                ExprKind::Name(ref qual) => (Some(&qual[..]), &name[..]),
+                _ => return Ok(None),
+            },
+            ExprKind::Name(ref name) => (None, &name[..]),
+            _ => return Ok(None),
+        };
+        if self.lookup(ctx, qual.unwrap_or(name)).is_some() {
+            return Ok(None);
+        }
+        self.enum_member(qual, name)
+    }
+
+    /// Find an enum member, optionally qualified by the enum type.
+    fn enum_member(&self, qual: Option<&str>, name: &str) -> Result<Option<(Value, Ty)>, Error> {
+        let mut found = None;
+        for pou in self.pous.values() {
+            if let POUType::Enum { ref members, ref base } = pou.kind {
+                if qual.is_some_and(|q| !q.eq_ignore_ascii_case(&pou.name)) {
+                    continue;
+                }
+                if qual.is_none() && find_attribute(&pou.attrs, "qualified_only").is_some() {
+                    continue;
+                }
+                match members.iter().find(|m| m.name.eq_ignore_ascii_case(name)) {
+                    Some(member) => {
+                        if found.is_some() {
+                            bail!("Enum member {} is ambiguous, qualify it with the type", name);
+                        }
+                        let ty = self.enum_base(&pou.name, base)?;
+                        let value = Value::from_int(ty.elementary_val_type().unwrap(),
+                                                    member.value as i128)?;
+                        found = Some((value, ty));
+                    }
+                    None if qual.is_some() => bail!("Enum {} has no member {}", pou.name, name),
+                    None => {}
+                }
+            }
+        }
+        Ok(found)
+    }
+
+    fn size_align(&mut self, ty: &Ty) -> Result<(usize, usize), Error> {
+        Ok(match *ty {
+            Ty::Bool | Ty::SInt | Ty::USInt | Ty::Byte => (1, 1),
+            Ty::Int | Ty::UInt | Ty::Word => (2, 2),
+            Ty::DInt | Ty::UDInt | Ty::DWord | Ty::Real |
+            Ty::Time | Ty::Date | Ty::Tod | Ty::Dt => (4, 4),
+            Ty::LInt | Ty::ULInt | Ty::LWord | Ty::LReal | Ty::LTime => (8, 8),
+            Ty::String(len) => (len + 1, 1),
+            Ty::WString(len) => (2 * (len + 1), 2),
+            Ty::Ref(_) | Ty::Pointer(_) | Ty::Interface(_) => (PTR_SIZE, PTR_SIZE),
+            Ty::Subrange(ref base, ..) => self.size_align(base)?,
+            Ty::Array(ref inner, lo, hi) => {
+                let (size, align) = self.size_align(inner)?;
+                (size * (hi - lo + 1) as usize, align)
+            }
+            Ty::Struct(ref name) | Ty::FBlock(ref name) => {
+                let layout = self.layout(name)?;
+                (layout.size, layout.align)
+            }
+        })
+    }
+
+    /// Compute (and cache) the memory layout of a struct or POU.
+    fn layout(&mut self, name: &str) -> Result<Rc<Layout<'a>>, Error> {
+        let (key, pou) = match self.find_pou(name)? {
+            Some(pou) => pou,
+            None => bail!("Unknown type {}", name),
+        };
+        if let Some(layout) = self.layouts.get(&Name(key)) {
+            return Ok(layout.clone());
+        }
+        // a type that contains itself, directly or indirectly, has no size
+        if let Some(i) = self.laying_out.iter().position(|t| t.eq_ignore_ascii_case(key)) {
+            let mut cycle = self.laying_out[i..].to_vec();
+            cycle.push(key);
+            bail!("Type {} contains itself: {}", key, cycle.join(" -> "));
+        }
+        self.laying_out.push(key);
+        let res = self.compute_layout(key, pou);
+        self.laying_out.pop();
+        let layout = Rc::new(res?);
+        self.layouts.insert(Name(key), layout.clone());
+        Ok(layout)
+    }
+
+    /// Compute the layout of a struct or POU, without caching.
+    fn compute_layout(&mut self, key: &'a str, pou: &'a POU) -> Result<Layout<'a>, Error> {
+        let mut layout = Layout::new();
+        let mut fields = vec![];
+        match pou.kind {
+            POUType::Struct { ref members } | POUType::Union { ref members } => {
+                for var in members {
+                    fields.push((&var.name[..], self.resolve_type(&var.typ)?,
+                                 var.default.as_ref(), false, VarFlags::default()));
+                }
+            }
+            POUType::Program { ref vars, .. } => {
+                fields.extend(self.block_fields(vars)?.into_iter().map(|f| f.1));
+            }
+            POUType::FBlock { ref vars, ref extends, .. } => {
+                // derived function blocks start with the fields of their base
+                if let Some(ref base) = *extends {
+                    self.ancestors(key)?;
+                    let base = self.layout(base)?;
+                    layout.size = base.size;
+                    layout.align = base.align;
+                    layout.fields = base.fields.clone();
+                } else if self.classes.contains_key(&Name(key)) {
+                    layout.size = CLASS_SIZE;
+                    layout.align = CLASS_SIZE;
+                }
+                fields.extend(self.block_fields(vars)?.into_iter().map(|f| f.1));
+                // VAR_INST variables of methods are stored in the instance
+                for blocks in var_blocks(&pou.kind) {
+                    for block in blocks.iter().filter(|b| b.0 == VarType::Inst) {
+                        for var in &block.1 {
+                            fields.push((&var.name[..], self.resolve_type(&var.typ)?,
+                                         var.default.as_ref(), false, block.2));
+                        }
+                    }
+                }
+            }
+            POUType::Function { ref rtype, ref vars, .. } => {
+                // the return value is named like the function, unqualified
+                fields.push((&pou.name[..], self.resolve_type(rtype)?, None, false,
+                             VarFlags::default()));
+                fields.extend(self.block_fields(vars)?.into_iter().map(|f| f.1));
+            }
+            _ => bail!("{} is not a structured type", key),
+        }
+        let is_union = matches!(pou.kind, POUType::Union { .. });
+        let pack = pack_mode(&pou.attrs)?;
+        self.add_fields(key, &mut layout, fields, is_union, pack)?;
+        Ok(layout)
+    }
+
+    /// Resolve the variables of a POU or method that are stored in its frame
+    /// or instance, also returning for each one if it is a local variable.
+    fn block_fields(&self, blocks: &'a [VarBlock]) -> Result<Vec<(bool, FieldDef<'a>)>, Error> {
+        let mut fields = vec![];
+        for block in blocks {
+            let (is_input, is_ref, is_local) = match block.0 {
+                VarType::In => (true, false, false),
+                VarType::InOut => (true, true, false),
+                VarType::Out => (false, false, false),
+                VarType::Local | VarType::Temp => (false, false, true),
+                // these are stored elsewhere
+                VarType::Static | VarType::Inst | VarType::External | VarType::Global => continue,
+            };
+            for var in &block.1 {
+                let mut ty = self.resolve_type(&var.typ)?;
+                if is_ref {
+                    ty = Ty::Ref(Box::new(ty));
+                }
+                fields.push((is_local, (&var.name[..], ty, var.default.as_ref(), is_input,
+                                        block.2)));
+            }
+        }
+        Ok(fields)
+    }
+
+    /// Allocate the variables of the sections of the given kind in task
+    /// memory, named after the given POU.
+    fn alloc_statics(&mut self, qual: &str, pou: &'a POU, blocks: &'a [VarBlock],
+                     kind: VarType) -> Result<Vec<StaticVar<'a>>, Error> {
+        let mut list: Vec<StaticVar<'a>> = vec![];
+        for block in blocks.iter().filter(|b| b.0 == kind) {
+            for var in &block.1 {
+                if list.iter().any(|v| v.name.eq_ignore_ascii_case(&var.name)) {
+                    bail!("Duplicate definition of variable {}.{}", qual, var.name);
+                }
+                let ty = self.resolve_type(&var.typ)?;
+                let (size, align) = self.size_align(&ty)?;
+                let offset = self.alloc(size, align);
+                self.name_var(offset, &ty, format!("{}.{}", qual, var.name))?;
+                list.push(StaticVar { name: &var.name, offset, ty, default: var.default.as_ref(),
+                                      flags: block.2, source: pou.source.as_ref() });
+            }
+        }
+        self.static_vars.extend(list.iter().cloned());
+        Ok(list)
+    }
+
+    /// Check that VAR_EXTERNAL variables refer to global variables of the
+    /// same type.
+    fn check_externals(&self, blocks: &[VarBlock]) -> Result<(), Error> {
+        for block in blocks.iter().filter(|b| b.0 == VarType::External) {
+            for var in &block.1 {
+                let global = match find_unqualified(&self.globals, &var.name)? {
+                    Some(global) => global,
+                    None => bail!("External variable {} is not a global variable", var.name),
+                };
+                if self.resolve_type(&var.typ)? != global.ty {
+                    bail!("External variable {} has a different type than the global variable",
+                          var.name);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Add fields to a layout, after the existing ones.
+    ///
+    /// `pack` is the maximum alignment of the fields, if given.
+    fn add_fields(&mut self, key: &str, layout: &mut Layout<'a>, fields: Vec<FieldDef<'a>>,
+                  is_union: bool, pack: Option<usize>) -> Result<(), Error> {
+        for (name, ty, default, input, flags) in fields {
+            if layout.field(name).is_some() {
+                bail!("Duplicate definition of {} in {}", name, key);
+            }
+            let (size, align) = self.size_align(&ty)?;
+            let align = pack.map_or(align, |pack| align.min(pack));
+            // all members of a union start at the beginning
+            let offset = if is_union { 0 } else { align_up(layout.size, align) };
+            layout.size = layout.size.max(offset + size);
+            layout.align = layout.align.max(align);
+            layout.fields.push(Field { name, offset, ty, default, input, flags });
+        }
+        layout.size = align_up(layout.size, layout.align);
+        Ok(())
+    }
+
+    /// Find a POU by name, which can be qualified with the namespace of the
+    /// library that defines it.  Returns the name it is known by, which is
+    /// qualified for library POUs.
+    ///
+    /// Unqualified names refer to the project's own POU, if there is one,
+    /// and otherwise to the POU of the only library that defines the name.
+    fn find_pou(&self, name: &str) -> Result<Option<(&'a str, &'a POU)>, Error> {
+        if let Some(i) = name.find('.') {
+            let (ns, name) = (&name[..i], &name[i+1..]);
+            if !self.namespaces.contains(&Name(ns)) {
+                bail!("Unknown library namespace {}", ns);
+            }
+            return match self.library_pou(ns, name) {
+                Some(key) => Ok(Some((key, self.pous[&Name(key)]))),
+                None => bail!("Library {} has no POU {}", ns, name),
+            };
+        }
+        // only the project's own POUs are known by their plain name
+        if let Some(&pou) = self.pous.get(&Name(name)) {
+            return Ok(Some((&pou.name, pou)));
+        }
+        match self.library_pous.get(&Name(name)).map(|keys| &keys[..]) {
+            Some(&[key]) => Ok(Some((key, self.pous[&Name(key)]))),
+            Some(keys) => bail!("{} is ambiguous: it is defined by the libraries {}, qualify it \
+                                 with the namespace", name, library_list(keys)),
+            None => Ok(None),
+        }
+    }
+
+    /// Find a POU defined by the library with the given namespace, and return
+    /// its qualified name.
+    fn library_pou(&self, ns: &str, name: &str) -> Option<&'a str> {
+        self.library_pous.get(&Name(name))?.iter().cloned()
+            .find(|key| key.split('.').next().unwrap().eq_ignore_ascii_case(ns))
+    }
+
+    /// Determine if a qualifier is the namespace of a library, which must
+    /// not also be the name of a global variable list or program.
+    fn is_namespace(&self, qual: &str) -> Result<bool, Error> {
+        if !self.namespaces.contains(&Name(qual)) {
+            return Ok(false);
+        }
+        if self.gvls.keys().any(|k| k.1 == Name(qual)) || self.pous.contains_key(&Name(qual)) {
+            bail!("{} is ambiguous: it is both a library namespace and a global variable \
+                   list or POU", qual);
+        }
+        Ok(true)
+    }
+
+    /// Get a function block and all its bases, starting with itself.
+    fn ancestors(&self, name: &str) -> Result<Vec<&'a str>, Error> {
+        let mut chain = vec![];
+        let mut current = name;
+        loop {
+            let (key, pou) = match self.find_pou(current)? {
+                Some(pou) => pou,
+                None => bail!("Unknown function block {}", current),
+            };
+            match pou.kind {
+                POUType::FBlock { ref extends, .. } => {
+                    if chain.contains(&key) {
+                        bail!("Function block {} extends itself", name);
+                    }
+                    chain.push(key);
+                    match *extends {
+                        Some(ref base) => current = base,
+                        None => return Ok(chain),
+                    }
+                }
+                _ => bail!("{} is not a function block", current),
+            }
+        }
+    }
+
+    /// Determine if the POU with this name is an interface.
+    fn is_interface(&self, name: &str) -> bool {
+        matches!(self.find_pou(name),
+                 Ok(Some((_, &POU { kind: POUType::Interface { .. }, .. }))))
+    }
+
+    /// Get an interface and all interfaces it extends, starting with itself.
+    fn interface_ancestors(&self, name: &str) -> Result<Vec<&'a str>, Error> {
+        let mut result = vec![];
+        let mut todo = vec![name];
+        while let Some(current) = todo.pop() {
+            let (key, pou) = match self.find_pou(current)? {
+                Some(pou) => pou,
+                None => bail!("Unknown interface {}", current),
+            };
+            match pou.kind {
+                POUType::Interface { ref extends, .. } => {
+                    if !result.contains(&key) {
+                        result.push(key);
+                        todo.extend(extends.iter().map(|base| &base[..]));
+                    }
+                }
+                _ => bail!("{} is not an interface", current),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Get all interfaces a function block implements, also via its bases
+    /// and via interfaces extending others.
+    fn class_interfaces(&self, class: &str) -> Result<Vec<&'a str>, Error> {
+        let mut result = vec![];
+        for base in self.ancestors(class)? {
+            if let POUType::FBlock { ref implements, .. } = self.pous[&Name(base)].kind {
+                for itf in implements {
+                    for itf in self.interface_ancestors(itf)? {
+                        if !result.contains(&itf) {
+                            result.push(itf);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Check that function blocks implement all methods and properties of
+    /// their interfaces.
+    fn check_interfaces(&self) -> Result<(), Error> {
+        for &Name(class) in self.classes.keys() {
+            for itf in self.class_interfaces(class)? {
+                for &(Name(owner), Name(ref name)) in self.methods.keys() {
+                    if owner == itf && self.find_method(class, name)?.is_none() {
+                        bail!("{} does not implement {} of interface {}", class, name, itf);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Register a method or property accessor of a function block.
+    ///
+    /// `first` is the return value, or the input of a property setter.
+    fn add_method(&mut self, class: &'a str, name: String, first: Option<(&'a str, Ty, bool)>,
+                  vars: &'a [VarBlock], func: Func) -> Result<(), Error> {
+        let mut fields = vec![];
+        let mut returns = false;
+        if let Some((fname, ty, input)) = first {
+            returns = !input;
+            fields.push((fname, ty, None, input, VarFlags::default()));
+        }
+        // parameters come first, so that they are at the same offsets in all
+        // overriding methods
+        let (locals, params): (Vec<_>, Vec<_>) =
+            self.block_fields(vars)?.into_iter().partition(|f| f.0);
+        fields.extend(params.into_iter().map(|f| f.1));
+        let nparams = fields.len();
+        fields.extend(locals.into_iter().map(|f| f.1));
+        let mut layout = Layout::new();
+        self.add_fields(&name, &mut layout, fields, false, None)?;
+        let slot = self.slots.len();
+        self.slots.entry(Name(name.clone())).or_insert(slot);
+        let method = MethodImpl { func, root: class, frame: 0, layout: Rc::new(layout),
+                                  returns, params: nparams };
+        if self.methods.insert((Name(class), Name(name.clone())), method).is_some() {
+            bail!("Duplicate definition of method {}.{}", class, name);
+        }
+        Ok(())
+    }
+
+    /// Register the methods and property accessors of a function block or
+    /// interface, adding their bodies to be compiled unless it is an
+    /// interface.
+    fn add_methods(&mut self, class: &'a str, methods: &'a [Method],
+                   properties: &'a [Property],
+                   mut bodies: Option<&mut Vec<Body<'a>>>) -> Result<(), Error> {
+        for method in methods {
+            let ret = match method.rtype {
+                Some(ref rtype) => Some((&method.name[..], self.resolve_type(rtype)?, false)),
+                None => None,
+            };
+            let func = bodies.as_ref().map_or(0, |b| b.len());
+            self.add_method(class, method.name.clone(), ret, &method.vars, func)?;
+            if let Some(ref mut bodies) = bodies {
+                bodies.push(Body::Method(class, method.name.clone(), &method.vars, &method.body));
+            }
+        }
+        for prop in properties {
+            let ty = self.resolve_type(&prop.typ)?;
+            // the getter returns the value, the setter gets it as input
+            for (accessor, set) in prop.get.iter().map(|a| (a, false))
+                                        .chain(prop.set.iter().map(|a| (a, true))) {
+                let name = accessor_name(&prop.name, set);
+                let first = Some((&prop.name[..], ty.clone(), set));
+                let func = bodies.as_ref().map_or(0, |b| b.len());
+                self.add_method(class, name.clone(), first, &accessor.vars, func)?;
+                if let Some(ref mut bodies) = bodies {
+                    bodies.push(Body::Method(class, name, &accessor.vars, &accessor.body));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Allocate the static frames of methods, and the frames for passing
+    /// arguments, which are shared by a method and all methods overriding or
+    /// implementing it.
+    ///
+    /// Also check that the signatures of overriding methods match.
+    fn allocate_method_frames(&mut self) -> Result<(), Error> {
+        let mut keys = self.methods.keys().cloned().collect::<Vec<_>>();
+        keys.sort();
+        let mut frames: Vec<(MethodKey<'a>, usize, usize)> = vec![];
+        for (Name(class), name) in keys {
+            let is_interface = self.is_interface(class);
+            let mut root = class;
+            if !is_interface {
+                for base in self.ancestors(class)? {
+                    if self.methods.contains_key(&(Name(base), name.clone())) {
+                        root = base;
+                    }
+                }
+                // methods declared by an interface are called through it
+                let itfs = self.class_interfaces(class)?.into_iter()
+                    .filter(|itf| self.methods.contains_key(&(Name(*itf), name.clone())))
+                    .collect::<Vec<_>>();
+                match itfs.len() {
+                    0 => {}
+                    1 => root = itfs[0],
+                    _ => bail!("Method {}.{} is declared by more than one interface",
+                               class, name.0),
+                }
+            }
+            let (size, align) = {
+                let method = &self.methods[&(Name(class), name.clone())];
+                let first = &self.methods[&(Name(root), name.clone())];
+                if method.returns != first.returns || method.params != first.params ||
+                    method.layout.fields.iter().zip(&first.layout.fields).take(first.params)
+                        .any(|(a, b)| !a.name.eq_ignore_ascii_case(b.name) || a.ty != b.ty)
+                {
+                    bail!("Method {}.{} does not match the signature of {}.{}",
+                          class, name.0, root, name.0);
+                }
+                (method.layout.size, method.layout.align)
+            };
+            // interface methods have no implementation that needs a frame
+            let frame = if is_interface { 0 } else { self.alloc(size, align) };
+            {
+                let method = self.methods.get_mut(&(Name(class), name.clone())).unwrap();
+                method.root = root;
+                method.frame = frame;
+            }
+            match frames.iter_mut().find(|f| f.0 == (Name(root), name.clone())) {
+                Some(frame) => {
+                    frame.1 = frame.1.max(size);
+                    frame.2 = frame.2.max(align);
+                }
+                None => frames.push(((Name(root), name), size, align)),
+            }
+        }
+        for (key, size, align) in frames {
+            let frame = self.alloc(size, align);
+            self.method_frames.insert(key, frame);
+        }
+        Ok(())
+    }
+
+    /// Copy the parameters of a method between the shared frame, where the
+    /// caller puts them, and the method's own frame.
+    ///
+    /// This copies inputs on entry, and outputs and the return value on exit.
+    fn copy_params(&mut self, ctx: &mut Context<'a>, method: &MethodImpl<'a>, shared: usize,
+                   inputs: bool) -> Result<(), Error> {
+        for field in method.layout.fields.iter().take(method.params) {
+            if field.input != inputs {
+                continue;
+            }
+            let typ = self.val_type(&field.ty)?;
+            let (from, to) = if inputs { (shared, method.frame) } else { (method.frame, shared) };
+            self.load_place(ctx, Place::Abs(from + field.offset), typ, None);
+            self.store_place(ctx, Place::Abs(to + field.offset), typ, None);
+        }
+        Ok(())
+    }
+
+    /// Find the method (or property accessor) a class has or inherits.
+    fn find_method(&self, class: &str, name: &str) -> Result<Option<MethodImpl<'a>>, Error> {
+        for base in self.ancestors(class)? {
+            if let Some(method) = self.methods.get(&(Name(base), Name(name.to_string()))) {
+                return Ok(Some(method.clone()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Find the method (or property accessor) of a function block or
+    /// interface type.
+    fn lookup_method(&self, ty: &Ty, name: &str) -> Result<Option<MethodImpl<'a>>, Error> {
+        match *ty {
+            Ty::FBlock(ref class) => self.find_method(class, name),
+            Ty::Interface(ref itf) => {
+                for base in self.interface_ancestors(itf)? {
+                    if let Some(method) = self.methods.get(&(Name(base), Name(name.to_string()))) {
+                        return Ok(Some(method.clone()));
+                    }
+                }
+                Ok(None)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Find the action a function block has or inherits.
+    fn find_action(&self, class: &str, name: &str) -> Result<Option<Func>, Error> {
+        for base in self.ancestors(class)? {
+            if let Some(&func) = self.actions.get(&(Name(base), Name(name))) {
+                return Ok(Some(func));
             }
         }
+        Ok(None)
+    }
+
+    /// Check if values of type `ty` can be used where `target` is expected,
+    /// i.e. they are equal or `ty` is a function block derived from `target`.
+    fn is_subtype(&self, ty: &Ty, target: &Ty) -> Result<bool, Error> {
+        Ok(match (ty, target) {
+            (Ty::FBlock(name), Ty::FBlock(base)) =>
+                self.ancestors(name)?.contains(&&base[..]),
+            _ => ty == target,
+        })
+    }
+
+    /// The function block whose instance is the base in the current scope.
+    fn this_class(&self, ctx: &Context<'a>) -> Option<&'a str> {
+        match self.pous.get(&Name(ctx.scope.name)) {
+            Some(&&POU { kind: POUType::FBlock { .. }, .. }) => Some(ctx.scope.name),
+            _ => None,
+        }
+    }
+
+    /// Get the type of THIS or SUPER, if the name refers to one of them.
+    fn this_pointer(&self, ctx: &Context<'a>, name: &str) -> Result<Option<Ty>, Error> {
+        let is_super = name.eq_ignore_ascii_case("SUPER");
+        if (!is_super && !name.eq_ignore_ascii_case("THIS")) || self.lookup(ctx, name).is_some() {
+            return Ok(None);
+        }
+        let class = match self.this_class(ctx) {
+            Some(class) => class,
+            None => bail!("{} can only be used in function blocks", name),
+        };
+        let class = if is_super {
+            match self.ancestors(class)?.get(1) {
+                Some(base) => *base,
+                None => bail!("{} has no base function block", class),
+            }
+        } else {
+            class
+        };
+        Ok(Some(Ty::Pointer(Box::new(Ty::FBlock(class.into())))))
+    }
+
+    /// Scope for the body of a POU, also used for its actions.
+    fn pou_scope(&mut self, name: &'a str) -> Result<Scope<'a>, Error> {
+        Ok(Scope {
+            name,
+            layout: self.layout(name)?,
+            frame: self.frames.get(&Name(name)).cloned(),
+            instance: None,
+        })
+    }
+
+    /// Build the symbol table for access from outside.
+    fn symbols(&mut self) -> Result<SymbolTable, Error> {
+        let mut table = SymbolTable::default();
+        let layouts = self.layouts.iter().map(|(k, v)| (k.0, v.clone())).collect::<Vec<_>>();
+        for (name, layout) in layouts {
+            let mut members = vec![];
+            for field in &layout.fields {
+                members.push(Member { name: field.name.into(), offset: field.offset,
+                                      typ: self.sym_type(&field.ty)? });
+            }
+            table.add_type(name, members);
+        }
+        // global variables can also be accessed unqualified
+        let mut globals = vec![];
+        for &(_, Name(name)) in self.globals.keys() {
+            if let Ok(Some(var)) = find_unqualified(&self.globals, name) {
+                globals.push((name, var.clone()));
+            }
+        }
+        for (name, var) in globals {
+            let typ = self.sym_type(&var.ty)?;
+            table.add_root(name, var.offset, typ);
+        }
+        for (&Name(name), &frame) in &self.frames {
+            table.add_root(name, frame, SymType::Struct(name.into()));
+        }
+        // global variable lists are structs with absolute offsets
+        let mut gvls = vec![];
+        for &(_, Name(gvl)) in self.gvls.keys() {
+            if let Ok(Some(vars)) = find_unqualified(&self.gvls, gvl) {
+                gvls.push((gvl, vars.clone()));
+            }
+        }
+        for (gvl, vars) in gvls {
+            let mut members = vec![];
+            for var in vars {
+                members.push(Member { name: var.name.into(), offset: var.offset,
+                                      typ: self.sym_type(&var.ty)? });
+            }
+            table.add_type(gvl, members);
+            table.add_root(gvl, 0, SymType::Struct(gvl.into()));
+        }
+        Ok(table)
+    }
+
+    fn sym_type(&mut self, ty: &Ty) -> Result<SymType, Error> {
+        Ok(match *ty {
+            Ty::Array(ref inner, lo, hi) => {
+                let (size, _) = self.size_align(inner)?;
+                SymType::Array(Box::new(self.sym_type(inner)?), lo, hi, size)
+            }
+            Ty::Struct(ref name) | Ty::FBlock(ref name) => SymType::Struct(name.clone()),
+            Ty::Ref(ref inner) => SymType::Ref(Box::new(self.sym_type(inner)?)),
+            _ => SymType::Value(self.val_type(ty)?),
+        })
+    }
+
+    /// Create the function that writes initial values to task memory.
+    fn compile_init(&mut self, pous: &[(&'a str, &'a POU)]) -> Result<Vec<Instr>, Error> {
+        let mut ctx = Context::new(Scope {
+            name: "",
+            layout: Rc::new(Layout::new()),
+            frame: Some(0),
+            instance: None,
+        });
+        for var in self.static_vars.clone() {
+            ctx.source = var.source;
+            self.init_place(&mut ctx, Place::Abs(var.offset), &var.ty, var.default, false)?;
+        }
+        for &(key, pou) in pous {
+            if let POUType::Program { .. } = pou.kind {
+                let frame = self.frames[&Name(key)];
+                let layout = self.layout(key)?;
+                ctx.source = pou.source.as_ref();
+                for field in &layout.fields {
+                    self.init_place(&mut ctx, Place::Abs(frame + field.offset), &field.ty,
+                                    field.default, false)?;
+                }
+            }
+        }
+        ctx.emit(Instr::Return);
+        Ok(ctx.code)
+    }
+
+    /// Get the memory ranges of RETAIN and PERSISTENT variables, as start
+    /// and length.
+    fn retained(&mut self, pous: &[(&'a str, &'a POU)]) -> Result<(Ranges, Ranges), Error> {
+        let (mut retain, mut persistent) = (vec![], vec![]);
+        for var in self.static_vars.clone() {
+            self.collect_retained(var.offset, &var.ty, var.flags, &mut retain, &mut persistent)?;
+        }
+        for &(key, pou) in pous {
+            if let POUType::Program { .. } = pou.kind {
+                let frame = self.frames[&Name(key)];
+                for field in &self.layout(key)?.fields {
+                    self.collect_retained(frame + field.offset, &field.ty, field.flags,
+                                          &mut retain, &mut persistent)?;
+                }
+            }
+        }
+        Ok((retain, persistent))
+    }
+
+    /// Collect the memory ranges of retained variables at `offset`, looking
+    /// into function block instances, which can declare their own.
+    fn collect_retained(&mut self, offset: usize, ty: &Ty, flags: VarFlags,
+                        retain: &mut Ranges, persistent: &mut Ranges) -> Result<(), Error> {
+        if flags.retain || flags.persistent {
+            let (size, _) = self.size_align(ty)?;
+            if flags.persistent { persistent } else { retain }.push((offset, size));
+            return Ok(());
+        }
+        match *ty {
+            Ty::FBlock(ref name) => {
+                for field in &self.layout(name)?.fields {
+                    self.collect_retained(offset + field.offset, &field.ty, field.flags,
+                                          retain, persistent)?;
+                }
+            }
+            Ty::Array(ref inner, lo, hi) => {
+                // collect for the first element, then repeat for the others
+                let (mut inner_retain, mut inner_persistent) = (vec![], vec![]);
+                self.collect_retained(0, inner, flags, &mut inner_retain,
+                                      &mut inner_persistent)?;
+                let (size, _) = self.size_align(inner)?;
+                for i in 0..(hi - lo + 1) as usize {
+                    let base = offset + i * size;
+                    retain.extend(inner_retain.iter().map(|&(start, len)| (base + start, len)));
+                    persistent.extend(inner_persistent.iter()
+                                                      .map(|&(start, len)| (base + start, len)));
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Initialize variables which do not keep their values across calls:
+    /// VAR_TEMP, and unless `temps_only` is given, function and method locals.
+    fn init_locals(&mut self, ctx: &mut Context<'a>, vars: &'a [VarBlock],
+                   temps_only: bool) -> Result<(), Error> {
+        let layout = ctx.scope.layout.clone();
+        for block in vars {
+            if block.0 == VarType::Temp || (block.0 == VarType::Local && !temps_only) {
+                for var in &block.1 {
+                    let field = layout.field(&var.name).unwrap();
+                    let place = match ctx.scope.frame {
+                        Some(frame) => Place::Abs(frame + field.offset),
+                        None => Place::Rel(field.offset),
+                    };
+                    self.init_place(ctx, place, &field.ty, field.default, true)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Emit code to initialize a memory location with its default value.
+    ///
+    /// If `zero` is false, locations without default value are skipped,
+    /// since task memory starts out zeroed.
+    fn init_place(&mut self, ctx: &mut Context<'a>, place: Place, ty: &Ty,
+                  default: Option<&'a Expr>, zero: bool) -> Result<(), Error> {
+        match (ty, default) {
+            (&Ty::Array(ref inner, lo, hi), Some(Expr { kind: ExprKind::List(items), .. })) => {
+                if items.len() as i64 > hi - lo + 1 {
+                    bail!("Too many initializers for array");
+                }
+                let (size, _) = self.size_align(inner)?;
+                for i in 0..(hi - lo + 1) as usize {
+                    self.init_place(ctx, place.add(i * size), inner, items.get(i), zero)?;
+                }
+            }
+            (Ty::Struct(name), Some(Expr { kind: ExprKind::Initializer(items), .. })) => {
+                let layout = self.layout(name)?;
+                for field in &layout.fields {
+                    let default = items.iter().find(|i| i.0.eq_ignore_ascii_case(field.name))
+                                               .map(|i| &i.1)
+                                               .or(field.default);
+                    self.init_place(ctx, place.add(field.offset), &field.ty, default, zero)?;
+                }
+                for item in items {
+                    if layout.field(&item.0).is_none() {
+                        bail!("Struct {} has no member {}", name, item.0);
+                    }
+                }
+            }
+            (_, Some(expr)) if !ty.is_compound() => {
+                let lv = LValue::new(place, ty.clone());
+                self.begin_store(ctx, &lv);
+                self.coerced_expr(ctx, expr, ty)?;
+                self.store(ctx, &lv)?;
+            }
+            (_, Some(_)) => bail!("Invalid initializer for variable of type {:?}", ty),
+            (&Ty::Array(ref inner, lo, hi), None) => {
+                let (size, _) = self.size_align(inner)?;
+                for i in 0..(hi - lo + 1) as usize {
+                    self.init_place(ctx, place.add(i * size), inner, None, zero)?;
+                }
+            }
+            (&Ty::Struct(ref name), None) | (&Ty::FBlock(ref name), None) => {
+                if let Some(&id) = self.classes.get(&Name(&name[..])) {
+                    let lv = LValue::new(place, Ty::UDInt);
+                    self.begin_store(ctx, &lv);
+                    ctx.emit(Instr::Const(Value::UDInt(id)));
+                    self.store(ctx, &lv)?;
+                }
+                let layout = self.layout(name)?;
+                for field in &layout.fields {
+                    // references are set on every call
+                    if let Ty::Ref(_) = field.ty {
+                        continue;
+                    }
+                    self.init_place(ctx, place.add(field.offset), &field.ty,
+                                    field.default, zero)?;
+                }
+                if let Ty::FBlock(ref class) = *ty {
+                    self.call_after_init(ctx, place, class)?;
+                }
+            }
+            (_, None) => if zero {
+                let lv = LValue::new(place, ty.clone());
+                self.begin_store(ctx, &lv);
+                let typ = self.val_type(ty)?;
+                ctx.emit(Instr::Const(typ.default_value()));
+                self.store(ctx, &lv)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Call the methods marked with the `call_after_init` attribute on a
+    /// newly initialized function block instance.
+    ///
+    /// The function block itself must also have the attribute.
+    fn call_after_init(&mut self, ctx: &mut Context<'a>, place: Place,
+                       class: &str) -> Result<(), Error> {
+        if find_attribute(&self.pous[&Name(class)].attrs, "call_after_init").is_none() {
+            return Ok(());
+        }
+        let mut names = vec![];
+        for base in self.ancestors(class)? {
+            if let POUType::FBlock { ref methods, .. } = self.pous[&Name(base)].kind {
+                for method in methods {
+                    if find_attribute(&method.attrs, "call_after_init").is_some() &&
+                        !names.contains(&Name(&method.name[..]))
+                    {
+                        names.push(Name(&method.name[..]));
+                    }
+                }
+            }
+        }
+        for Name(name) in names {
+            // the implementation of the instance's class
+            let method = self.find_method(class, name)?.unwrap();
+            self.emit_addr(ctx, place);
+            ctx.emit(Instr::CallInst(method.func));
+        }
+        Ok(())
+    }
+
+    fn compile_body(&mut self, ctx: &mut Context<'a>, stmts: &'a [Stmt]) -> Result<(), Error> {
+        for stmt in stmts {
+            self.compile_stmt(ctx, stmt).map_err(|e| locate(ctx, e, stmt.span))?;
+        }
+        Ok(())
+    }
+
+    /// Compile the body of a loop, collecting EXIT and CONTINUE jumps.
+    fn compile_loop_body(&mut self, ctx: &mut Context<'a>,
+                         stmts: &'a [Stmt]) -> Result<LoopJumps, Error> {
+        ctx.loops.push(LoopJumps::default());
+        let res = self.compile_body(ctx, stmts);
+        let jumps = ctx.loops.pop().unwrap();
+        res.map(|_| jumps)
+    }
+
+    fn compile_stmt(&mut self, ctx: &mut Context<'a>, stmt: &'a Stmt) -> Result<(), Error> {
+        match stmt.kind {
+            StmtKind::Empty => {}
+            StmtKind::Return => {
+                // jump to the end, where there can be code to run on return
+                let jump = ctx.emit(Instr::Jump(0));
+                ctx.returns.push(jump);
+            }
+            StmtKind::Exit => {
+                let jump = ctx.emit(Instr::Jump(0));
+                match ctx.loops.last_mut() {
+                    Some(jumps) => jumps.exits.push(jump),
+                    None => bail!("EXIT outside of a loop"),
+                }
+            }
+            StmtKind::Continue => {
+                let jump = ctx.emit(Instr::Jump(0));
+                match ctx.loops.last_mut() {
+                    Some(jumps) => jumps.continues.push(jump),
+                    None => bail!("CONTINUE outside of a loop"),
+                }
+            }
+            StmtKind::If(ref cond, ref then, ref else_) => {
+                self.condition(ctx, cond)?;
+                let jump_else = ctx.emit(Instr::JumpIfNot(0));
+                self.compile_body(ctx, then)?;
+                if else_.is_empty() {
+                    let here = ctx.here();
+                    ctx.patch(jump_else, here);
+                } else {
+                    let jump_end = ctx.emit(Instr::Jump(0));
+                    let here = ctx.here();
+                    ctx.patch(jump_else, here);
+                    self.compile_body(ctx, else_)?;
+                    let here = ctx.here();
+                    ctx.patch(jump_end, here);
+                }
+            }
+            StmtKind::Case(ref head, ref cases, ref else_) => {
+                // the selector stays on the stack while checking the labels
+                let head_ty = self.value_expr(ctx, head)?;
+                let mut body_jumps = vec![];
+                for case in cases {
+                    let mut jumps = vec![];
+                    for label in &case.0 {
+                        match *label {
+                            CaseExpr::Single(ref value) => {
+                                ctx.emit(Instr::Dup);
+                                self.case_label(ctx, value, &head_ty)?;
+                                ctx.emit(Instr::BinOp(Value::eq));
+                                jumps.push(ctx.emit(Instr::JumpIf(0)));
+                            }
+                            CaseExpr::Range(ref lo, ref hi) => {
+                                ctx.emit(Instr::Dup);
+                                self.case_label(ctx, lo, &head_ty)?;
+                                ctx.emit(Instr::BinOp(Value::ge));
+                                let skip = ctx.emit(Instr::JumpIfNot(0));
+                                ctx.emit(Instr::Dup);
+                                self.case_label(ctx, hi, &head_ty)?;
+                                ctx.emit(Instr::BinOp(Value::le));
+                                jumps.push(ctx.emit(Instr::JumpIf(0)));
+                                let here = ctx.here();
+                                ctx.patch(skip, here);
+                            }
+                        }
+                    }
+                    body_jumps.push(jumps);
+                }
+                let mut end_jumps = vec![];
+                ctx.emit(Instr::Pop);
+                self.compile_body(ctx, else_)?;
+                end_jumps.push(ctx.emit(Instr::Jump(0)));
+                for (case, jumps) in cases.iter().zip(body_jumps) {
+                    let here = ctx.here();
+                    for jump in jumps {
+                        ctx.patch(jump, here);
+                    }
+                    ctx.emit(Instr::Pop);
+                    self.compile_body(ctx, &case.1)?;
+                    end_jumps.push(ctx.emit(Instr::Jump(0)));
+                }
+                let here = ctx.here();
+                for jump in end_jumps {
+                    ctx.patch(jump, here);
+                }
+            }
+            StmtKind::While(ref cond, ref body) => {
+                let start = ctx.here();
+                self.condition(ctx, cond)?;
+                let jump_end = ctx.emit(Instr::JumpIfNot(0));
+                let jumps = self.compile_loop_body(ctx, body)?;
+                ctx.emit(Instr::Jump(start));
+                let here = ctx.here();
+                ctx.patch(jump_end, here);
+                ctx.patch_loop(jumps, start, here);
+            }
+            StmtKind::For(ref var, ref start, ref end, ref step, ref body) => {
+                self.check_writable(ctx, var)?;
+                let lv = self.lvalue(ctx, var)?;
+                let place = self.spill(ctx, lv.place);
+                let lv = LValue { place, ..lv };
+                let var_ty = lv.value_ty();
+                if lv.bit.is_some() || !self.val_type(&var_ty)?.is_integer() {
+                    bail!("FOR loop variable must be an integer variable");
+                }
+                self.begin_store(ctx, &lv);
+                self.coerced_expr(ctx, start, &var_ty)?;
+                self.store(ctx, &lv)?;
+                // the variable may leave its subrange when the loop ends
+                let var_ty = var_ty.value_type();
+                let top = ctx.here();
+                match step.as_ref().map(|s| (s, const_sign(s))) {
+                    None | Some((_, Some(true))) =>
+                        self.loop_bound(ctx, &lv, end, BinOp::Gt)?,
+                    Some((_, Some(false))) =>
+                        self.loop_bound(ctx, &lv, end, BinOp::Lt)?,
+                    Some((step, None)) => {
+                        // (step >= 0 AND var > end) OR (step < 0 AND var < end)
+                        let sty = self.value_expr(ctx, step)?;
+                        ctx.emit(Instr::Const(Value::DInt(0)));
+                        self.binop(ctx, &sty, &Ty::DInt, &BinOp::Ge)?;
+                        ctx.emit(Instr::Dup);
+                        self.loop_bound(ctx, &lv, end, BinOp::Gt)?;
+                        ctx.emit(Instr::BinOp(Value::and));
+                        ctx.emit(Instr::Swap);
+                        ctx.emit(Instr::Const(Value::Bool(true)));
+                        ctx.emit(Instr::BinOp(Value::xor));
+                        self.loop_bound(ctx, &lv, end, BinOp::Lt)?;
+                        ctx.emit(Instr::BinOp(Value::and));
+                        ctx.emit(Instr::BinOp(Value::or));
+                    }
+                }
+                let jump_end = ctx.emit(Instr::JumpIf(0));
+                let jumps = self.compile_loop_body(ctx, body)?;
+                let next = ctx.here();
+                self.begin_store(ctx, &lv);
+                self.load(ctx, &lv)?;
+                let step = match *step {
+                    Some(ref step) => self.literal_as(step, &var_ty)?.ok_or(step),
+                    None => Ok(Value::SInt(1).convert(self.val_type(&var_ty)?)?),
+                };
+                let sty = match step {
+                    Ok(value) => {
+                        ctx.emit(Instr::Const(value));
+                        var_ty.clone()
+                    }
+                    Err(step) => self.value_expr(ctx, step)?,
+                };
+                let ty = self.binop(ctx, &var_ty, &sty, &BinOp::Add)?;
+                self.coerce(ctx, &ty, &var_ty)?;
+                self.store(ctx, &lv)?;
+                ctx.emit(Instr::Jump(top));
+                let here = ctx.here();
+                ctx.patch(jump_end, here);
+                ctx.patch_loop(jumps, next, here);
+            }
+            StmtKind::Repeat(ref body, ref cond) => {
+                let top = ctx.here();
+                let jumps = self.compile_loop_body(ctx, body)?;
+                let next = ctx.here();
+                self.condition(ctx, cond)?;
+                ctx.emit(Instr::JumpIfNot(top));
+                let here = ctx.here();
+                ctx.patch_loop(jumps, next, here);
+            }
+            StmtKind::Assign(ref target, ref value) => {
+                if let Some((base, class, name)) = self.property(ctx, target)? {
+                    self.call_property(ctx, base, &class, name, Some(value))?;
+                    return Ok(());
+                }
+                self.check_writable(ctx, target)?;
+                let lv = self.lvalue(ctx, target)?;
+                self.begin_store(ctx, &lv);
+                self.coerced_expr(ctx, value, &lv.value_ty())?;
+                self.store(ctx, &lv)?;
+            }
+            StmtKind::RefAssign(ref target, ref source) => {
+                self.check_writable(ctx, target)?;
+                let (place, ty) = self.lvalue_ref(ctx, target)?;
+                let inner = match ty {
+                    Ty::Ref(inner) => *inner,
+                    _ => bail!("REF= needs a reference on the left side"),
+                };
+                let lv = LValue::new(place, Ty::Ref(Box::new(inner.clone())));
+                self.begin_store(ctx, &lv);
+                let src = self.lvalue(ctx, source)?;
+                if !self.is_subtype(&src.ty, &inner)? || src.bit.is_some() {
+                    bail!("Invalid target for reference of type {:?}", inner);
+                }
+                self.emit_addr(ctx, src.place);
+                self.store(ctx, &lv)?;
+            }
+            StmtKind::Expr(ref expr) => {
+                if self.expr(ctx, expr)?.is_some() {
+                    ctx.emit(Instr::Pop);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Compare a FOR loop variable with the end value.
+    fn loop_bound(&mut self, ctx: &mut Context<'a>, lv: &LValue, end: &'a Expr,
+                  op: BinOp) -> Result<(), Error> {
+        self.load(ctx, lv)?;
+        let ety = self.value_expr(ctx, end)?;
+        self.binop(ctx, &lv.value_ty().value_type(), &ety, &op)?;
+        Ok(())
+    }
+
+    /// Look up a plain name in the current scope.
+    fn lookup(&self, ctx: &Context<'a>, name: &str) -> Option<(Place, Ty)> {
+        self.lookup_var(ctx, name).map(|(place, ty, _)| (place, ty))
+    }
+
+    /// Like `lookup`, but also return the modifiers of the variable.
+    fn lookup_var(&self, ctx: &Context<'a>, name: &str) -> Option<(Place, Ty, VarFlags)> {
+        if let Some(field) = ctx.scope.layout.field(name) {
+            let place = match ctx.scope.frame {
+                Some(frame) => Place::Abs(frame + field.offset),
+                None => Place::Rel(field.offset),
+            };
+            return Some((place, field.ty.clone(), field.flags));
+        }
+        if let Some(ref instance) = ctx.scope.instance {
+            if let Some(field) = instance.field(name) {
+                return Some((Place::Rel(field.offset), field.ty.clone(), field.flags));
+            }
+        }
+        // VAR_STAT variables are shared by all instances, including those
+        // of derived function blocks
+        let owners = self.ancestors(ctx.scope.name).unwrap_or_else(|_| vec![ctx.scope.name]);
+        let var = owners.into_iter()
+                        .filter_map(|pou| self.statics.get(&Name(pou)))
+                        .flat_map(|vars| vars.iter())
+                        .find(|v| v.name.eq_ignore_ascii_case(name))
+                        .or_else(|| find_unqualified(&self.globals, name).ok().flatten());
+        var.map(|var| (Place::Abs(var.offset), var.ty.clone(), var.flags))
+    }
+
+    /// Look up a name qualified with a global variable list, program name or
+    /// library namespace.
+    fn lookup_qualified(&mut self, qual: &str, name: &str) -> Result<Option<(Place, Ty)>, Error> {
+        Ok(self.lookup_qualified_var(qual, name)?.map(|(place, ty, _)| (place, ty)))
+    }
+
+    /// Like `lookup_qualified`, but also return the modifiers of the variable.
+    fn lookup_qualified_var(&mut self, qual: &str,
+                            name: &str) -> Result<Option<(Place, Ty, VarFlags)>, Error> {
+        if self.is_namespace(qual)? {
+            return match self.globals.get(&(Some(Name(qual)), Name(name))) {
+                Some(var) => Ok(Some((Place::Abs(var.offset), var.ty.clone(), var.flags))),
+                None => bail!("Library {} has no global variable {}", qual, name),
+            };
+        }
+        if let Some(vars) = find_unqualified(&self.gvls, qual)? {
+            if let Some(var) = vars.iter().find(|v| v.name.eq_ignore_ascii_case(name)) {
+                return Ok(Some((Place::Abs(var.offset), var.ty.clone(), var.flags)));
+            }
+            bail!("Global variable list {} has no variable {}", qual, name);
+        }
+        if let Some(&frame) = self.frames.get(&Name(qual)) {
+            if let POUType::Program { .. } = self.pous[&Name(qual)].kind {
+                let layout = self.layout(qual)?;
+                return match layout.field(name) {
+                    Some(field) => Ok(Some((Place::Abs(frame + field.offset), field.ty.clone(),
+                                            field.flags))),
+                    None => bail!("Program {} has no variable {}", qual, name),
+                };
+            }
+        }
+        Ok(None)
+    }
+
+    /// Check if an assignable expression refers to a constant, or to a part
+    /// of one.
+    fn is_constant(&mut self, ctx: &Context<'a>, expr: &'a Expr) -> Result<bool, Error> {
+        Ok(match expr.kind {
+            ExprKind::Name(ref name) =>
+                self.lookup_var(ctx, name).is_some_and(|(_, _, flags)| flags.constant),
+            ExprKind::Member(ref base, ref member) => {
+                if let ExprKind::Name(ref qual) = base.kind {
+                    if self.lookup(ctx, qual).is_none() {
+                        if let Some((_, _, flags)) = self.lookup_qualified_var(qual, member)? {
+                            return Ok(flags.constant);
+                        }
+                    }
+                }
+                if self.is_constant(ctx, base)? {
+                    return Ok(true);
+                }
+                match self.static_type(ctx, base)? {
+                    Some(Ty::Struct(ref name)) | Some(Ty::FBlock(ref name)) =>
+                        self.layout(name)?.field(member).is_some_and(|f| f.flags.constant),
+                    _ => false,
+                }
+            }
+            ExprKind::Sub(ref base, _) | ExprKind::Bit(ref base, _) =>
+                self.is_constant(ctx, base)?,
+            _ => false,
+        })
+    }
+
+    /// Make sure that an assignable expression can be written to.
+    fn check_writable(&mut self, ctx: &Context<'a>, expr: &'a Expr) -> Result<(), Error> {
+        if self.is_constant(ctx, expr)? {
+            bail!("Cannot write to a constant");
+        }
+        Ok(())
+    }
+
+    /// Resolve an assignable expression, emitting code to compute the
+    /// address if it is not known statically.
+    fn lvalue(&mut self, ctx: &mut Context<'a>, expr: &'a Expr) -> Result<LValue, Error> {
+        self.lvalue_inner(ctx, expr).map_err(|e| locate(ctx, e, expr.span))
+    }
+
+    fn lvalue_inner(&mut self, ctx: &mut Context<'a>, expr: &'a Expr) -> Result<LValue, Error> {
+        if let ExprKind::Bit(ref base, bit) = expr.kind {
+            let lv = self.lvalue(ctx, base)?;
+            let (size, _) = self.size_align(&lv.ty)?;
+            if lv.ty.is_compound() || lv.bit.is_some() || bit as usize >= 8 * size {
+                bail!("Invalid bit access .{}", bit);
+            }
+            return Ok(LValue { place: lv.place, ty: lv.ty, bit: Some(bit as usize) });
+        }
+        if let Some((base, class, name)) = self.property(ctx, expr)? {
+            return match self.call_property(ctx, base, &class, name, None)? {
+                Some(lv) => Ok(lv),
+                None => bail!("Property {} has no getter", name),
+            };
+        }
+        let (place, ty) = self.lvalue_ref(ctx, expr)?;
+        Ok(self.deref(ctx, place, ty))
+    }
+
+    /// Like `lvalue`, but do not dereference a resulting reference.
+    fn lvalue_ref(&mut self, ctx: &mut Context<'a>,
+                  expr: &'a Expr) -> Result<(Place, Ty), Error> {
+        Ok(match expr.kind {
+            ExprKind::Name(ref name) => match self.lookup(ctx, name) {
+                Some(res) => res,
+                None => {
+                    // report global variables of several libraries
+                    find_unqualified(&self.globals, name)?;
+                    bail!("Unknown variable {}", name)
+                }
+            },
+            ExprKind::Member(ref base, ref member) => {
+                if let ExprKind::Name(ref qual) = base.kind {
+                    if self.lookup(ctx, qual).is_none() {
+                        if let Some(res) = self.lookup_qualified(qual, member)? {
+                            return Ok(res);
+                        }
+                    }
+                }
+                let lv = self.lvalue(ctx, base)?;
+                self.member(lv, member)?
+            }
+            ExprKind::Sub(ref base, ref indices) => {
+                let lv = self.lvalue(ctx, base)?;
+                if lv.bit.is_some() {
+                    bail!("Cannot index a bit");
+                }
+                let (mut place, mut ty) = (lv.place, lv.ty);
+                for index in indices {
+                    let (inner, lo, hi) = match ty {
+                        Ty::Array(inner, lo, hi) => (*inner, lo, hi),
+                        _ => bail!("Cannot index a non-array value"),
+                    };
+                    let (size, _) = self.size_align(&inner)?;
+                    if let ExprKind::Lit(Lit::Int(_, i)) = index.kind {
+                        if i < lo || i > hi {
+                            bail!("Array index {} out of bounds {}..{}", i, lo, hi);
+                        }
+                        place = place.add((i - lo) as usize * size);
+                    } else {
+                        self.emit_addr(ctx, place);
+                        let ity = self.value_expr(ctx, index)?;
+                        if !self.val_type(&ity)?.is_integer() {
+                            bail!("Array index must be an integer");
+                        }
+                        ctx.emit(Instr::Index(lo, hi, size));
+                        place = Place::Ind(0);
+                    }
+                    ty = inner;
+                }
+                (place, ty)
+            }
+            ExprKind::Deref(ref inner) => {
+                match self.value_expr(ctx, inner)? {
+                    Ty::Pointer(ty) => (Place::Ind(0), *ty),
+                    ty => bail!("Cannot dereference a value of type {:?}", ty),
+                }
+            }
+            _ => bail!("Expression is not assignable"),
+        })
+    }
+
+    /// Get the place of a member of a struct or function block.
+    fn member(&mut self, lv: LValue, member: &str) -> Result<(Place, Ty), Error> {
+        if lv.bit.is_some() {
+            bail!("Cannot access a member of a bit");
+        }
+        match lv.ty {
+            Ty::Struct(ref name) | Ty::FBlock(ref name) => {
+                let layout = self.layout(name)?;
+                match layout.field(member) {
+                    Some(field) => Ok((lv.place.add(field.offset), field.ty.clone())),
+                    None => bail!("{} has no member {}", name, member),
+                }
+            }
+            _ => bail!("Cannot access member {} of non-struct value", member),
+        }
+    }
+
+    /// Determine the type of an assignable expression without emitting code.
+    fn static_type(&mut self, ctx: &Context<'a>, expr: &Expr) -> Result<Option<Ty>, Error> {
+        let ty = match expr.kind {
+            ExprKind::Name(ref name) => match self.lookup(ctx, name) {
+                Some((_, ty)) => Some(ty),
+                None => self.this_pointer(ctx, name)?,
+            },
+            ExprKind::Member(ref base, ref member) => {
+                if let ExprKind::Name(ref qual) = base.kind {
+                    if self.lookup(ctx, qual).is_none() {
+                        if let Some((_, ty)) = self.lookup_qualified(qual, member)? {
+                            return Ok(Some(ty));
+                        }
+                    }
+                }
+                match self.static_type(ctx, base)? {
+                    Some(Ty::Struct(ref name)) | Some(Ty::FBlock(ref name)) =>
+                        self.layout(name)?.field(member).map(|f| f.ty.clone()),
+                    _ => None,
+                }
+            }
+            ExprKind::Sub(ref base, ref indices) => {
+                let mut ty = self.static_type(ctx, base)?;
+                for _ in indices {
+                    ty = match ty {
+                        Some(Ty::Array(inner, _, _)) => Some(*inner),
+                        _ => None,
+                    };
+                }
+                ty
+            }
+            ExprKind::Deref(ref inner) => match self.static_type(ctx, inner)? {
+                Some(Ty::Pointer(ty)) => Some(*ty),
+                _ => None,
+            },
+            _ => None,
+        };
+        Ok(match ty {
+            Some(Ty::Ref(inner)) => Some(*inner),
+            ty => ty,
+        })
+    }
+
+    /// Determine if an expression refers to a property.
+    fn property(&mut self, ctx: &Context<'a>,
+                expr: &'a Expr) -> Result<Option<PropertyRef<'a>>, Error> {
+        let (base, class, name) = match expr.kind {
+            ExprKind::Member(ref base, ref name) => match self.static_type(ctx, base)? {
+                Some(Ty::FBlock(class)) => {
+                    if self.layout(&class)?.field(name).is_some() {
+                        return Ok(None);
+                    }
+                    (Some(&**base), Ty::FBlock(class), &name[..])
+                }
+                Some(ty @ Ty::Interface(_)) => (Some(&**base), ty, &name[..]),
+                _ => return Ok(None),
+            },
+            ExprKind::Name(ref name) => match self.this_class(ctx) {
+                Some(class) if self.lookup(ctx, name).is_none() =>
+                    (None, Ty::FBlock(class.into()), &name[..]),
+                _ => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+        if self.lookup_method(&class, &accessor_name(name, false))?.is_some() ||
+            self.lookup_method(&class, &accessor_name(name, true))?.is_some()
+        {
+            Ok(Some((base, class, name)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Call the getter of a property, or the setter if a value is given.
+    ///
+    /// Returns the place of the value for the getter.
+    fn call_property(&mut self, ctx: &mut Context<'a>, base: Option<&'a Expr>, class: &Ty,
+                     name: &str, value: Option<&'a Expr>) -> Result<Option<LValue>, Error> {
+        let (place, dynamic) = match base {
+            Some(base) => {
+                let lv = self.lvalue(ctx, base)?;
+                (self.instance_place(ctx, lv)?, !is_super(base))
+            }
+            None => (Place::Rel(0), true),
+        };
+        let accessor = accessor_name(name, value.is_some());
+        if self.lookup_method(class, &accessor)?.is_none() {
+            bail!("Property {} cannot be {}", name, if value.is_some() { "written" } else { "read" });
+        }
+        match value {
+            Some(value) => self.call_method(ctx, place, class, &accessor, &[(None, value)], &[],
+                                            dynamic),
+            None => self.call_method(ctx, place, class, &accessor, &[], &[], dynamic),
+        }
+    }
+
+    /// Get the place of the instance a function block or interface value
+    /// refers to.
+    fn instance_place(&mut self, ctx: &mut Context<'a>, lv: LValue) -> Result<Place, Error> {
+        match lv.ty {
+            Ty::Interface(_) => {
+                // a null interface is caught when calling through it
+                self.load(ctx, &lv)?;
+                Ok(Place::Ind(0))
+            }
+            _ => Ok(lv.place),
+        }
+    }
+
+    /// Turn a place holding a reference into the place it refers to.
+    fn deref(&mut self, ctx: &mut Context<'a>, place: Place, ty: Ty) -> LValue {
+        match ty {
+            Ty::Ref(inner) => {
+                self.load_place(ctx, place, PTR_TYPE, None);
+                LValue::new(Place::Ind(0), *inner)
+            }
+            ty => LValue::new(place, ty)
+        }
+    }
+
+    /// Make a place usable more than once, by moving an address from the
+    /// stack into a temporary variable.
+    fn spill(&mut self, ctx: &mut Context<'a>, place: Place) -> Place {
+        match place {
+            Place::Ind(o) => {
+                let tmp = self.alloc(PTR_SIZE, PTR_SIZE);
+                let var = self.var(tmp, PTR_TYPE);
+                ctx.emit(Instr::Store(var));
+                Place::Deref(tmp, o)
+            }
+            place => place
+        }
+    }
+
+    /// Push the address of a place onto the stack.
+    fn emit_addr(&mut self, ctx: &mut Context<'a>, place: Place) {
+        let offset = match place {
+            Place::Abs(o) => {
+                ctx.emit(Instr::Const(Value::ULInt(o as u64)));
+                return;
+            }
+            Place::Rel(o) => {
+                ctx.emit(Instr::Base);
+                o
+            }
+            Place::Ind(o) => o,
+            Place::Deref(p, o) => {
+                let var = self.var(p, PTR_TYPE);
+                ctx.emit(Instr::Load(var));
+                o
+            }
+        };
+        if offset != 0 {
+            ctx.emit(Instr::Const(Value::ULInt(offset as u64)));
+            ctx.emit(Instr::BinOp(Value::add));
+        }
+    }
+
+    /// Emit what needs to precede the value for a store, or the load.
+    fn prepare_access(&mut self, ctx: &mut Context<'a>, place: Place) {
+        match place {
+            Place::Rel(_) => {
+                ctx.emit(Instr::Base);
+            }
+            Place::Deref(p, _) => {
+                let var = self.var(p, PTR_TYPE);
+                ctx.emit(Instr::Load(var));
+            }
+            Place::Abs(_) | Place::Ind(_) => {}
+        }
+    }
+
+    fn load_place(&mut self, ctx: &mut Context<'a>, place: Place, typ: ValType, bit: Option<usize>) {
+        self.prepare_access(ctx, place);
+        let instr = match place {
+            Place::Abs(o) => {
+                let var = self.var(o, typ);
+                bit.map_or(Instr::Load(var), |b| Instr::LoadBit(var, b))
+            }
+            Place::Rel(o) | Place::Ind(o) | Place::Deref(_, o) => {
+                let var = self.var(o, typ);
+                bit.map_or(Instr::LoadInd(var), |b| Instr::LoadBitInd(var, b))
+            }
+        };
+        ctx.emit(instr);
+    }
+
+    fn store_place(&mut self, ctx: &mut Context<'a>, place: Place, typ: ValType, bit: Option<usize>) {
+        let instr = match place {
+            Place::Abs(o) => {
+                let var = self.var(o, typ);
+                bit.map_or(Instr::Store(var), |b| Instr::StoreBit(var, b))
+            }
+            Place::Rel(o) | Place::Ind(o) | Place::Deref(_, o) => {
+                let var = self.var(o, typ);
+                bit.map_or(Instr::StoreInd(var), |b| Instr::StoreBitInd(var, b))
+            }
+        };
+        ctx.emit(instr);
+    }
+
+    fn load(&mut self, ctx: &mut Context<'a>, lv: &LValue) -> Result<(), Error> {
+        let typ = self.val_type(&lv.ty)?;
+        self.load_place(ctx, lv.place, typ, lv.bit);
+        Ok(())
+    }
+
+    /// Emit what needs to precede the value for a store.
+    fn begin_store(&mut self, ctx: &mut Context<'a>, lv: &LValue) {
+        self.prepare_access(ctx, lv.place);
+    }
+
+    /// Store the value on top of the stack (after `begin_store`).
+    fn store(&mut self, ctx: &mut Context<'a>, lv: &LValue) -> Result<(), Error> {
+        let typ = self.val_type(&lv.ty)?;
+        self.store_place(ctx, lv.place, typ, lv.bit);
+        Ok(())
+    }
+
+    /// Emit a binary operation on the two values on top of the stack,
+    /// converting them to a common type first.
+    fn binop(&mut self, ctx: &mut Context<'a>, lty: &Ty, rty: &Ty,
+             op: &BinOp) -> Result<Ty, Error> {
+        if let BinOp::Pow = *op {
+            return self.expt(ctx, lty, rty);
+        }
+        let ty = match common_type(lty, rty) {
+            Some(ty) => ty,
+            None => bail!("Type mismatch in operation: {:?} and {:?}", lty, rty),
+        };
+        // common_type only allows meaningful combinations
+        if *lty != ty {
+            ctx.emit(Instr::Swap);
+            self.convert(ctx, lty, &ty)?;
+            ctx.emit(Instr::Swap);
+        }
+        self.convert(ctx, rty, &ty)?;
+        let typ = self.val_type(&ty)?;
+        let (func, cmp): (BinOpFn, bool) = match *op {
+            BinOp::Add => (Value::add, false),
+            BinOp::Sub => (Value::sub, false),
+            BinOp::Mul => (Value::mul, false),
+            BinOp::Div => (Value::div, false),
+            BinOp::Mod => (Value::rem, false),
+            BinOp::And | BinOp::AndThen => (Value::and, false),
+            BinOp::Or | BinOp::OrElse => (Value::or, false),
+            BinOp::Xor => (Value::xor, false),
+            BinOp::Pow => unreachable!(),
+            BinOp::Eq => (Value::eq, true),
+            BinOp::Neq => (Value::ne, true),
+            BinOp::Lt => (Value::lt, true),
+            BinOp::Le => (Value::le, true),
+            BinOp::Gt => (Value::gt, true),
+            BinOp::Ge => (Value::ge, true),
+        };
+        let valid = match *op {
+            BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div | BinOp::Mod =>
+                typ.is_integer() || typ.is_float() || typ.is_time(),
+            BinOp::And | BinOp::Or | BinOp::Xor =>
+                typ.is_integer() || typ == ValType::Bool,
+            BinOp::AndThen | BinOp::OrElse => typ == ValType::Bool,
+            _ => !ty.is_compound(),
+        };
+        if !valid {
+            bail!("Invalid operand type for operation: {:?}", ty);
+        }
+        ctx.emit(Instr::BinOp(func));
+        Ok(if cmp { Ty::Bool } else { ty })
+    }
+
+    /// Emit an exponentiation of the two values on top of the stack.
+    ///
+    /// The result is a floating point number: of the base's type if it is
+    /// one, LREAL otherwise.
+    fn expt(&mut self, ctx: &mut Context<'a>, lty: &Ty, rty: &Ty) -> Result<Ty, Error> {
+        for ty in &[lty, rty] {
+            let typ = self.val_type(ty)?;
+            if !typ.is_integer() && !typ.is_float() {
+                bail!("Invalid operand type for exponentiation: {:?}", ty);
+            }
+        }
+        let ty = if *lty == Ty::Real { Ty::Real } else { Ty::LReal };
+        ctx.emit(Instr::Swap);
+        self.convert(ctx, lty, &ty)?;
+        ctx.emit(Instr::Swap);
+        self.convert(ctx, rty, &ty)?;
+        ctx.emit(Instr::BinOp(Value::pow));
+        Ok(ty)
+    }
+
+    /// Convert the value on top of the stack to another type, if possible
+    /// implicitly.
+    fn coerce(&mut self, ctx: &mut Context<'a>, from: &Ty, to: &Ty) -> Result<(), Error> {
+        if from == to {
+            return Ok(());
+        }
+        // values stored in subranges are checked at runtime
+        if let Ty::Subrange(ref base, ..) = *from {
+            return self.coerce(ctx, base, to);
+        }
+        if let Ty::Subrange(ref base, lo, hi) = *to {
+            self.coerce(ctx, from, base)?;
+            ctx.emit(Instr::CheckRange(lo, hi));
+            return Ok(());
+        }
+        if let (Ty::Interface(from), Ty::Interface(to)) = (from, to) {
+            if self.interface_ancestors(from)?.contains(&&to[..]) {
+                return Ok(());
+            }
+            bail!("Interface {} does not extend {}", from, to);
+        }
+        if !implicit_conversion(from, to) {
+            bail!("Cannot implicitly convert value of type {:?} to {:?}", from, to);
+        }
+        self.convert(ctx, from, to)
+    }
+
+    /// Convert the value on top of the stack to another elementary type,
+    /// even if information can be lost.
+    fn convert(&mut self, ctx: &mut Context<'a>, from: &Ty, to: &Ty) -> Result<(), Error> {
+        match (from.elementary_val_type(), to.elementary_val_type()) {
+            (Some(ftyp), Some(typ)) => {
+                if ftyp != typ {
+                    ctx.emit(Instr::Conv(typ));
+                }
+                Ok(())
+            }
+            _ => bail!("Cannot convert value of type {:?} to {:?}", from, to)
+        }
+    }
+
+    /// Get the value of an untyped numeric literal, or a constant expression
+    /// of them, as the given type if it can be represented there.
+    ///
+    /// Such literals take the type they are used with, so that `x := x + 1`
+    /// needs no conversion for any integer `x`.
+    fn literal_as(&self, expr: &Expr, to: &Ty) -> Result<Option<Value>, Error> {
+        let typ = match to.elementary_val_type() {
+            Some(typ) if typ.is_integer() || typ.is_float() => typ,
+            _ => return Ok(None),
+        };
+        let value = match self.const_number(expr)? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+        if value.val_type().is_float() && !typ.is_float() {
+            return Ok(None);
+        }
+        let converted = value.clone().convert(typ)?;
+        match (value.as_int(), converted.as_int()) {
+            (Some(a), Some(b)) if a != b => Ok(None),
+            _ => Ok(Some(converted)),
+        }
+    }
+
+    /// Evaluate an expression made only of untyped numeric literals and
+    /// arithmetic, or return None if it is something else.
+    fn const_number(&self, expr: &Expr) -> Result<Option<Value>, Error> {
+        Ok(match expr.kind {
+            ExprKind::Lit(ref lit @ Lit::Int(..)) | ExprKind::Lit(ref lit @ Lit::Float(_)) =>
+                Some(self.literal(lit)?.0),
+            ExprKind::Unary(UnOp::Neg, ref inner) => match self.const_number(inner)? {
+                Some(value) => Some(value.neg()?),
+                None => None,
+            },
+            ExprKind::Binary(ref left, ref op, ref right) => {
+                let func: fn(Value, Value) -> Result<Value, Error> = match *op {
+                    BinOp::Add => Value::add,
+                    BinOp::Sub => Value::sub,
+                    BinOp::Mul => Value::mul,
+                    BinOp::Div => Value::div,
+                    BinOp::Mod => Value::rem,
+                    _ => return Ok(None),
+                };
+                let (l, r) = match (self.const_number(left)?, self.const_number(right)?) {
+                    (Some(l), Some(r)) => (l, r),
+                    _ => return Ok(None),
+                };
+                // literals are DINT, LINT or LREAL
+                let typ = match (l.val_type(), r.val_type()) {
+                    (ValType::LReal, _) | (_, ValType::LReal) => ValType::LReal,
+                    (ValType::LInt, _) | (_, ValType::LInt) => ValType::LInt,
+                    (typ, _) => typ,
+                };
+                Some(func(l.convert(typ)?, r.convert(typ)?)?)
+            }
+            _ => None,
+        })
+    }
+
+    /// Compile a boolean condition.
+    fn condition(&mut self, ctx: &mut Context<'a>, expr: &'a Expr) -> Result<(), Error> {
+        match self.value_expr(ctx, expr)? {
+            Ty::Bool => Ok(()),
+            ty => bail!("Condition must be boolean, not {:?}", ty),
+        }
+    }
+
+    /// Compile a CASE label, converting it to the selector type.
+    fn case_label(&mut self, ctx: &mut Context<'a>, expr: &'a Expr, head: &Ty) -> Result<(), Error> {
+        self.coerced_expr(ctx, expr, head)
+    }
+
+    /// Get the value and type of a literal.
+    fn literal(&self, lit: &Lit) -> Result<(Value, Ty), Error> {
+        let narrow = |v: i64| if v >= 0 && v <= u32::MAX as i64 {
+            Ok(v as u32)
+        } else {
+            Err(format_err!("Literal {:?} out of range", lit))
+        };
+        let value = match *lit {
+            Lit::Bool(b) => Value::Bool(b),
+            Lit::Int(_, i) if i as i32 as i64 == i => Value::DInt(i as i32),
+            Lit::Int(_, i) => Value::LInt(i),
+            Lit::Float(f) => Value::LReal(f),
+            Lit::Str(ref s) => Value::String(s.clone()),
+            Lit::WStr(ref s) => Value::WString(s.clone()),
+            Lit::Time(ns) => Value::Time(narrow(ns / NS_PER_MS)?),
+            Lit::LTime(ns) if ns >= 0 => Value::LTime(ns as u64),
+            Lit::LTime(_) => bail!("Literal {:?} out of range", lit),
+            Lit::Date(s) => Value::Date(narrow(s)?),
+            Lit::Tod(ns) => Value::Tod(narrow(ns / NS_PER_MS)?),
+            Lit::Dt(s) => Value::Dt(narrow(s)?),
+            Lit::Typed(ref name, ref inner) => {
+                let ty = match Ty::elementary(&name.to_uppercase()) {
+                    Some(ty) => ty,
+                    None => bail!("Invalid type {} for a typed literal", name),
+                };
+                let typ = ty.elementary_val_type().unwrap();
+                let value = self.literal(inner)?.0;
+                if let Lit::Float(_) = **inner {
+                    if typ == ValType::Bool || typ.is_integer() {
+                        bail!("Invalid literal {:?}", lit);
+                    }
+                }
+                let converted = value.clone().convert(typ)?;
+                if let (Some(a), Some(b)) = (value.as_int(), converted.as_int()) {
+                    // literals above the LINT range arrive wrapped around
+                    let wrapped = a < 0 && b == a as i64 as u64 as i128;
+                    if a != b && !(wrapped && (typ == ValType::ULInt || typ == ValType::LWord)) {
+                        bail!("Literal {} out of range for {}", a, name);
+                    }
+                }
+                return Ok((converted, ty));
+            }
+            Lit::Enum(ref typ, ref member) => match self.enum_member(Some(typ), member)? {
+                Some(result) => return Ok(result),
+                None => bail!("Unknown enum type {}", typ),
+            },
+        };
+        let ty = Ty::from_val_type(value.val_type());
+        Ok((value, ty))
+    }
+
+    /// Compile an expression, leaving its value on the stack.
+    ///
+    /// Returns the type of the value, or None for calls without a result.
+    fn expr(&mut self, ctx: &mut Context<'a>, expr: &'a Expr) -> Result<Option<Ty>, Error> {
+        let ty = self.expr_inner(ctx, expr).map_err(|e| locate(ctx, e, expr.span))?;
+        Ok(ty.map(Ty::value_type))
+    }
+
+    fn expr_inner(&mut self, ctx: &mut Context<'a>,
+                  expr: &'a Expr) -> Result<Option<Ty>, Error> {
+        Ok(Some(match expr.kind {
+            ExprKind::Lit(ref lit) => {
+                let (value, ty) = self.literal(lit)?;
+                ctx.emit(Instr::Const(value));
+                ty
+            }
+            ExprKind::Name(_) | ExprKind::Member(..) | ExprKind::Sub(..) |
+            ExprKind::Bit(..) | ExprKind::Deref(_) => {
+                if let Some((value, ty)) = self.enum_value(ctx, expr)? {
+                    ctx.emit(Instr::Const(value));
+                    return Ok(Some(ty));
+                }
+                if let ExprKind::Name(ref name) = expr.kind {
+                    if let Some(ty) = self.this_pointer(ctx, name)? {
+                        ctx.emit(Instr::Base);
+                        return Ok(Some(ty));
+                    }
+                }
+                let lv = self.lvalue(ctx, expr)?;
+                self.load(ctx, &lv)?;
+                lv.value_ty()
+            }
+            ExprKind::Unary(UnOp::Neg, ref inner) => {
+                let mut ty = self.value_expr(ctx, inner)?;
+                let typ = self.val_type(&ty)?;
+                if !typ.is_signed() {
+                    if !typ.is_integer() {
+                        bail!("Cannot negate value of type {:?}", ty);
+                    }
+                    // negating an unsigned value gives a signed one
+                    let signed = common_type(&ty, &Ty::SInt).unwrap();
+                    self.convert(ctx, &ty, &signed)?;
+                    ty = signed;
+                }
+                ctx.emit(Instr::UnOp(Value::neg));
+                ty
+            }
+            ExprKind::Unary(UnOp::Not, ref inner) => {
+                // logical for BOOL, bitwise for integers
+                let ty = self.value_expr(ctx, inner)?;
+                let typ = self.val_type(&ty)?;
+                if typ != ValType::Bool && !typ.is_integer() {
+                    bail!("Invalid operand type for NOT: {:?}", ty);
+                }
+                ctx.emit(Instr::UnOp(Value::not));
+                ty
+            }
+            ExprKind::Binary(ref left, BinOp::AndThen, ref right) |
+            ExprKind::Binary(ref left, BinOp::OrElse, ref right) => {
+                // the right operand is only evaluated if the left one does
+                // not already determine the result
+                self.condition(ctx, left)?;
+                ctx.emit(Instr::Dup);
+                let jump = match expr.kind {
+                    ExprKind::Binary(_, BinOp::AndThen, _) => ctx.emit(Instr::JumpIfNot(0)),
+                    _ => ctx.emit(Instr::JumpIf(0)),
+                };
+                ctx.emit(Instr::Pop);
+                self.condition(ctx, right)?;
+                let here = ctx.here();
+                ctx.patch(jump, here);
+                Ty::Bool
+            }
+            ExprKind::Binary(ref left, BinOp::Pow, ref right) => {
+                let lty = self.value_expr(ctx, left)?;
+                let rty = self.value_expr(ctx, right)?;
+                self.expt(ctx, &lty, &rty)?
+            }
+            ExprKind::Binary(ref left, ref op, ref right)
+                if matches!(self.const_number(left), Ok(Some(_))) => {
+                // a literal takes the type of the other operand, which is
+                // evaluated first since the literal has no side effects
+                let rty = self.value_expr(ctx, right)?;
+                let lty = match self.literal_as(left, &rty)? {
+                    Some(value) => {
+                        ctx.emit(Instr::Const(value));
+                        rty.clone()
+                    }
+                    None => self.value_expr(ctx, left)?,
+                };
+                ctx.emit(Instr::Swap);
+                self.binop(ctx, &lty, &rty, op)?
+            }
+            ExprKind::Binary(ref left, ref op, ref right) => {
+                let lty = self.value_expr(ctx, left)?;
+                let rty = match self.literal_as(right, &lty)? {
+                    Some(value) => {
+                        ctx.emit(Instr::Const(value));
+                        lty.clone()
+                    }
+                    None => self.value_expr(ctx, right)?,
+                };
+                self.binop(ctx, &lty, &rty, op)?
+            }
+            ExprKind::Call(ref callee, ref args) => {
+                let args = args.iter().map(|a| (None, a)).collect::<Vec<_>>();
+                return self.call(ctx, callee, &args, &[]);
+            }
+            ExprKind::CallFB(ref callee, ref kwargs) => {
+                let mut inputs = vec![];
+                let mut outputs = vec![];
+                for kwarg in kwargs {
+                    match *kwarg {
+                        Kwarg::In(ref n, ref e) => inputs.push((Some(&n[..]), e)),
+                        Kwarg::Out(ref n, ref e) => outputs.push((&n[..], e)),
+                        Kwarg::None(_) => {}
+                    }
+                }
+                return self.call(ctx, callee, &inputs, &outputs);
+            }
+            ExprKind::List(_) | ExprKind::Initializer(_) =>
+                bail!("Aggregate values are only allowed as initial values"),
+        }))
+    }
+
+    /// Compile an expression that must produce a value.
+    fn value_expr(&mut self, ctx: &mut Context<'a>, expr: &'a Expr) -> Result<Ty, Error> {
+        match self.expr(ctx, expr)? {
+            Some(ty) => Ok(ty),
+            None => bail!("Expression has no value"),
+        }
+    }
+
+    /// Compile an expression and convert its value to the given type.
+    ///
+    /// Function block instances are converted to interfaces they implement
+    /// by taking their address.
+    fn coerced_expr(&mut self, ctx: &mut Context<'a>, expr: &'a Expr,
+                    to: &Ty) -> Result<(), Error> {
+        if let Ty::Interface(ref itf) = *to {
+            if let Some(Ty::FBlock(class)) = self.static_type(ctx, expr)? {
+                if !self.class_interfaces(&class)?.contains(&&itf[..]) {
+                    bail!("{} does not implement interface {}", class, itf);
+                }
+                let lv = self.lvalue(ctx, expr)?;
+                self.emit_addr(ctx, lv.place);
+                return Ok(());
+            }
+        }
+        if let Some(value) = self.literal_as(expr, to)? {
+            if let Ty::Subrange(_, lo, hi) = *to {
+                let i = value.as_int().unwrap_or_default();
+                if i < lo as i128 || i > hi as i128 {
+                    bail!("Value {} out of subrange {}..{}", i, lo, hi);
+                }
+            }
+            ctx.emit(Instr::Const(value));
+            return Ok(());
+        }
+        let ty = self.value_expr(ctx, expr)?;
+        self.coerce(ctx, &ty, to)
+    }
+
+    /// Compile a call of a function, function block instance, program,
+    /// method or action.
+    fn call(&mut self, ctx: &mut Context<'a>, callee: &'a Expr,
+            inputs: &[(Option<&'a str>, &'a Expr)],
+            outputs: &[(&'a str, &'a Expr)]) -> Result<Option<Ty>, Error> {
+        match callee.kind {
+            ExprKind::Name(ref name) => self.call_name(ctx, name, inputs, outputs),
+            ExprKind::Member(ref base, ref name) =>
+                self.call_member(ctx, base, name, inputs, outputs),
+            _ => {
+                // e.g. SUPER^() or an element of an array of instances
+                let lv = self.lvalue(ctx, callee)?;
+                self.call_instance(ctx, lv, None, inputs, outputs)
+            }
+        }
+    }
+
+    /// Compile a call of a plain name.
+    fn call_name(&mut self, ctx: &mut Context<'a>, name: &'a str,
+                 inputs: &[(Option<&'a str>, &'a Expr)],
+                 outputs: &[(&'a str, &'a Expr)]) -> Result<Option<Ty>, Error> {
+        if let Some((place, ty)) = self.lookup(ctx, name) {
+            let lv = self.deref(ctx, place, ty);
+            if let Ty::FBlock(_) = lv.ty {
+                return self.call_instance(ctx, lv, None, inputs, outputs);
+            }
+            bail!("{} is not a function block instance", name);
+        }
+        let builtin = name.to_ascii_uppercase();
+        if builtin == "ADR" || builtin == "SIZEOF" {
+            if inputs.len() != 1 || inputs[0].0.is_some() || !outputs.is_empty() {
+                bail!("{} takes exactly one argument", name);
+            }
+            let lv = self.lvalue(ctx, inputs[0].1)?;
+            if lv.bit.is_some() {
+                bail!("{} cannot be used on a bit", name);
+            }
+            if builtin == "SIZEOF" {
+                // the address might have been computed already
+                if let Place::Ind(_) = lv.place {
+                    ctx.emit(Instr::Pop);
+                }
+                let (size, _) = self.size_align(&lv.ty)?;
+                ctx.emit(Instr::Const(Value::UDInt(size as u32)));
+                return Ok(Some(Ty::UDInt));
+            }
+            self.emit_addr(ctx, lv.place);
+            return Ok(Some(Ty::Pointer(Box::new(lv.ty))));
+        }
+        if builtin == "EXPT" {
+            if inputs.len() != 2 || inputs.iter().any(|i| i.0.is_some()) || !outputs.is_empty() {
+                bail!("EXPT takes exactly two arguments");
+            }
+            let lty = self.value_expr(ctx, inputs[0].1)?;
+            let rty = self.value_expr(ctx, inputs[1].1)?;
+            return self.expt(ctx, &lty, &rty).map(Some);
+        }
+        // functions can be named like conversions
+        let conversion = match conversion_target(name) {
+            Some(target) if self.find_pou(name)?.is_none() => Some(target),
+            _ => None,
+        };
+        if let Some(target) = conversion {
+            if inputs.len() != 1 || inputs[0].0.is_some() || !outputs.is_empty() {
+                bail!("Conversion function {} takes exactly one argument", name);
+            }
+            let ty = self.value_expr(ctx, inputs[0].1)?;
+            let typ = match ty.elementary_val_type() {
+                Some(typ) => typ,
+                None => bail!("Cannot convert value of type {:?}", ty),
+            };
+            if typ != target.elementary_val_type().unwrap() {
+                ctx.emit(Instr::Conv(target.elementary_val_type().unwrap()));
+            }
+            return Ok(Some(target));
+        }
+        // methods and actions of the current POU
+        if let Some(class) = self.this_class(ctx) {
+            if self.find_action(class, name)?.is_some() || self.find_method(class, name)?.is_some() {
+                return self.call_on(ctx, Place::Rel(0), &Ty::FBlock(class.into()), name, inputs,
+                                    outputs, true);
+            }
+        } else if let Some(&func) = self.actions.get(&(Name(ctx.scope.name), Name(name))) {
+            return self.call_program_action(ctx, func, inputs, outputs);
+        }
+        // library POUs are known by their qualified name
+        let name = match self.find_pou(name)? {
+            Some((key, _)) => key,
+            None => name,
+        };
+        let (func, frame) = match (self.funcs.get(&Name(name)), self.frames.get(&Name(name))) {
+            (Some(&func), Some(&frame)) => (func, frame),
+            _ => bail!("Unknown function or function block instance {}", name),
+        };
+        let layout = self.layout(name)?;
+        let is_function = matches!(self.pous[&Name(name)].kind, POUType::Function { .. });
+        self.assign_args(ctx, &layout, Place::Abs(frame), inputs, is_function)?;
+        ctx.emit(Instr::Call(func));
+        self.fetch_outputs(ctx, &layout, Place::Abs(frame), outputs)?;
+        match self.pous[&Name(name)].kind {
+            POUType::Function { .. } => {
+                let ret = &layout.fields[0];
+                let lv = LValue::new(Place::Abs(frame + ret.offset), ret.ty.clone());
+                self.load(ctx, &lv)?;
+                Ok(Some(lv.ty))
+            }
+            _ => Ok(None)
+        }
+    }
+
+    /// Compile a call of a method or action on an instance, or of a function
+    /// block instance that is a member of another instance or struct.
+    fn call_member(&mut self, ctx: &mut Context<'a>, base: &'a Expr, name: &'a str,
+                   inputs: &[(Option<&'a str>, &'a Expr)],
+                   outputs: &[(&'a str, &'a Expr)]) -> Result<Option<Ty>, Error> {
+        if let ExprKind::Name(ref qual) = base.kind {
+            if self.lookup(ctx, qual).is_none() {
+                // functions and programs qualified with their library
+                if self.is_namespace(qual)? {
+                    if let Some(key) = self.library_pou(qual, name) {
+                        return self.call_name(ctx, key, inputs, outputs);
+                    }
+                }
+                let pou = self.pous.get(&Name(&qual[..]));
+                if let Some(&&POU { kind: POUType::Program { .. }, .. }) = pou {
+                    if let Some(&func) = self.actions.get(&(Name(&qual[..]), Name(name))) {
+                        return self.call_program_action(ctx, func, inputs, outputs);
+                    }
+                }
+                if let Some((place, ty)) = self.lookup_qualified(qual, name)? {
+                    let lv = self.deref(ctx, place, ty);
+                    return self.call_instance(ctx, lv, None, inputs, outputs);
+                }
+            }
+        }
+        let lv = self.lvalue(ctx, base)?;
+        if let Ty::FBlock(ref class) = lv.ty {
+            if lv.bit.is_none() && (self.find_action(class, name)?.is_some() ||
+                                    self.find_method(class, name)?.is_some()) {
+                // methods called via SUPER^ are not dispatched dynamically
+                return self.call_on(ctx, lv.place, &lv.ty, name, inputs, outputs,
+                                    !is_super(base));
+            }
+        }
+        if let Ty::Interface(_) = lv.ty {
+            let ty = lv.ty.clone();
+            let place = self.instance_place(ctx, lv)?;
+            return self.call_on(ctx, place, &ty, name, inputs, outputs, true);
+        }
+        let (place, ty) = self.member(lv, name)?;
+        let lv = self.deref(ctx, place, ty);
+        self.call_instance(ctx, lv, None, inputs, outputs)
+    }
+
+    /// Compile a call of a function block instance, running its body or the
+    /// given action.
+    fn call_instance(&mut self, ctx: &mut Context<'a>, lv: LValue, func: Option<Func>,
+                     inputs: &[(Option<&'a str>, &'a Expr)],
+                     outputs: &[(&'a str, &'a Expr)]) -> Result<Option<Ty>, Error> {
+        let fbname = match lv.ty {
+            Ty::FBlock(ref fbname) if lv.bit.is_none() => fbname.clone(),
+            _ => bail!("Expression is not a function block instance"),
+        };
+        let place = self.spill(ctx, lv.place);
+        let func = func.unwrap_or(self.funcs[&Name(&fbname[..])]);
+        let layout = self.layout(&fbname)?;
+        self.assign_args(ctx, &layout, place, inputs, false)?;
+        self.emit_addr(ctx, place);
+        ctx.emit(Instr::CallInst(func));
+        self.fetch_outputs(ctx, &layout, place, outputs)?;
+        Ok(None)
+    }
+
+    /// Compile a call of an action of a program.
+    fn call_program_action(&mut self, ctx: &mut Context<'a>, func: Func,
+                           inputs: &[(Option<&'a str>, &'a Expr)],
+                           outputs: &[(&'a str, &'a Expr)]) -> Result<Option<Ty>, Error> {
+        if !inputs.is_empty() || !outputs.is_empty() {
+            bail!("Actions of programs take no arguments");
+        }
+        ctx.emit(Instr::Call(func));
+        Ok(None)
+    }
+
+    /// Compile a call of a method or action on the instance at `place`,
+    /// which is of the given function block or interface type.
+    #[allow(clippy::too_many_arguments)]
+    fn call_on(&mut self, ctx: &mut Context<'a>, place: Place, class: &Ty, name: &str,
+               inputs: &[(Option<&'a str>, &'a Expr)], outputs: &[(&'a str, &'a Expr)],
+               dynamic: bool) -> Result<Option<Ty>, Error> {
+        if let Ty::FBlock(ref class) = *class {
+            if let Some(func) = self.find_action(class, name)? {
+                let lv = LValue::new(place, Ty::FBlock(class.clone()));
+                return self.call_instance(ctx, lv, Some(func), inputs, outputs);
+            }
+        }
+        match self.call_method(ctx, place, class, name, inputs, outputs, dynamic)? {
+            Some(ret) => {
+                self.load(ctx, &ret)?;
+                Ok(Some(ret.ty))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Compile a call of a method, returning the place of the return value.
+    ///
+    /// Dynamic calls go to the implementation of the instance's actual class.
+    #[allow(clippy::too_many_arguments)]
+    fn call_method(&mut self, ctx: &mut Context<'a>, place: Place, class: &Ty, name: &str,
+                   inputs: &[(Option<&'a str>, &'a Expr)], outputs: &[(&'a str, &'a Expr)],
+                   dynamic: bool) -> Result<Option<LValue>, Error> {
+        let method = match self.lookup_method(class, name)? {
+            Some(method) => method,
+            None => match *class {
+                Ty::FBlock(ref class) | Ty::Interface(ref class) =>
+                    bail!("{} has no method {}", class, name),
+                _ => bail!("Value of type {:?} has no methods", class),
+            },
+        };
+        let frame = Place::Abs(self.method_frames[&(Name(method.root), Name(name.to_string()))]);
+        // an address on the stack stays below the arguments
+        self.assign_args(ctx, &method.layout, frame, inputs, true)?;
+        self.emit_addr(ctx, place);
+        if dynamic {
+            ctx.emit(Instr::CallMethod(self.slots[&Name(name.to_string())]));
+        } else {
+            ctx.emit(Instr::CallInst(method.func));
+        }
+        self.fetch_outputs(ctx, &method.layout, frame, outputs)?;
+        if method.returns {
+            let ret = &method.layout.fields[0];
+            Ok(Some(LValue::new(frame.add(ret.offset), ret.ty.clone())))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Store call arguments into the input variables of the callee.
+    ///
+    /// All arguments are evaluated onto the stack before the first one is
+    /// stored, since evaluating one may call the same callee again.
+    ///
+    /// If `defaults` is given, omitted inputs are set to their initial
+    /// value, as for functions and methods.  Function blocks keep the values
+    /// of omitted inputs from the last call.
+    fn assign_args(&mut self, ctx: &mut Context<'a>, layout: &Layout<'a>, base: Place,
+                   args: &[(Option<&'a str>, &'a Expr)], defaults: bool) -> Result<(), Error> {
+        let inputs = layout.fields.iter().filter(|f| f.input).collect::<Vec<_>>();
+        let mut fields = Vec::with_capacity(args.len());
+        for (i, &(name, _)) in args.iter().enumerate() {
+            fields.push(match name {
+                // outputs and local variables cannot be assigned
+                Some(name) => match layout.field(name) {
+                    Some(field) if field.input => field,
+                    _ => bail!("Callee has no input {}", name),
+                },
+                None => match inputs.get(i) {
+                    Some(field) => field,
+                    None => bail!("Too many arguments in call"),
+                }
+            });
+        }
+        let omitted = inputs.iter().filter(|f| !fields.iter().any(|a| a.name == f.name))
+                                   .collect::<Vec<_>>();
+        if let Some(field) = omitted.iter().find(|f| matches!(f.ty, Ty::Ref(_))) {
+            bail!("Missing argument for VAR_IN_OUT {}", field.name);
+        }
+        let mut targets = Vec::with_capacity(args.len());
+        for (field, &(_, expr)) in fields.iter().zip(args) {
+            let place = base.add(field.offset);
+            if let Ty::Ref(ref inner) = field.ty {
+                if !field.flags.constant && self.is_constant(ctx, expr)? {
+                    bail!("Cannot pass a constant to VAR_IN_OUT {}", field.name);
+                }
+                let arg = self.lvalue(ctx, expr)?;
+                if !self.is_subtype(&arg.ty, inner)? || arg.bit.is_some() {
+                    bail!("Invalid argument for VAR_IN_OUT {}", field.name);
+                }
+                self.emit_addr(ctx, arg.place);
+                targets.push((place, PTR_TYPE));
+            } else {
+                self.coerced_expr(ctx, expr, &field.ty)?;
+                targets.push((place, self.val_type(&field.ty)?));
+            }
+        }
+        for (place, typ) in targets.into_iter().rev() {
+            // the base address must go below the value
+            self.prepare_access(ctx, place);
+            if let Place::Rel(_) | Place::Deref(..) = place {
+                ctx.emit(Instr::Swap);
+            }
+            self.store_place(ctx, place, typ, None);
+        }
+        if defaults {
+            for field in omitted {
+                self.init_place(ctx, base.add(field.offset), &field.ty, field.default, true)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Copy output variables of the callee to the given targets.
+    fn fetch_outputs(&mut self, ctx: &mut Context<'a>, layout: &Layout<'a>, base: Place,
+                     outputs: &[(&'a str, &'a Expr)]) -> Result<(), Error> {
+        for &(name, target) in outputs {
+            let field = match layout.field(name) {
+                Some(field) => field,
+                None => bail!("Callee has no output {}", name),
+            };
+            self.check_writable(ctx, target)?;
+            let lv = self.lvalue(ctx, target)?;
+            self.begin_store(ctx, &lv);
+            let src = LValue::new(base.add(field.offset), field.ty.clone());
+            self.load(ctx, &src)?;
+            self.coerce(ctx, &field.ty, &lv.value_ty())?;
+            self.store(ctx, &lv)?;
+        }
+        Ok(())
+    }
+}
+
+/// Get the variable sections of a POU and of its methods and property
+/// accessors.
+fn var_blocks(pou: &POUType) -> Vec<&[VarBlock]> {
+    let mut result = vec![];
+    match *pou {
+        POUType::Program { ref vars, .. } | POUType::Function { ref vars, .. } => {
+            result.push(&vars[..]);
+        }
+        POUType::FBlock { ref vars, ref methods, ref properties, .. } => {
+            result.push(&vars[..]);
+            result.extend(methods.iter().map(|m| &m.vars[..]));
+            for prop in properties {
+                result.extend(prop.get.iter().chain(&prop.set).map(|a| &a.vars[..]));
+            }
+        }
+        _ => {}
+    }
+    result
+}
+
+/// Name under which a property getter or setter is registered as a method.
+fn accessor_name(property: &str, set: bool) -> String {
+    format!("{}.{}", property, if set { "SET" } else { "GET" })
+}
+
+/// Get the maximum alignment of fields given by a `pack_mode` attribute.
+fn pack_mode(attrs: &[Attribute]) -> Result<Option<usize>, Error> {
+    let attr = match find_attribute(attrs, "pack_mode") {
+        Some(attr) => attr,
+        None => return Ok(None),
+    };
+    match attr.value.as_ref().and_then(|v| v.trim().parse().ok()) {
+        // 0 means no gaps, just like 1
+        Some(0) => Ok(Some(1)),
+        Some(pack @ 1) | Some(pack @ 2) | Some(pack @ 4) | Some(pack @ 8) => Ok(Some(pack)),
+        _ => bail!("Invalid pack_mode {:?}", attr.value.as_ref().map_or("", |v| &v[..])),
+    }
+}
+
+/// Determine if an expression is `SUPER^`.
+fn is_super(expr: &Expr) -> bool {
+    match expr.kind {
+        ExprKind::Deref(ref inner) => match inner.kind {
+            ExprKind::Name(ref name) => name.eq_ignore_ascii_case("SUPER"),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Attach the location of the code being compiled to an error, unless it
+/// already has a more precise one.
+fn locate(ctx: &Context, err: Error, span: Span) -> Error {
+    match ctx.source {
+        Some(source) if err.downcast_ref::<Diagnostic>().is_none() =>
+            Diagnostic::at(source.path.as_deref(), &source.map, span,
+                           err.to_string()).into(),
+        _ => err,
+    }
+}
+
+/// Determine if a value can be converted without an explicit conversion
+/// function: to a wider type of the same kind, or from an integer to a
+/// floating point type.
+fn implicit_conversion(from: &Ty, to: &Ty) -> bool {
+    match (from, to) {
+        (&Ty::Pointer(_), &Ty::Pointer(_)) => return true,
+        // pointer arithmetic is done in 64-bit integers
+        (_, &Ty::Pointer(_)) => return match from.elementary_val_type() {
+            Some(typ) => typ.is_integer() && typ.size() == PTR_SIZE,
+            None => false,
+        },
+        (&Ty::Pointer(_), _) | (&Ty::Interface(_), _) | (_, &Ty::Interface(_)) => return false,
+        _ => {}
+    }
+    let (from, to) = match (from.elementary_val_type(), to.elementary_val_type()) {
+        (Some(from), Some(to)) => (from, to),
+        _ => return false,
+    };
+    match (from, to) {
+        (ValType::String(_), ValType::String(_)) |
+        (ValType::WString(_), ValType::WString(_)) |
+        (ValType::Time, ValType::LTime) |
+        (ValType::Real, ValType::LReal) => true,
+        (from, to) if from.is_integer() && to.is_float() => true,
+        (from, to) if from.is_integer() && to.is_integer() => {
+            if from.is_signed() {
+                to.is_signed() && to.size() >= from.size()
+            } else if to.is_signed() {
+                to.size() > from.size()
+            } else {
+                to.size() >= from.size()
+            }
+        }
+        (from, to) => from == to,
+    }
+}
+
+/// The name a POU is known by in the compiler.  Library POUs are qualified
+/// with the namespace, so that libraries can use the same names as the
+/// project and each other.
+fn pou_key(pou: &POU) -> String {
+    match pou.namespace {
+        Some(ref ns) if !pou.name.is_empty() => format!("{}.{}", ns, pou.name),
+        _ => pou.name.clone(),
+    }
+}
+
+/// List the namespaces of the given qualified names of library POUs.
+fn library_list(keys: &[&str]) -> String {
+    keys.iter().map(|key| key.split('.').next().unwrap()).collect::<Vec<_>>().join(", ")
+}
+
+/// Find a global variable or list by unqualified name.  The project's own
+/// definition takes precedence, and otherwise the name must be defined by
+/// only one library.
+fn find_unqualified<'m, T>(map: &'m HashMap<GlobalKey, T>,
+                           name: &'m str) -> Result<Option<&'m T>, Error> {
+    if let Some(item) = map.get(&(None, Name(name))) {
+        return Ok(Some(item));
+    }
+    let mut found = map.iter().filter(|(k, _)| k.1 == Name(name)).collect::<Vec<_>>();
+    match found.len() {
+        0 => Ok(None),
+        1 => Ok(Some(found[0].1)),
+        _ => {
+            found.sort_by_key(|(k, _)| k.0);
+            let libs = found.iter().filter_map(|(k, _)| k.0.map(|ns| ns.0)).collect::<Vec<_>>();
+            bail!("{} is ambiguous: it is defined by the libraries {}, qualify it with the \
+                   namespace", name, libs.join(", "))
+        }
+    }
+}
+
+fn align_up(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+
+/// Determine if a FOR loop step is a constant, and if so, whether it is positive.
+fn const_sign(expr: &Expr) -> Option<bool> {
+    match expr.kind {
+        ExprKind::Lit(Lit::Int(_, i)) => Some(i >= 0),
+        ExprKind::Unary(UnOp::Neg, ref inner) => const_sign(inner).map(|s| !s),
+        _ => None
+    }
+}
+
+/// Get the target type of a conversion function like INT_TO_REAL or TO_REAL.
+///
+/// Both sides must be elementary types, other names like X_TO_INT are left
+/// to user functions.
+fn conversion_target(name: &str) -> Option<Ty> {
+    let name = name.to_ascii_uppercase();
+    let (source, target) = match name.rfind("TO_") {
+        Some(0) => (None, &name[3..]),
+        Some(i) if name[..i].ends_with('_') => (Some(&name[..i-1]), &name[i+3..]),
+        _ => return None,
+    };
+    let elementary = |name: &str| match name {
+        "STRING" => Some(Ty::String(80)),
+        "WSTRING" => Some(Ty::WString(80)),
+        _ => Ty::elementary(name),
+    };
+    if source.is_some_and(|source| elementary(source).is_none()) {
+        return None;
+    }
+    elementary(target)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+    use charon_parsers::{parse_tc2_file, parse_tc3_file, parse_pou_str, parse_method_str};
+    use charon_parsers::{Defines, Dialect};
+    use st::ast::{Library, Project, POU, POUType};
+    use st::runtime::*;
+    use st::Diagnostic;
+
+    /// Write a source file with a fresh name and the given extension into a
+    /// temporary directory.
+    fn temp_file(ext: &str, contents: &str) -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let dir = env::temp_dir().join(format!("charon-{}-compile", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{}.{}", COUNTER.fetch_add(1, Ordering::SeqCst), ext));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    /// Parse the objects of the given TwinCat 2 sources.
+    fn parse_tc2(sources: &[&str]) -> Vec<POU> {
+        sources.iter()
+               .flat_map(|src| parse_tc2_file(temp_file("exp", src), &Defines::new()).unwrap())
+               .collect()
+    }
+
+    /// Parse the given POUs from TwinCat 3 sources.  Methods follow the
+    /// function block they belong to, like in the TwinCat 3 editor.
+    fn parse_tc3_str(sources: &[&str]) -> Vec<POU> {
+        let mut pous = vec![];
+        for src in sources {
+            if src.starts_with("METHOD") {
+                match pous.last_mut() {
+                    Some(&mut POU { kind: POUType::FBlock { ref mut methods, .. }, .. }) =>
+                        methods.push(parse_method_str(src).unwrap()),
+                    _ => panic!("method without function block"),
+                }
+            } else {
+                pous.push(parse_pou_str(Dialect::TwinCat3, src).unwrap());
+            }
+        }
+        pous
+    }
+
+    /// Compile already parsed POUs.
+    fn compile_pous(pous: Vec<POU>) -> Result<Program, ::failure::Error> {
+        super::compile_project(&Project { pous, tasks: vec![], libraries: vec![] })
+    }
+
+    /// Parse the given POUs from TwinCat 2 sources and compile them.
+    pub(crate) fn compile(sources: &[&str]) -> Result<Program, ::failure::Error> {
+        compile_pous(parse_tc2(sources))
+    }
+
+    /// Parse a TwinCat 3 file of the given type with the given XML content.
+    fn parse_tc3(ext: &str, content: &str) -> POU {
+        let path = temp_file(ext, &format!("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n\
+                                            <TcPlcObject Version=\"1.1.0.1\">{}</TcPlcObject>",
+                                           content));
+        parse_tc3_file(&path, &Defines::new()).unwrap().unwrap()
+    }
+
+    /// Like `run`, but with already parsed POUs.
+    fn run_pous(pous: Vec<POU>) -> Runtime {
+        run_program(compile_pous(pous).unwrap())
+    }
+
+    /// Compile the given POUs and run one cycle of the program MAIN.
+    pub(crate) fn run(sources: &[&str]) -> Runtime {
+        run_program(compile(sources).unwrap())
+    }
+
+    fn run_program(program: Program) -> Runtime {
+        let task = TaskConfig { name: "Task".into(), cycle_time: Duration::from_millis(10),
+                                priority: 20, programs: vec!["MAIN".into()] };
+        let mut rt = Runtime::new(program, &[task]).unwrap();
+        rt.run_cycle().unwrap();
+        rt
+    }
+
+    #[test]
+    fn statements() {
+        let rt = run(&["FUNCTION Classify : INT VAR_INPUT x : INT; END_VAR \
+                        CASE x OF 1, 2: Classify := 10; 3..5: Classify := 20; \
+                        ELSE Classify := -1; RETURN; END_CASE Classify := Classify + 1; \
+                        END_FUNCTION",
+                       "PROGRAM MAIN VAR a : INT; b : INT; c : INT; d : INT; e : INT; \
+                        n : INT; f : BOOL; END_VAR \
+                        a := Classify(2); b := Classify(x := 4); c := Classify(9); \
+                        IF a > 20 THEN d := 1; ELSIF a > 10 THEN d := 2; ELSE d := 3; END_IF \
+                        WHILE n < 4 DO n := n + 1; END_WHILE \
+                        f := a = 11 AND_THEN b = 21 OR_ELSE c = 0; \
+                        e := 7 MOD 4 - 9 / 2 + 10; END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.a").unwrap(), Value::Int(11));
+        assert_eq!(rt.read("MAIN.b").unwrap(), Value::Int(21));
+        assert_eq!(rt.read("MAIN.c").unwrap(), Value::Int(-1));
+        assert_eq!(rt.read("MAIN.d").unwrap(), Value::Int(2));
+        assert_eq!(rt.read("MAIN.n").unwrap(), Value::Int(4));
+        assert_eq!(rt.read("MAIN.f").unwrap(), Value::Bool(true));
+        assert_eq!(rt.read("MAIN.e").unwrap(), Value::Int(9));
+    }
+
+    #[test]
+    fn calls() {
+        let acc = "FUNCTION_BLOCK Acc VAR_INPUT n : INT; END_VAR \
+                   VAR_IN_OUT total : INT; END_VAR VAR_OUTPUT last : INT; END_VAR \
+                   total := total + n; last := n; END_FUNCTION_BLOCK";
+        let f = "FUNCTION F : INT VAR_INPUT a : INT; b : INT; END_VAR F := 10 * a + b; \
+                 END_FUNCTION";
+        compile(&[acc, f, "PROGRAM MAIN VAR acc : Acc; sum : INT; last : INT; END_VAR \
+                           acc(n := 2, total := sum); acc(total := sum, n := F(1, F(2, 3)), \
+                           last => last); END_PROGRAM"]).unwrap();
+
+        let error = |main: &str| {
+            let err = compile(&[acc, f, main]).err().unwrap();
+            err.downcast_ref::<Diagnostic>().unwrap().message.clone()
+        };
+        assert_eq!(error("PROGRAM MAIN VAR x : INT; END_VAR x := F(1, 2, 3); END_PROGRAM"),
+                   "Too many arguments in call");
+        assert_eq!(error("PROGRAM MAIN VAR x : INT; END_VAR x := F(c := 1); END_PROGRAM"),
+                   "Callee has no input c");
+        assert_eq!(error("PROGRAM MAIN VAR acc : Acc; t : INT; END_VAR \
+                          acc(total := t, last := 1); END_PROGRAM"), "Callee has no input last");
+        assert_eq!(error("PROGRAM MAIN VAR acc : Acc; b : BOOL; END_VAR acc(total := b); \
+                          END_PROGRAM"), "Invalid argument for VAR_IN_OUT total");
+        assert_eq!(error("PROGRAM MAIN VAR acc : Acc; t : INT; END_VAR \
+                          acc(total := t, sum => t); END_PROGRAM"), "Callee has no output sum");
+        assert_eq!(error("PROGRAM MAIN VAR acc : Acc; END_VAR acc(n := 1); END_PROGRAM"),
+                   "Missing argument for VAR_IN_OUT total");
+        assert_eq!(error("PROGRAM MAIN VAR x : INT; END_VAR x := G(1); END_PROGRAM"),
+                   "Unknown function or function block instance G");
+        assert_eq!(error("PROGRAM MAIN VAR x : INT; END_VAR y := 1; END_PROGRAM"),
+                   "Unknown variable y");
+    }
+
+    #[test]
+    fn for_and_repeat_loops() {
+        let rt = run(&["PROGRAM MAIN VAR i : INT; up : INT; down : INT; n : INT; \
+                        empty : INT; END_VAR \
+                        FOR i := 1 TO 10 DO up := up + i; END_FOR \
+                        FOR i := 10 TO 1 BY -3 DO down := down * 10 + i; END_FOR \
+                        FOR i := 5 TO 1 DO empty := empty + 1; END_FOR \
+                        REPEAT n := n + 1; UNTIL n >= 3 END_REPEAT END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.up").unwrap(), Value::Int(55));
+        assert_eq!(rt.read("MAIN.down").unwrap(), Value::Int(10741));
+        assert_eq!(rt.read("MAIN.empty").unwrap(), Value::Int(0));
+        assert_eq!(rt.read("MAIN.n").unwrap(), Value::Int(3));
+    }
+
+    #[test]
+    fn exit_and_continue() {
+        let rt = run(&["PROGRAM MAIN VAR i : INT; j : INT; odd : INT; pairs : INT; \
+                        w : INT; r : INT; END_VAR \
+                        FOR i := 1 TO 10 DO IF i MOD 2 = 0 THEN CONTINUE; END_IF \
+                        IF i > 7 THEN EXIT; END_IF odd := odd + i; END_FOR \
+                        FOR i := 1 TO 3 DO FOR j := 1 TO 3 DO IF j > i THEN EXIT; END_IF \
+                        pairs := pairs + 1; END_FOR END_FOR \
+                        WHILE TRUE DO w := w + 1; IF w < 5 THEN CONTINUE; END_IF EXIT; END_WHILE \
+                        REPEAT r := r + 1; IF r = 2 THEN EXIT; END_IF UNTIL FALSE END_REPEAT \
+                        END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.odd").unwrap(), Value::Int(16));
+        assert_eq!(rt.read("MAIN.pairs").unwrap(), Value::Int(6));
+        assert_eq!(rt.read("MAIN.w").unwrap(), Value::Int(5));
+        assert_eq!(rt.read("MAIN.r").unwrap(), Value::Int(2));
+        let err = compile(&["PROGRAM MAIN VAR i : INT; END_VAR IF i = 0 THEN EXIT; END_IF \
+                             END_PROGRAM"]).err().unwrap();
+        assert_eq!(err.downcast_ref::<Diagnostic>().unwrap().message, "EXIT outside of a loop");
+    }
+
+    #[test]
+    fn enums() {
+        let rt = run(&["TYPE E_State : (Idle, Running := 5, Done) END_TYPE",
+                       "TYPE E_Mode : (Auto, Manual) BYTE END_TYPE",
+                       "PROGRAM MAIN VAR s : E_State; next : E_State := Running; \
+                        m : E_Mode := E_Mode.Manual; d : INT; n : INT; b : BOOL; END_VAR \
+                        s := next; CASE s OF Idle: n := 1; E_State.Running: n := 2; \
+                        E_State.Done: n := 3; END_CASE d := Done; \
+                        b := m = E_Mode.Manual AND s > Idle; END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.s").unwrap(), Value::Int(5));
+        assert_eq!(rt.read("MAIN.n").unwrap(), Value::Int(2));
+        assert_eq!(rt.read("MAIN.d").unwrap(), Value::Int(6));
+        assert_eq!(rt.read("MAIN.m").unwrap(), Value::Byte(1));
+        assert_eq!(rt.read("MAIN.b").unwrap(), Value::Bool(true));
+        assert!(compile(&["TYPE E_Mode : (Auto, Manual) END_TYPE",
+                          "PROGRAM MAIN VAR m : E_Mode; END_VAR m := E_Mode.Other; END_PROGRAM"])
+                .is_err());
+    }
+
+    #[test]
+    fn unions_pointers_and_arrays() {
+        let rt = run(&["TYPE U : UNION w : WORD; b : ARRAY[0..1] OF BYTE; END_UNION END_TYPE",
+                       "TYPE Small : INT(0..100) END_TYPE",
+                       "PROGRAM MAIN VAR u : U; m : ARRAY[1..2, 0..2] OF INT; s : Small; \
+                        p : POINTER TO INT; r : REFERENCE TO INT; x : INT; y : UDINT; \
+                        lo : BYTE; END_VAR \
+                        u.w := 16#1234; lo := u.b[0]; m[2, 1] := 7; s := 42; \
+                        p := ADR(m[2, 1]); x := p^ + 1; p^ := 8; \
+                        r REF= x; r := r * 2; y := SIZEOF(m) + SIZEOF(u); END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.lo").unwrap(), Value::Byte(0x34));
+        assert_eq!(rt.read("MAIN.u.b[1]").unwrap(), Value::Byte(0x12));
+        assert_eq!(rt.read("MAIN.m[2, 1]").unwrap(), Value::Int(8));
+        assert_eq!(rt.read("MAIN.s").unwrap(), Value::Int(42));
+        assert_eq!(rt.read("MAIN.x").unwrap(), Value::Int(16));
+        assert_eq!(rt.read("MAIN.y").unwrap(), Value::UDInt(14));
+        assert!(compile(&["TYPE Bad : REAL(0..1) END_TYPE",
+                          "PROGRAM MAIN VAR b : Bad; END_VAR b := 0; END_PROGRAM"]).is_err());
+    }
+
+    #[test]
+    fn subranges() {
+        let percent = "TYPE Percent : INT(0..100) END_TYPE";
+        let rt = run(&[percent,
+                       "PROGRAM MAIN VAR p : Percent := 50; a : ARRAY[1..2] OF Percent; \
+                        q : INT(-5..5); x : INT := 70; n : INT; END_VAR \
+                        p := p + 20; a[2] := x; q := -5; \
+                        FOR q := -5 TO 5 DO n := n + 1; END_FOR END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.p").unwrap(), Value::Int(70));
+        assert_eq!(rt.read("MAIN.a[2]").unwrap(), Value::Int(70));
+        assert_eq!(rt.read("MAIN.n").unwrap(), Value::Int(11));
+
+        // constants are checked when compiling
+        for decl in &["p : Percent := 101;", "p : Percent := -1;", "q : INT(-5..5) := 6;"] {
+            let src = format!("PROGRAM MAIN VAR {} END_VAR ; END_PROGRAM", decl);
+            let err = compile(&[percent, &src]).err().unwrap();
+            assert!(err.to_string().contains("out of subrange"), "{}", err);
+        }
+        let err = compile(&[percent, "PROGRAM MAIN VAR p : Percent; END_VAR p := 100 + 1; \
+                                      END_PROGRAM"]).err().unwrap();
+        assert_eq!(err.downcast_ref::<Diagnostic>().unwrap().message,
+                   "Value 101 out of subrange 0..100");
+        // other values when storing them
+        let program = compile(&[percent, "FUNCTION Half : INT VAR_INPUT p : Percent; END_VAR \
+                                          Half := p / 2; END_FUNCTION",
+                                "PROGRAM MAIN VAR p : Percent; x : INT; y : INT; END_VAR \
+                                 y := Half(x); x := x + 60; p := x * 2; END_PROGRAM"]).unwrap();
+        let task = TaskConfig { name: "Task".into(), cycle_time: Duration::from_millis(10),
+                                priority: 20, programs: vec!["MAIN".into()] };
+        let mut rt = Runtime::new(program, &[task]).unwrap();
+        let err = rt.run_cycle().err().unwrap();
+        assert_eq!(err.to_string(), "Error in task Task: Value 120 out of subrange 0..100");
+        rt.write("MAIN.x", Value::Int(-60)).unwrap();
+        let err = rt.run_cycle().err().unwrap();
+        assert_eq!(err.to_string(), "Error in task Task: Value -60 out of subrange 0..100");
+    }
+
+    #[test]
+    fn properties_actions_and_inheritance() {
+        let base = parse_tc3("TcPOU", "<POU Name=\"Base\">\
+            <Declaration><![CDATA[FUNCTION_BLOCK Base VAR v : INT; END_VAR]]></Declaration>\
+            <Implementation><ST><![CDATA[v := v + 1;]]></ST></Implementation>\
+            <Method Name=\"Describe\">\
+              <Declaration><![CDATA[METHOD Describe : INT]]></Declaration>\
+              <Implementation><ST><![CDATA[Describe := 1;]]></ST></Implementation>\
+            </Method>\
+            <Property Name=\"Value\">\
+              <Declaration><![CDATA[PROPERTY Value : INT]]></Declaration>\
+              <Get Name=\"Get\"><Declaration><![CDATA[VAR END_VAR]]></Declaration>\
+                <Implementation><ST><![CDATA[Value := v;]]></ST></Implementation></Get>\
+              <Set Name=\"Set\"><Declaration><![CDATA[]]></Declaration>\
+                <Implementation><ST><![CDATA[v := Value;]]></ST></Implementation></Set>\
+            </Property>\
+            <Action Name=\"Reset\">\
+              <Implementation><ST><![CDATA[v := 0;]]></ST></Implementation>\
+            </Action>\
+            </POU>");
+        let derived = parse_tc3("TcPOU", "<POU Name=\"Derived\">\
+            <Declaration><![CDATA[FUNCTION_BLOCK Derived EXTENDS Base]]></Declaration>\
+            <Implementation><ST><![CDATA[SUPER^();]]></ST></Implementation>\
+            <Method Name=\"Describe\">\
+              <Declaration><![CDATA[METHOD Describe : INT]]></Declaration>\
+              <Implementation><ST><![CDATA[Describe := SUPER^.Describe() + 10;]]></ST>\
+              </Implementation>\
+            </Method>\
+            </POU>");
+        let main = parse_tc3("TcPOU", "<POU Name=\"MAIN\">\
+            <Declaration><![CDATA[PROGRAM MAIN VAR d : Derived; b : Base; x : INT; y : INT; \
+            z : INT; END_VAR]]></Declaration>\
+            <Implementation><ST><![CDATA[d(); d(); d.Value := d.Value + 10; x := d.Value; \
+            d.Reset(); y := d.Value; z := d.Describe() * 100 + b.Describe();]]></ST>\
+            </Implementation></POU>");
+        let rt = run_pous(vec![base, derived, main]);
+        assert_eq!(rt.read("MAIN.x").unwrap(), Value::Int(12));
+        assert_eq!(rt.read("MAIN.y").unwrap(), Value::Int(0));
+        assert_eq!(rt.read("MAIN.z").unwrap(), Value::Int(1101));
+    }
+
+    #[test]
+    fn interfaces() {
+        let shape = parse_tc3("TcIO", "<Itf Name=\"I_Shape\">\
+            <Declaration><![CDATA[INTERFACE I_Shape]]></Declaration>\
+            <Method Name=\"Area\"><Declaration><![CDATA[METHOD Area : INT]]></Declaration>\
+            </Method></Itf>");
+        let named = parse_tc3("TcIO", "<Itf Name=\"I_Named\">\
+            <Declaration><![CDATA[INTERFACE I_Named EXTENDS I_Shape]]></Declaration>\
+            <Property Name=\"Id\"><Declaration><![CDATA[PROPERTY Id : INT]]></Declaration>\
+            <Get Name=\"Get\"><Declaration><![CDATA[]]></Declaration></Get></Property>\
+            </Itf>");
+        match named.kind {
+            POUType::Interface { ref extends, ref properties, .. } => {
+                assert_eq!(extends, &["I_Shape"]);
+                assert_eq!(properties[0].name, "Id");
+            }
+            _ => panic!("expected an interface"),
+        }
+        let square = parse_tc3("TcPOU", "<POU Name=\"Square\">\
+            <Declaration><![CDATA[FUNCTION_BLOCK Square IMPLEMENTS I_Named\n\
+              VAR_INPUT side : INT; END_VAR]]></Declaration>\
+            <Implementation><ST><![CDATA[]]></ST></Implementation>\
+            <Method Name=\"Area\"><Declaration><![CDATA[METHOD Area : INT]]></Declaration>\
+              <Implementation><ST><![CDATA[Area := side * side;]]></ST></Implementation>\
+            </Method>\
+            <Property Name=\"Id\"><Declaration><![CDATA[PROPERTY Id : INT]]></Declaration>\
+            <Get Name=\"Get\"><Declaration><![CDATA[]]></Declaration>\
+              <Implementation><ST><![CDATA[Id := 4;]]></ST></Implementation></Get></Property>\
+            </POU>");
+        let main = parse_tc3("TcPOU", "<POU Name=\"MAIN\">\
+            <Declaration><![CDATA[PROGRAM MAIN VAR sq : Square; shape : I_Shape; \
+            named : I_Named; area : INT; id : INT; unset : BOOL; END_VAR]]></Declaration>\
+            <Implementation><ST><![CDATA[unset := shape = 0; sq.side := 3; named := sq; \
+            shape := named; area := shape.Area(); id := named.Id;]]></ST></Implementation>\
+            </POU>");
+        let rt = run_pous(vec![shape, named, square, main]);
+        assert_eq!(rt.read("MAIN.unset").unwrap(), Value::Bool(true));
+        assert_eq!(rt.read("MAIN.area").unwrap(), Value::Int(9));
+        assert_eq!(rt.read("MAIN.id").unwrap(), Value::Int(4));
+
+        // all methods of the interface must be implemented
+        let shape = parse_tc3("TcIO", "<Itf Name=\"I_Shape\">\
+            <Declaration><![CDATA[INTERFACE I_Shape]]></Declaration>\
+            <Method Name=\"Area\"><Declaration><![CDATA[METHOD Area : INT]]></Declaration>\
+            </Method></Itf>");
+        let circle = parse_tc3("TcPOU", "<POU Name=\"Circle\">\
+            <Declaration><![CDATA[FUNCTION_BLOCK Circle IMPLEMENTS I_Shape]]></Declaration>\
+            <Implementation><ST><![CDATA[]]></ST></Implementation></POU>");
+        let project = Project { pous: vec![shape, circle], tasks: vec![], libraries: vec![] };
+        assert!(super::compile_project(&project).is_err());
+    }
+
+    #[test]
+    fn not_and_bitwise_operators() {
+        let rt = run(&["PROGRAM MAIN VAR a : BOOL := TRUE; b : BOOL; w : WORD := 16#00FF; \
+                        x : BOOL; y : BOOL; z : BOOL; nw : WORD; aw : WORD; ow : WORD; \
+                        xw : WORD; i : INT := 5; ni : INT; END_VAR \
+                        x := NOT a OR NOT b; y := NOT a AND b; z := NOT (i > 3) XOR a; \
+                        nw := NOT w; aw := w AND 16#0F0F; ow := w OR 16#F000; \
+                        xw := w XOR 16#0FF0; ni := NOT i; END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.x").unwrap(), Value::Bool(true));
+        assert_eq!(rt.read("MAIN.y").unwrap(), Value::Bool(false));
+        assert_eq!(rt.read("MAIN.z").unwrap(), Value::Bool(true));
+        assert_eq!(rt.read("MAIN.nw").unwrap(), Value::Word(0xff00));
+        assert_eq!(rt.read("MAIN.aw").unwrap(), Value::Word(0x000f));
+        assert_eq!(rt.read("MAIN.ow").unwrap(), Value::Word(0xf0ff));
+        assert_eq!(rt.read("MAIN.xw").unwrap(), Value::Word(0x0f0f));
+        assert_eq!(rt.read("MAIN.ni").unwrap(), Value::Int(-6));
+        let err = compile(&["PROGRAM MAIN VAR r : REAL; END_VAR r := NOT r; END_PROGRAM"])
+            .err().unwrap();
+        assert!(err.to_string().contains("NOT"), "{}", err);
+    }
+
+    #[test]
+    fn short_circuit_and_power() {
+        // the right operands would fail with a division by zero
+        let rt = run(&["PROGRAM MAIN VAR z : INT; a : BOOL; b : BOOL; p : LREAL; END_VAR \
+                        a := FALSE AND_THEN 1 / z = 1; b := TRUE OR_ELSE 1 / z = 1; \
+                        p := 2 ** 10; END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.a").unwrap(), Value::Bool(false));
+        assert_eq!(rt.read("MAIN.b").unwrap(), Value::Bool(true));
+        assert_eq!(rt.read("MAIN.p").unwrap(), Value::LReal(1024.0));
+    }
+
+    #[test]
+    fn pack_mode_and_call_after_init() {
+        let fb = |name: &str, attr: &str| parse_tc3("TcPOU", &format!("<POU Name=\"{0}\">\
+            <Declaration><![CDATA[{1} FUNCTION_BLOCK {0} VAR_OUTPUT calls : INT; END_VAR]]>\
+            </Declaration><Implementation><ST><![CDATA[]]></ST></Implementation>\
+            <Method Name=\"Init\">\
+              <Declaration><![CDATA[{{attribute 'call_after_init'}} METHOD Init]]></Declaration>\
+              <Implementation><ST><![CDATA[calls := calls + 1;]]></ST></Implementation>\
+            </Method>\
+            <Method Name=\"Other\"><Declaration><![CDATA[METHOD Other]]></Declaration>\
+              <Implementation><ST><![CDATA[calls := calls + 100;]]></ST></Implementation>\
+            </Method></POU>", name, attr));
+        let types = ["TYPE ST_Loose : STRUCT a : BYTE; b : DINT; END_STRUCT END_TYPE",
+                     "{attribute 'pack_mode' := '1'} \
+                      TYPE ST_Packed : STRUCT a : BYTE; b : DINT; END_STRUCT END_TYPE",
+                     "{attribute 'pack_mode' := '2'} \
+                      TYPE ST_Pack2 : STRUCT a : BYTE; b : DINT; END_STRUCT END_TYPE",
+                     "PROGRAM MAIN VAR loose : UDINT; packed : UDINT; pack2 : UDINT; \
+                      fb : FB_Init; fb2 : FB_NoInit; \
+                      s1 : ST_Loose; s2 : ST_Packed; s3 : ST_Pack2; END_VAR \
+                      loose := SIZEOF(s1); packed := SIZEOF(s2); pack2 := SIZEOF(s3); \
+                      END_PROGRAM"];
+        let mut pous = parse_tc2(&types);
+        pous.push(fb("FB_Init", "{attribute 'call_after_init'}"));
+        pous.push(fb("FB_NoInit", ""));
+        let rt = run_pous(pous);
+        assert_eq!(rt.read("MAIN.loose").unwrap(), Value::UDInt(8));
+        assert_eq!(rt.read("MAIN.packed").unwrap(), Value::UDInt(5));
+        assert_eq!(rt.read("MAIN.pack2").unwrap(), Value::UDInt(6));
+        // the init method runs once, and only if the FB has the attribute too
+        assert_eq!(rt.read("MAIN.fb.calls").unwrap(), Value::Int(1));
+        assert_eq!(rt.read("MAIN.fb2.calls").unwrap(), Value::Int(0));
+
+        assert!(compile(&["{attribute 'pack_mode' := '3'} \
+                           TYPE ST_Bad : STRUCT a : BYTE; END_STRUCT END_TYPE",
+                          "PROGRAM MAIN VAR s : ST_Bad; END_VAR ; END_PROGRAM"]).is_err());
+        // qualified_only members need their type
+        assert!(compile(&["{attribute 'qualified_only'} TYPE E_Mode : (Auto, Manual) END_TYPE",
+                          "PROGRAM MAIN VAR m : E_Mode; END_VAR m := Auto; END_PROGRAM"])
+                .is_err());
+    }
+
+    #[test]
+    fn literals() {
+        let rt = run(&["PROGRAM MAIN VAR t : TIME := T#1m2s500ms; lt : LTIME; d : DATE; \
+                        tod : TOD; dt : DT; w : WORD; b : BYTE := 2#1010_1010; i : INT; \
+                        s : STRING := 'a$'b$N'; ws : WSTRING := \"$00e4\"; r : REAL; END_VAR \
+                        t := t + TIME#1s; lt := LTIME#1us; d := D#2018-03-01; \
+                        tod := TIME_OF_DAY#01:00; dt := DT#1970-01-01-00:00:10; \
+                        w := WORD#16#FFFF; i := INT#-5 * 2; r := REAL#1.5; END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.t").unwrap(), Value::Time(63500));
+        assert_eq!(rt.read("MAIN.lt").unwrap(), Value::LTime(1000));
+        assert_eq!(rt.read("MAIN.d").unwrap(), Value::Date(1519862400));
+        assert_eq!(rt.read("MAIN.tod").unwrap(), Value::Tod(3600000));
+        assert_eq!(rt.read("MAIN.dt").unwrap(), Value::Dt(10));
+        assert_eq!(rt.read("MAIN.b").unwrap(), Value::Byte(0xaa));
+        assert_eq!(rt.read("MAIN.w").unwrap(), Value::Word(0xffff));
+        assert_eq!(rt.read("MAIN.i").unwrap(), Value::Int(-10));
+        assert_eq!(rt.read("MAIN.s").unwrap(), Value::String("a'b\n".into()));
+        assert_eq!(rt.read("MAIN.ws").unwrap(), Value::WString("\u{e4}".into()));
+        assert_eq!(rt.read("MAIN.r").unwrap(), Value::Real(1.5));
+        let rt = run(&["PROGRAM MAIN VAR u : ULINT := ULINT#18446744073709551615; lw : LWORD; \
+                        lr : LREAL := -2.5; END_VAR lw := LWORD#16#FFFF_FFFF_FFFF_FFFF; \
+                        lr := lr-1.5; END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.lr").unwrap(), Value::LReal(-4.0));
+        assert_eq!(rt.read("MAIN.u").unwrap(), Value::ULInt(u64::MAX));
+        assert_eq!(rt.read("MAIN.lw").unwrap(), Value::LWord(u64::MAX));
+        let err = compile(&["PROGRAM MAIN VAR x : USINT; END_VAR x := USINT#300; END_PROGRAM"])
+            .err().unwrap();
+        assert!(err.to_string().contains("300"), "{}", err);
+    }
+
+    #[test]
+    fn case_insensitive_names() {
+        let rt = run(&["function_block Fb var_input Amount : int; end_var \
+                        var_output Total : Int; end_var total := TOTAL + amount; \
+                        end_function_block",
+                       "program main var fb : FB; i : int; end_var \
+                        for I := 1 to 3 do Fb(AMOUNT := i); end_for end_program"]);
+        assert_eq!(rt.read("MAIN.FB.TOTAL").unwrap(), Value::Int(6));
+        assert_eq!(rt.read("main.fb.total").unwrap(), Value::Int(6));
+    }
+
+    #[test]
+    fn var_sections() {
+        let fb = parse_tc3("TcPOU", "<POU Name=\"FB_Count\">\
+            <Declaration><![CDATA[FUNCTION_BLOCK FB_Count \
+            VAR_OUTPUT temp : INT; stat : INT; inst : INT; local : INT; END_VAR \
+            VAR_TEMP t : INT := 5; END_VAR VAR_STAT s : INT; END_VAR]]></Declaration>\
+            <Implementation><ST><![CDATA[t := t + 1; temp := t; s := s + 1; stat := s; \
+            THIS^.Count();]]></ST></Implementation>\
+            <Method Name=\"Count\">\
+              <Declaration><![CDATA[METHOD Count VAR_INST n : INT; END_VAR \
+              VAR l : INT; END_VAR]]></Declaration>\
+              <Implementation><ST><![CDATA[n := n + 1; l := l + 1; inst := n; local := l;]]>\
+              </ST></Implementation>\
+            </Method></POU>");
+        let sources = ["VAR_GLOBAL g : INT := 7; END_VAR",
+                       "PROGRAM MAIN \
+                        VAR a : FB_Count; b : FB_Count; ext : INT; END_VAR \
+                        VAR CONSTANT c : INT := 3; END_VAR \
+                        VAR RETAIN r : INT; END_VAR VAR PERSISTENT p : INT; END_VAR \
+                        VAR_EXTERNAL g : INT; END_VAR \
+                        a(); a(); b(); ext := g + c; r := r + 1; p := p + 1; END_PROGRAM"];
+        let mut pous = parse_tc2(&sources);
+        pous.push(fb);
+        let mut rt = run_pous(pous);
+        assert_eq!(rt.read("MAIN.a.temp").unwrap(), Value::Int(6));
+        assert_eq!(rt.read("MAIN.a.inst").unwrap(), Value::Int(2));
+        assert_eq!(rt.read("MAIN.b.inst").unwrap(), Value::Int(1));
+        assert_eq!(rt.read("MAIN.a.local").unwrap(), Value::Int(1));
+        // static variables are shared by all instances
+        assert_eq!(rt.read("MAIN.a.stat").unwrap(), Value::Int(2));
+        assert_eq!(rt.read("MAIN.b.stat").unwrap(), Value::Int(3));
+        assert_eq!(rt.read("MAIN.ext").unwrap(), Value::Int(10));
+
+        rt.run_cycle().unwrap();
+        assert_eq!(rt.read("MAIN.r").unwrap(), Value::Int(2));
+        rt.reset(Reset::Warm).unwrap();
+        rt.run_cycle().unwrap();
+        assert_eq!(rt.read("MAIN.r").unwrap(), Value::Int(3));
+        assert_eq!(rt.read("MAIN.p").unwrap(), Value::Int(3));
+        assert_eq!(rt.read("MAIN.b.stat").unwrap(), Value::Int(3));
+        rt.reset(Reset::Cold).unwrap();
+        rt.run_cycle().unwrap();
+        assert_eq!(rt.read("MAIN.r").unwrap(), Value::Int(1));
+        assert_eq!(rt.read("MAIN.p").unwrap(), Value::Int(4));
+        rt.reset(Reset::Origin).unwrap();
+        rt.run_cycle().unwrap();
+        assert_eq!(rt.read("MAIN.p").unwrap(), Value::Int(1));
+
+        assert!(compile(&["PROGRAM MAIN VAR CONSTANT c : INT := 3; END_VAR c := 4; \
+                           END_PROGRAM"]).is_err());
+        assert!(compile(&["VAR_GLOBAL g : INT; END_VAR",
+                          "PROGRAM MAIN VAR_EXTERNAL g : BOOL; END_VAR ; END_PROGRAM"]).is_err());
+        assert!(compile(&["PROGRAM MAIN VAR_EXTERNAL h : INT; END_VAR ; END_PROGRAM"]).is_err());
+    }
+
+    #[test]
+    fn nested_calls_of_same_function() {
+        let rt = run(&["FUNCTION F : INT VAR_INPUT a : INT; b : INT; END_VAR F := 10 * a + b; \
+                        END_FUNCTION",
+                       "PROGRAM MAIN VAR x : INT; y : INT; END_VAR \
+                        x := F(1, F(2, 3)); y := F(b := F(4, 5), a := 6); END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.x").unwrap(), Value::Int(33));
+        assert_eq!(rt.read("MAIN.y").unwrap(), Value::Int(105));
+    }
+
+    #[test]
+    fn instance_and_in_out_arguments() {
+        let rt = run(&["FUNCTION_BLOCK Acc VAR_INPUT n : INT; END_VAR \
+                        VAR_IN_OUT total : INT; END_VAR VAR_OUTPUT last : INT; END_VAR \
+                        total := total + n; last := n; END_FUNCTION_BLOCK",
+                       "FUNCTION_BLOCK Outer VAR acc : Acc; sum : INT; END_VAR \
+                        acc(n := 2, total := sum); acc(total := sum, n := 5); \
+                        END_FUNCTION_BLOCK",
+                       "PROGRAM MAIN VAR o : Outer; last : INT; END_VAR \
+                        o(); o.acc(n := 4, total := o.sum, last => last); END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.o.sum").unwrap(), Value::Int(11));
+        assert_eq!(rt.read("MAIN.last").unwrap(), Value::Int(4));
+    }
+
+    #[test]
+    fn nested_method_calls() {
+        let rt = run_pous(parse_tc3_str(&[
+            "FUNCTION_BLOCK A END_FUNCTION_BLOCK",
+            "METHOD M : INT VAR_INPUT a : INT; b : INT; END_VAR M := 10 * a + b;",
+            "FUNCTION_BLOCK B EXTENDS A END_FUNCTION_BLOCK",
+            "METHOD M : INT VAR_INPUT a : INT; b : INT; END_VAR M := 100 * a + b;",
+            "PROGRAM MAIN VAR fa : A; fb : B; x : INT; y : INT; END_VAR \
+             x := fa.M(a := 1, b := fb.M(a := 2, b := 3)); \
+             y := fb.M(b := fa.M(4, 5), a := 6); END_PROGRAM"]));
+        assert_eq!(rt.read("MAIN.x").unwrap(), Value::Int(213));
+        assert_eq!(rt.read("MAIN.y").unwrap(), Value::Int(645));
+    }
+
+    #[test]
+    fn omitted_inputs() {
+        let rt = run_pous(parse_tc3_str(&[
+            "FUNCTION F : INT VAR_INPUT a : INT; b : INT := 5; c : INT; END_VAR \
+             F := 100 * a + 10 * b + c; END_FUNCTION",
+            "FUNCTION_BLOCK A VAR_INPUT n : INT; END_VAR VAR_OUTPUT out : INT; END_VAR \
+             out := n; END_FUNCTION_BLOCK",
+            "METHOD M : INT VAR_INPUT a : INT; b : INT := 5; END_VAR M := 10 * a + b;",
+            "PROGRAM MAIN VAR fa : A; x : INT; y : INT; z : INT; END_VAR \
+             x := F(1, 2, 3); x := F(a := 4); \
+             y := fa.M(1, 2); y := fa.M(a := 3); \
+             fa(n := 7); fa(); z := fa.out; END_PROGRAM"]));
+        // functions and methods start with initial values
+        assert_eq!(rt.read("MAIN.x").unwrap(), Value::Int(450));
+        assert_eq!(rt.read("MAIN.y").unwrap(), Value::Int(35));
+        // function blocks keep their inputs
+        assert_eq!(rt.read("MAIN.z").unwrap(), Value::Int(7));
+    }
+
+    #[test]
+    fn recursion_is_rejected() {
+        let err = compile_pous(parse_tc3_str(&[
+            "FUNCTION_BLOCK A END_FUNCTION_BLOCK",
+            "METHOD M : INT VAR_INPUT n : INT; END_VAR \
+             IF n > 0 THEN M := n + THIS^.M(n - 1); END_IF",
+            "PROGRAM MAIN VAR a : A; END_VAR a.M(3); END_PROGRAM"])).err().unwrap();
+        assert_eq!(err.to_string(), "Recursive call of A.M is not supported: A.M -> A.M");
+        let err = compile(&["FUNCTION F : INT VAR_INPUT n : INT; END_VAR F := G(n); END_FUNCTION",
+                            "FUNCTION G : INT VAR_INPUT n : INT; END_VAR \
+                             IF n > 0 THEN G := F(n - 1); END_IF END_FUNCTION",
+                            "PROGRAM MAIN VAR x : INT; END_VAR x := F(3); END_PROGRAM"])
+            .err().unwrap();
+        assert_eq!(err.to_string(), "Recursive call of F is not supported: F -> G -> F");
+    }
+
+    #[test]
+    fn implicit_conversions() {
+        let rt = run(&["PROGRAM MAIN VAR s : SINT := -5; u : USINT := 200; i : INT; d : DINT; \
+                        r : REAL; lr : LREAL; w : WORD; p : POINTER TO INT; n : INT; END_VAR \
+                        s := s + 1; i := s; i := i * 2 + u; d := i; r := d; lr := r * 2; \
+                        w := u; u := 255; p := 0; s := DINT_TO_SINT(d - 240); \
+                        n := 100 * 3 - (-2); END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.i").unwrap(), Value::Int(192));
+        assert_eq!(rt.read("MAIN.d").unwrap(), Value::DInt(192));
+        assert_eq!(rt.read("MAIN.lr").unwrap(), Value::LReal(384.0));
+        assert_eq!(rt.read("MAIN.w").unwrap(), Value::Word(200));
+        assert_eq!(rt.read("MAIN.u").unwrap(), Value::USInt(255));
+        assert_eq!(rt.read("MAIN.s").unwrap(), Value::SInt(-48));
+        assert_eq!(rt.read("MAIN.n").unwrap(), Value::Int(302));
+        for (decl, stmt) in &[("x : INT; y : STRING;", "x := y;"),
+                              ("x : INT; y : REAL;", "x := y;"),
+                              ("x : SINT; y : LREAL;", "x := y;"),
+                              ("x : DINT; y : POINTER TO INT;", "x := y;"),
+                              ("x : BOOL; y : INT;", "x := y;"),
+                              ("x : INT; y : BOOL;", "x := y;"),
+                              ("x : INT; y : DINT;", "x := y + 1;"),
+                              ("x : UINT; y : INT;", "x := y;"),
+                              ("x : USINT;", "x := 256;"),
+                              ("x : USINT;", "x := 200 + 2 * 30;"),
+                              ("x : INT;", "x := 1.5;")] {
+            let src = format!("PROGRAM MAIN VAR {} END_VAR {} END_PROGRAM", decl, stmt);
+            let err = compile(&[&src]).err().unwrap();
+            let diag = err.downcast_ref::<Diagnostic>().unwrap();
+            assert!(diag.message.starts_with("Cannot implicitly convert"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn conversion_functions() {
+        let rt = run(&["FUNCTION DEG_TO_INT : INT VAR_INPUT deg : REAL; END_VAR \
+                        DEG_TO_INT := REAL_TO_INT(deg / 10.0); END_FUNCTION",
+                       "FUNCTION LREAL_TO_DINT : DINT VAR_INPUT x : LREAL; END_VAR \
+                        LREAL_TO_DINT := 42; END_FUNCTION",
+                       "PROGRAM MAIN VAR a : INT; b : DINT; c : INT; d : STRING; END_VAR \
+                        a := DEG_TO_INT(900.0); b := LREAL_TO_DINT(1.0); c := TO_INT(b); \
+                        d := INT_TO_STRING(a); END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.a").unwrap(), Value::Int(90));
+        assert_eq!(rt.read("MAIN.b").unwrap(), Value::DInt(42));
+        assert_eq!(rt.read("MAIN.c").unwrap(), Value::Int(42));
+        assert_eq!(rt.read("MAIN.d").unwrap(), Value::String("90".into()));
+    }
+
+    #[test]
+    fn recursive_types_are_rejected() {
+        let err = compile(&["TYPE A : STRUCT b : B; END_STRUCT END_TYPE",
+                            "TYPE B : STRUCT c : ARRAY[0..1] OF C; END_STRUCT END_TYPE",
+                            "TYPE C : STRUCT a : A; END_STRUCT END_TYPE",
+                            "PROGRAM MAIN VAR a : A; END_VAR ; END_PROGRAM"]).err().unwrap();
+        assert_eq!(err.to_string(), "Type A contains itself: A -> B -> C -> A");
+        let err = compile(&["FUNCTION_BLOCK FB VAR inner : FB; END_VAR END_FUNCTION_BLOCK",
+                            "PROGRAM MAIN VAR fb : FB; END_VAR ; END_PROGRAM"]).err().unwrap();
+        assert_eq!(err.to_string(), "Type FB contains itself: FB -> FB");
+        // pointers to the type itself are fine
+        let rt = run(&["TYPE Node : STRUCT next : POINTER TO Node; v : INT; END_STRUCT END_TYPE",
+                       "PROGRAM MAIN VAR a : Node; b : Node; END_VAR \
+                        a.next := ADR(b); a.next^.v := 3; END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.b.v").unwrap(), Value::Int(3));
+    }
+
+    #[test]
+    fn library_namespaces() {
+        let compile_with_lib = |sources: &[&str]| {
+            let mut pous = vec![];
+            for src in &["FUNCTION_BLOCK Counter VAR_OUTPUT n : INT; END_VAR n := n + 1; \
+                          END_FUNCTION_BLOCK",
+                         "FUNCTION Twice : INT VAR_INPUT x : INT; END_VAR Twice := 2 * x; \
+                          END_FUNCTION",
+                         "VAR_GLOBAL gLimit : INT := 7; END_VAR"] {
+                let mut pou = parse_pou_str(Dialect::TwinCat3, src).unwrap();
+                pou.namespace = Some("Lib".into());
+                pous.push(pou);
+            }
+            for src in sources {
+                pous.push(parse_pou_str(Dialect::TwinCat3, src).unwrap());
+            }
+            let libraries = vec![Library { name: "Library".into(), namespace: "Lib".into(),
+                                           version: None }];
+            super::compile_project(&Project { pous, tasks: vec![], libraries })
+        };
+        let program = compile_with_lib(&[
+            "PROGRAM MAIN VAR c : Lib.Counter; x : INT; END_VAR \
+             c(); c(); x := Lib.Twice(c.n) + lib.gLimit; END_PROGRAM"]).unwrap();
+        let task = TaskConfig { name: "Task".into(), cycle_time: Duration::from_millis(10),
+                                priority: 20, programs: vec!["MAIN".into()] };
+        let mut rt = Runtime::new(program, &[task]).unwrap();
+        rt.run_cycle().unwrap();
+        assert_eq!(rt.read("MAIN.x").unwrap(), Value::Int(11));
+
+        for (src, msg) in &[
+            ("PROGRAM MAIN VAR c : Other.Counter; END_VAR ; END_PROGRAM",
+             "Unknown library namespace Other"),
+            ("FUNCTION_BLOCK Own END_FUNCTION_BLOCK\n\
+              PROGRAM MAIN VAR c : Lib.Own; END_VAR ; END_PROGRAM", "Library Lib has no POU Own"),
+        ] {
+            let sources = src.split('\n').collect::<Vec<_>>();
+            let err = compile_with_lib(&sources).err().unwrap();
+            assert_eq!(err.to_string(), *msg);
+        }
+        // a program must not be named like a namespace
+        let err = compile_with_lib(&["PROGRAM Lib VAR x : INT; END_VAR ; END_PROGRAM",
+                                     "PROGRAM MAIN VAR y : INT; END_VAR y := Lib.x; END_PROGRAM"])
+            .err().unwrap();
+        assert!(err.to_string().contains("Lib is ambiguous"), "{}", err);
+    }
+
+    #[test]
+    fn duplicate_library_definitions() {
+        let compile_with_libs = |sources: &[&str]| {
+            let mut pous = vec![];
+            for (ns, factor) in &[("LibA", 2), ("LibB", 3)] {
+                for src in &[format!("FUNCTION Scale : INT VAR_INPUT x : INT; END_VAR \
+                                      Scale := {} * x; END_FUNCTION", factor),
+                             format!("VAR_GLOBAL gLimit : INT := {}; END_VAR", factor * 10)] {
+                    let mut pou = parse_pou_str(Dialect::TwinCat3, src).unwrap();
+                    pou.namespace = Some(ns.to_string());
+                    pous.push(pou);
+                }
+            }
+            pous.extend(parse_tc2(sources));
+            let libraries = ["LibA", "LibB"].iter().map(|&ns| Library {
+                name: ns.into(), namespace: ns.into(), version: None
+            }).collect();
+            super::compile_project(&Project { pous, tasks: vec![], libraries })
+        };
+        let run_with_libs = |sources: &[&str]| run_program(compile_with_libs(sources).unwrap());
+
+        let rt = run_with_libs(&["PROGRAM MAIN VAR x : INT; y : INT; END_VAR \
+                                  x := LibA.Scale(1) + LibB.Scale(2); \
+                                  y := LibA.gLimit + LibB.gLimit; END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.x").unwrap(), Value::Int(8));
+        assert_eq!(rt.read("MAIN.y").unwrap(), Value::Int(50));
+        // the project's own definitions are used unqualified
+        let rt = run_with_libs(&["FUNCTION Scale : INT VAR_INPUT x : INT; END_VAR \
+                                  Scale := 10 * x; END_FUNCTION",
+                                 "VAR_GLOBAL gLimit : INT := 1; END_VAR",
+                                 "PROGRAM MAIN VAR x : INT; y : INT; END_VAR \
+                                  x := Scale(1) + LibB.Scale(1); y := gLimit; END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.x").unwrap(), Value::Int(13));
+        assert_eq!(rt.read("MAIN.y").unwrap(), Value::Int(1));
+        assert_eq!(rt.read("gLimit").unwrap(), Value::Int(1));
+
+        for stmt in &["x := Scale(1);", "x := gLimit;"] {
+            let src = format!("PROGRAM MAIN VAR x : INT; END_VAR {} END_PROGRAM", stmt);
+            let err = compile_with_libs(&[&src]).err().unwrap();
+            let msg = err.downcast_ref::<Diagnostic>().unwrap().message.clone();
+            assert!(msg.ends_with("is ambiguous: it is defined by the libraries LibA, LibB, \
+                                   qualify it with the namespace"), "{}", msg);
+        }
+    }
+
+    #[test]
+    fn errors_are_located() {
+        let err = compile(&["PROGRAM MAIN\nVAR x : INT; END_VAR\n\
+                             x := 1;\nIF x > 0 THEN\n    x := x + undefined;\nEND_IF\n\
+                             END_PROGRAM"]).err().unwrap();
+        let diag = err.downcast_ref::<Diagnostic>().unwrap();
+        assert_eq!(diag.message, "Unknown variable undefined");
+        assert_eq!((diag.span.line, diag.span.col), (5, 14));
+        assert_eq!(diag.token.as_ref().unwrap(), "undefined");
+        assert_eq!(diag.line, "    x := x + undefined;");
+        // errors in initial values are located too
+        let err = compile(&["VAR_GLOBAL\n  g : INT := 1 + z;\nEND_VAR"]).err().unwrap();
+        let diag = err.downcast_ref::<Diagnostic>().unwrap();
+        assert_eq!((diag.span.line, diag.span.col), (2, 18));
+    }
+
+    #[test]
+    fn named_global_variable_lists() {
+        let mut gvl = parse_pou_str(Dialect::TwinCat2, "VAR_GLOBAL gCount : INT; END_VAR").unwrap();
+        gvl.name = "Globale_Variablen".into();
+        let main = parse_pou_str(Dialect::TwinCat2, "PROGRAM MAIN VAR END_VAR \
+                                 Globale_Variablen.gCount := gCount + 2;").unwrap();
+        let program = super::compile_project(&Project { pous: vec![gvl, main], tasks: vec![],
+                                                        libraries: vec![] }).unwrap();
+        let task = TaskConfig { name: "Task".into(), cycle_time: Duration::from_millis(10),
+                                priority: 20, programs: vec!["MAIN".into()] };
+        let mut rt = Runtime::new(program, &[task]).unwrap();
+        rt.run_cycle().unwrap();
+        assert_eq!(rt.read("Globale_Variablen.gCount").unwrap(), Value::Int(2));
     }
 }
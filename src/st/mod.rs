@@ -22,20 +22,25 @@
 // *****************************************************************************
 
 pub mod ast;
+pub mod value;
+pub mod symbols;
 pub mod runtime;
+pub mod compile;
 
-pub use charon_parsers::{parse_tc2_project, parse_tc3_project};
+pub use charon_parsers::{parse_project, parse_tc2_project, parse_tc3_project, load_tc3_libraries,
+                         Defines, Dialect};
+pub use charon_parsers::diagnostic::Diagnostic;
 
 #[test]
 fn test_tc3() {
-    let proj = parse_tc3_project("CCMHTS/CCMHTS.plcproj");
+    let proj = parse_tc3_project("CCMHTS/CCMHTS.plcproj", &Defines::new());
     println!("{}", proj.0.pous.len());
     println!("{:#?}", proj.1);
 }
 
 #[test]
 fn test_tc2() {
-    let proj = parse_tc2_project("exps");
+    let proj = parse_tc2_project("exps", &Defines::new());
     println!("{}", proj.0.pous.len());
     println!("{:#?}", proj.1);
 }
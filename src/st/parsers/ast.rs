@@ -21,76 +21,272 @@
 //
 // *****************************************************************************
 
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use SourceMap;
+
+/// A range in the source file, as byte offsets, together with the line and
+/// column (both 1-based) where it starts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub col: usize,
+}
+
+impl Span {
+    /// Return a span that covers both this span and `other`.
+    pub fn to(self, other: Span) -> Span {
+        Span { end: other.end, ..self }
+    }
+}
 
 #[derive(Debug)]
 pub struct Project {
     pub pous: Vec<POU>,
+    /// The task configuration, only known for TwinCat 3 projects.
+    pub tasks: Vec<Task>,
+    /// Referenced libraries, only known for TwinCat 3 projects.
+    pub libraries: Vec<Library>,
+}
+
+/// A library referenced by a project.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Library {
+    pub name: String,
+    /// Namespace to qualify names from the library with.
+    pub namespace: String,
+    /// The referenced version, None for the newest one.
+    pub version: Option<String>,
+}
+
+/// A PLC task, which calls programs cyclically.
+#[derive(Clone, Debug)]
+pub struct Task {
+    pub name: String,
+    pub cycle_time: Duration,
+    /// Lower numbers mean higher priority.
+    pub priority: u32,
+    /// Names of the programs called by the task, in order.
+    pub programs: Vec<String>,
+}
+
+/// A POU or data type.
+#[derive(Debug)]
+pub struct POU {
+    pub name: String,
+    pub kind: POUType,
+    pub attrs: Vec<Attribute>,
+    /// Documentation from the comment before the declaration.
+    pub doc: Option<String>,
+    /// The folder in the project it is in, outermost first.
+    pub folder: Vec<String>,
+    /// Namespace of the library it comes from, None if it is part of the
+    /// project itself.
+    pub namespace: Option<String>,
+    /// Where it was parsed from, if known.
+    pub source: Option<Source>,
+}
+
+/// The file a POU was parsed from, to locate errors found after parsing.
+#[derive(Clone)]
+pub struct Source {
+    pub path: Option<PathBuf>,
+    pub map: SourceMap,
 }
 
+impl fmt::Debug for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Source({:?})", self.path)
+    }
+}
+
+/// An `{attribute 'name'}` or `{attribute 'name' := 'value'}` pragma.
 #[derive(Debug)]
-pub struct POU(pub String, pub POUType);
+pub struct Attribute {
+    pub name: String,
+    pub value: Option<String>,
+}
+
+/// Find an attribute by name, which is not case sensitive.
+pub fn find_attribute<'a>(attrs: &'a [Attribute], name: &str) -> Option<&'a Attribute> {
+    attrs.iter().find(|a| a.name.eq_ignore_ascii_case(name))
+}
 
 #[derive(Debug)]
 pub enum POUType {
     Globals {
-        constant: bool,
-        vars: Vec<VarDef>,
+        vars: Vec<VarBlock>,
     },
     Struct {
         members: Vec<VarDef>,
     },
+    Union {
+        members: Vec<VarDef>,
+    },
     Typedef {
         alias: Type,
     },
+    Enum {
+        members: Vec<EnumMember>,
+        /// The underlying integer type, INT if not given.
+        base: Option<Type>,
+    },
     Program {
         body: Vec<Stmt>,
-        vars: Vec<VarDef>,
+        vars: Vec<VarBlock>,
+        actions: Vec<Action>,
     },
     FBlock {
         body: Vec<Stmt>,
         vars: Vec<VarBlock>,
+        /// The base function block.
+        extends: Option<String>,
+        implements: Vec<String>,
+        methods: Vec<Method>,
+        properties: Vec<Property>,
+        actions: Vec<Action>,
     },
     Function {
         rtype: Type,
         vars: Vec<VarBlock>,
         body: Vec<Stmt>,
     },
+    /// Methods and properties of interfaces have no bodies.
+    Interface {
+        extends: Vec<String>,
+        methods: Vec<Method>,
+        properties: Vec<Property>,
+    },
+}
+
+#[derive(Debug)]
+pub struct Method {
+    pub name: String,
+    pub attrs: Vec<Attribute>,
+    pub doc: Option<String>,
+    pub rtype: Option<Type>,
+    pub vars: Vec<VarBlock>,
+    pub body: Vec<Stmt>,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct Property {
+    pub name: String,
+    pub attrs: Vec<Attribute>,
+    pub doc: Option<String>,
+    pub typ: Type,
+    pub get: Option<Accessor>,
+    pub set: Option<Accessor>,
+}
+
+/// The getter or setter of a property.
+#[derive(Debug)]
+pub struct Accessor {
+    pub vars: Vec<VarBlock>,
+    pub body: Vec<Stmt>,
+}
+
+/// An action, which runs on the variables of its POU.
+#[derive(Debug)]
+pub struct Action {
+    pub name: String,
+    pub body: Vec<Stmt>,
 }
 
 #[derive(Debug)]
-pub struct VarBlock(pub VarType, pub Vec<VarDef>);
+pub struct EnumMember {
+    pub name: String,
+    pub doc: Option<String>,
+    pub value: i64,
+    pub span: Span,
+}
 
+/// A variable section: its kind, the variables and its modifiers.
 #[derive(Debug)]
+pub struct VarBlock(pub VarType, pub Vec<VarDef>, pub VarFlags);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum VarType {
     In,
     Out,
     InOut,
     Local,
+    /// VAR_TEMP, reinitialized on every call.
+    Temp,
+    /// VAR_STAT, shared by all instances.
+    Static,
+    /// VAR_INST of a method, stored in the function block instance.
+    Inst,
+    /// VAR_EXTERNAL, declaring the use of a global variable.
+    External,
+    Global,
+}
+
+/// Modifiers of a variable section.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VarFlags {
+    /// The variables cannot be written.
+    pub constant: bool,
+    /// The variables keep their values over a warm reset.
+    pub retain: bool,
+    /// The variables keep their values over a cold reset too.
+    pub persistent: bool,
 }
 
 #[derive(Debug)]
 pub struct VarDef {
     pub name: String,
+    pub attrs: Vec<Attribute>,
+    /// The comments above the declaration and after it on the same line.
+    pub doc: Option<String>,
     pub loc: Option<String>, // TODO
     pub typ: Type,
     pub default: Option<Expr>,
+    pub span: Span,
 }
 
 #[derive(Debug)]
 pub enum Type {
     Simple(String),
-    Array(Box<Type>, i64, i64),
+    /// Element type and bounds of each dimension.
+    Array(Box<Type>, Vec<(i64, i64)>),
     String(usize),
+    WString(usize),
+    Pointer(Box<Type>),
+    Reference(Box<Type>),
+    /// Base type with lower and upper bound.
+    Subrange(Box<Type>, i64, i64),
+}
+
+#[derive(Debug)]
+pub struct Stmt {
+    pub kind: StmtKind,
+    pub span: Span,
 }
 
 #[derive(Debug)]
-pub enum Stmt {
+pub enum StmtKind {
     Empty,
+    Return,
+    /// Leave the innermost loop.
     Exit,
+    /// Continue with the next iteration of the innermost loop.
+    Continue,
     If(Box<Expr>, Vec<Stmt>, Vec<Stmt>),
     Case(Box<Expr>, Vec<Case>, Vec<Stmt>),
     While(Box<Expr>, Vec<Stmt>),
+    /// Loop variable, start, end, optional step, body.
+    For(Box<Expr>, Box<Expr>, Box<Expr>, Option<Box<Expr>>, Vec<Stmt>),
+    /// Body and end condition.
+    Repeat(Vec<Stmt>, Box<Expr>),
     Assign(Box<Expr>, Box<Expr>),
+    /// Binding a reference with `REF=`.
+    RefAssign(Box<Expr>, Box<Expr>),
     Expr(Box<Expr>),
 }
 
@@ -111,30 +307,46 @@ pub enum Kwarg {
 }
 
 #[derive(Debug)]
-pub enum Expr {
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub enum ExprKind {
     Name(String),
     Lit(Lit),
     List(Vec<Expr>),
     Unary(UnOp, Box<Expr>),
     Binary(Box<Expr>, BinOp, Box<Expr>),
-    Call(String, Vec<Expr>),
-    CallFB(String, Vec<Kwarg>),
+    /// Calls of a name, a method (`fb.M`) or a base body (`SUPER^`).
+    Call(Box<Expr>, Vec<Expr>),
+    CallFB(Box<Expr>, Vec<Kwarg>),
     Member(Box<Expr>, String),
     Bit(Box<Expr>, u16),
-    Sub(Box<Expr>, Box<Expr>),
+    /// Array access, with one index per dimension.
+    Sub(Box<Expr>, Vec<Expr>),
+    /// Pointer dereference with `^`.
+    Deref(Box<Expr>),
     Initializer(Vec<(String, Expr)>),
 }
 
 #[derive(Debug)]
 pub enum UnOp {
     Neg,
+    /// Logical negation for BOOL, bitwise complement for other types.
+    Not,
 }
 
 #[derive(Debug)]
 pub enum BinOp {
     Or,
+    /// Short-circuit OR.
+    OrElse,
     Xor,
     And,
+    /// Short-circuit AND.
+    AndThen,
     Eq,
     Neq,
     Gt,
@@ -146,13 +358,32 @@ pub enum BinOp {
     Mul,
     Div,
     Mod,
+    /// Exponentiation with `**`.
+    Pow,
 }
 
 #[derive(Debug)]
 pub enum Lit {
     Bool(bool),
+    /// Base and value.
     Int(u16, i64),
     Float(f64),
+    /// STRING, with escapes resolved.
     Str(String),
-    Time(String), // TODO
+    /// WSTRING, with escapes resolved.
+    WStr(String),
+    /// TIME, in nanoseconds.
+    Time(i64),
+    /// LTIME, in nanoseconds.
+    LTime(i64),
+    /// DATE, in seconds since 1970-01-01.
+    Date(i64),
+    /// TIME_OF_DAY, in nanoseconds since midnight.
+    Tod(i64),
+    /// DATE_AND_TIME, in seconds since 1970-01-01.
+    Dt(i64),
+    /// A literal with a type prefix, like `INT#5`.
+    Typed(String, Box<Lit>),
+    /// An enum member with the enum type as prefix, like `E_Mode#Auto`.
+    Enum(String, String),
 }
@@ -0,0 +1,153 @@
+// *****************************************************************************
+// Charon: Beckhoff TwinCat/ST testing and simulation tools
+// Copyright (c) 2017 by the contributors (see AUTHORS)
+//
+// This program is free software; you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation; either version 2 of the License, or (at your option) any later
+// version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE.  See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program; if not, write to the Free Software Foundation, Inc.,
+// 59 Temple Place, Suite 330, Boston, MA  02111-1307  USA
+//
+// Module authors:
+//   Georg Brandl <g.brandl@fz-juelich.de>
+//
+// *****************************************************************************
+
+//! Extraction of comments, to attach them to declarations as documentation.
+
+use regex::Regex;
+
+use preprocess::PreprocessError;
+use scan::{scan, Kind};
+
+lazy_static! {
+    static ref PRAGMA_RX: Regex = Regex::new(r"(?s)\{.*?\}").unwrap();
+}
+
+/// A comment, with its start and end offset in the parser input.
+struct Comment {
+    start: usize,
+    end: usize,
+    text: String,
+    /// If only whitespace precedes the comment on its line.
+    own_line: bool,
+}
+
+/// The comments of a parser input.
+///
+/// Declarations get the comments directly above them, and the comment
+/// following them on the same line, as their documentation.
+pub struct Comments {
+    list: Vec<Comment>,
+    /// The input with comments blanked out.
+    input: String,
+}
+
+impl Comments {
+    /// Blank out the comments in the input, and collect them.
+    ///
+    /// Comments starting with `@` are TC2 export metadata and are dropped.
+    pub(crate) fn extract(input: &str) -> Result<(String, Comments), PreprocessError> {
+        let mut output = String::with_capacity(input.len());
+        let mut list = vec![];
+        for piece in scan(input)? {
+            let text = &input[piece.start..piece.end];
+            if piece.kind != Kind::Comment {
+                output.push_str(text);
+                continue;
+            }
+            output.extend(text.chars().map(|c| if c == '\n' || c == '\r' { c } else { ' ' }));
+            let inner = if let Some(rest) = text.strip_prefix("//") {
+                rest.trim_start_matches('/')
+            } else {
+                &text[2..text.len() - 2]
+            }.trim();
+            if inner.starts_with('@') {
+                continue;
+            }
+            let line_start = input[..piece.start].rfind('\n').map_or(0, |i| i + 1);
+            list.push(Comment {
+                start: piece.start,
+                end: piece.end,
+                text: inner.into(),
+                own_line: input[line_start..piece.start].trim().is_empty(),
+            });
+        }
+        let comments = Comments { list, input: output.clone() };
+        Ok((output, comments))
+    }
+
+    /// Get the documentation for a declaration from `start` to `end` in the
+    /// input: the comments directly above it, and the one after it on the
+    /// same line.
+    pub fn doc(&self, start: usize, end: usize) -> Option<String> {
+        let mut lines = self.leading(start);
+        if let Some(c) = self.list.iter().find(|c| c.start >= end) {
+            if !self.input[end..c.start].contains('\n') {
+                lines.push(&c.text);
+            }
+        }
+        if lines.is_empty() { None } else { Some(lines.join("\n")) }
+    }
+
+    /// Get the documentation for a declaration starting at `start`, from the
+    /// comments directly above it.
+    pub fn doc_before(&self, start: usize) -> Option<String> {
+        let lines = self.leading(start);
+        if lines.is_empty() { None } else { Some(lines.join("\n")) }
+    }
+
+    /// Get the comments on their own lines directly before `pos`, without
+    /// blank lines in between.  Attributes can be between comment and
+    /// declaration.
+    fn leading(&self, mut pos: usize) -> Vec<&str> {
+        let mut lines = vec![];
+        let end = self.list.iter().position(|c| c.end > pos).unwrap_or(self.list.len());
+        for c in self.list[..end].iter().rev() {
+            // other pragmas have already been blanked out by preprocessing
+            let gap = PRAGMA_RX.replace_all(&self.input[c.end..pos], "@");
+            let mut gap_lines = gap.split('\n').skip(1).collect::<Vec<_>>();
+            gap_lines.pop();
+            if !c.own_line || !gap.replace('@', "").trim().is_empty() ||
+                gap_lines.iter().any(|l| l.trim().is_empty()) {
+                break;
+            }
+            lines.push(&c.text[..]);
+            pos = c.start;
+        }
+        lines.reverse();
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leading_and_trailing() {
+        let input = "(* @PATH := '' *)\n// Speed.\n/// In mm/s.\n{attribute 'x'}\nv : INT; // max\n\n\
+                     (* gap *)\n\nw : INT;\nx : INT; (* of x *)\n";
+        let (output, comments) = Comments::extract(input).unwrap();
+        assert_eq!(output.len(), input.len());
+        assert!(!output.contains("Speed") && output.contains("{attribute 'x'}"));
+        let v = input.find("v :").unwrap();
+        let w = input.find("w :").unwrap();
+        let x = input.find("x :").unwrap();
+        assert_eq!(comments.doc(v, v + 8).unwrap(), "Speed.\nIn mm/s.\nmax");
+        assert_eq!(comments.doc_before(v).unwrap(), "Speed.\nIn mm/s.");
+        // separated by a blank line
+        assert!(comments.doc(w, w + 8).is_none());
+        // not on its own line
+        assert_eq!(comments.doc(x, x + 8).unwrap(), "of x");
+        assert!(comments.doc_before(x).is_none());
+    }
+}
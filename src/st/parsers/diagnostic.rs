@@ -0,0 +1,223 @@
+// *****************************************************************************
+// Charon: Beckhoff TwinCat/ST testing and simulation tools
+// Copyright (c) 2017 by the contributors (see AUTHORS)
+//
+// This program is free software; you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation; either version 2 of the License, or (at your option) any later
+// version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE.  See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program; if not, write to the Free Software Foundation, Inc.,
+// 59 Temple Place, Suite 330, Boston, MA  02111-1307  USA
+//
+// Module authors:
+//   Georg Brandl <g.brandl@fz-juelich.de>
+//
+// *****************************************************************************
+
+//! Rich diagnostics for parse and compile errors.
+
+use std::error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use lalrpop_util::ParseError;
+
+use ast::Span;
+use SourceMap;
+
+/// An error with enough context to point at the offending source.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    /// The file containing the error, if any.
+    pub path: Option<PathBuf>,
+    pub message: String,
+    /// Location of the offending token in the file.
+    pub span: Span,
+    /// The offending token, if not at end of input.
+    pub token: Option<String>,
+    /// Descriptions of the tokens that would have been valid.
+    pub expected: Vec<String>,
+    /// The source line containing the error.
+    pub line: String,
+}
+
+impl Diagnostic {
+    /// Create a diagnostic for a range of the parser input.
+    pub(crate) fn new(path: Option<&Path>, map: &SourceMap, start: usize, end: usize,
+                      message: String) -> Diagnostic {
+        Diagnostic::at(path, map, map.span(start, end), message)
+    }
+
+    /// Create a diagnostic for a span that is already located in the file.
+    pub fn at(path: Option<&Path>, map: &SourceMap, span: Span, message: String) -> Diagnostic {
+        Diagnostic {
+            path: path.map(Into::into),
+            message,
+            span,
+            token: span_token(map, span),
+            expected: vec![],
+            line: map.line(span.line).into(),
+        }
+    }
+
+    /// Convert an error from the LALRPOP parser.
+    ///
+    /// `end` is the length of the parser input, which is used as the location
+    /// of an unexpected end of input.
+    pub(crate) fn from_parser<T: fmt::Display>(path: Option<&Path>, map: &SourceMap, end: usize,
+                                               err: ParseError<usize, T, (Span, &str)>) -> Diagnostic {
+        let (span, token, expected, message) = match err {
+            ParseError::InvalidToken { location } =>
+                (map.span(location, location + 1), None, vec![], "invalid token".into()),
+            ParseError::UnrecognizedToken { token: (l, tok, r), expected } => {
+                let msg = format!("unexpected token `{}`", tok);
+                (map.span(l, r), Some(tok.to_string()), expected, msg)
+            }
+            ParseError::UnrecognizedEOF { expected, .. } =>
+                (map.span(end, end), None, expected, "unexpected end of input".into()),
+            ParseError::ExtraToken { token: (l, tok, r) } => {
+                let msg = format!("extra token `{}` at end of input", tok);
+                (map.span(l, r), Some(tok.to_string()), vec![], msg)
+            }
+            // errors from the grammar actions are already located in the file
+            ParseError::User { error: (span, message) } =>
+                (span, span_token(map, span), vec![], message.into()),
+        };
+        let mut expected = expected.iter().map(|e| describe_token(e)).collect::<Vec<_>>();
+        expected.sort();
+        expected.dedup();
+        Diagnostic {
+            path: path.map(Into::into),
+            message,
+            span,
+            token,
+            expected,
+            line: map.line(span.line).into(),
+        }
+    }
+}
+
+/// The first line of the source text covered by a span.
+fn span_token(map: &SourceMap, span: Span) -> Option<String> {
+    map.text.get(span.start..span.end).and_then(|t| t.lines().next()).map(Into::into)
+}
+
+/// Make the token names used by LALRPOP presentable.
+fn describe_token(name: &str) -> String {
+    if name.starts_with("r#\"") {
+        // regex terminals: give them a name
+        match &name[3..name.len() - 2] {
+            r"[a-zA-Z_][a-zA-Z_0-9]*" => "identifier".into(),
+            r"[0-9][0-9_]*" => "integer".into(),
+            rx if rx.starts_with("16#") || rx.starts_with("8#") || rx.starts_with("2#") =>
+                "integer".into(),
+            rx if rx.starts_with("[a-zA-Z_]") => "typed literal".into(),
+            rx if rx.starts_with('\'') => "string".into(),
+            rx if rx.starts_with('"') => "wide string".into(),
+            rx if rx.starts_with('%') => "address".into(),
+            rx if rx.starts_with('\\') => "attribute".into(),
+            _ => "number".into(),
+        }
+    } else if name.starts_with('"') && name.ends_with('"') && name.len() > 1 {
+        format!("`{}`", &name[1..name.len() - 1])
+    } else {
+        name.into()
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let lineno = self.span.line.to_string();
+        let pad = " ".repeat(lineno.len());
+        writeln!(f, "error: {}", self.message)?;
+        match self.path {
+            Some(ref path) => writeln!(f, "{}--> {}:{}:{}", pad, path.display(),
+                                       self.span.line, self.span.col)?,
+            None => writeln!(f, "{}--> {}:{}", pad, self.span.line, self.span.col)?,
+        }
+        writeln!(f, "{} |", pad)?;
+        writeln!(f, "{} | {}", lineno, self.line)?;
+        // keep tabs in the indentation so that the caret lines up
+        let indent = self.line.chars().take(self.span.col - 1)
+                                      .map(|c| if c == '\t' { '\t' } else { ' ' })
+                                      .collect::<String>();
+        let width = self.token.as_ref().map_or(1, |t| t.chars().count().max(1));
+        write!(f, "{} | {}{}", pad, indent, "^".repeat(width))?;
+        if !self.expected.is_empty() {
+            let (prefix, list) = if self.expected.len() == 1 {
+                ("expected", self.expected[0].clone())
+            } else {
+                ("expected one of", self.expected.join(", "))
+            };
+            write!(f, "\n{} = {} {}", pad, prefix, list)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for Diagnostic {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use {parse_tc2_file, Defines};
+    use tests::temp_file;
+    use super::*;
+
+    fn diagnostic(text: &str) -> Diagnostic {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let name = format!("{}.EXP", COUNTER.fetch_add(1, Ordering::SeqCst));
+        let err = parse_tc2_file(temp_file("diagnostic", &name, text), &Defines::new()).unwrap_err();
+        let mut diag = err.downcast_ref::<Diagnostic>().unwrap().clone();
+        diag.path = None;
+        diag
+    }
+
+    #[test]
+    fn unexpected_tokens() {
+        let mut diag = diagnostic("PROGRAM MAIN\nVAR x : INT; END_VAR\n\tx := 1 +;\n");
+        assert_eq!(diag.message, "unexpected token `;`");
+        assert_eq!((diag.span.line, diag.span.col), (3, 10));
+        assert_eq!(diag.token.as_ref().unwrap(), ";");
+        assert!(diag.expected.contains(&"identifier".to_string()));
+        assert!(diag.expected.contains(&"`NOT`".to_string()));
+        diag.path = Some("POUs/MAIN.TcPOU".into());
+        diag.expected.truncate(2);
+        assert_eq!(diag.to_string(), "\
+error: unexpected token `;`
+ --> POUs/MAIN.TcPOU:3:10
+  |
+3 | \tx := 1 +;
+  | \t        ^
+  = expected one of `(`, `-`");
+    }
+
+    #[test]
+    fn end_of_input_and_invalid_tokens() {
+        let diag = diagnostic("PROGRAM MAIN\nVAR x : INT; END_VAR\nx := (1");
+        assert_eq!(diag.message, "unexpected end of input");
+        assert_eq!((diag.span.line, diag.span.col), (3, 8));
+        assert!(diag.token.is_none());
+        assert!(diag.expected.contains(&"`)`".to_string()));
+
+        let diag = diagnostic("PROGRAM MAIN\nVAR x : INT; END_VAR\n x := 1 $ 2;");
+        assert_eq!(diag.message, "invalid token");
+        assert!(diag.expected.is_empty());
+        assert_eq!(diag.to_string(), "\
+error: invalid token
+ --> 3:9
+  |
+3 |  x := 1 $ 2;
+  |         ^");
+    }
+}
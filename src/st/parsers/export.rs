@@ -0,0 +1,190 @@
+// *****************************************************************************
+// Charon: Beckhoff TwinCat/ST testing and simulation tools
+// Copyright (c) 2017 by the contributors (see AUTHORS)
+//
+// This program is free software; you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation; either version 2 of the License, or (at your option) any later
+// version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE.  See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program; if not, write to the Free Software Foundation, Inc.,
+// 59 Temple Place, Suite 330, Boston, MA  02111-1307  USA
+//
+// Module authors:
+//   Georg Brandl <g.brandl@fz-juelich.de>
+//
+// *****************************************************************************
+
+//! Splitting of TwinCat 2 export files into their objects.
+//!
+//! An export of a whole project contains all its POUs, data types and global
+//! variable lists.  Each of them starts with a block of `(* @KEY := value *)`
+//! comments, the first being `@NESTEDCOMMENTS`, and is followed by the
+//! actions of the POU as `ACTION name: ... END_ACTION`.
+
+use regex::{Captures, Regex};
+
+use preprocess::PreprocessError;
+use scan::{scan, Kind, Piece};
+
+lazy_static! {
+    static ref META_RX: Regex =
+        Regex::new(r"(?s)^\(\*\s*@([A-Z_]+)\s*:=\s*(.*?)\s*\*\)$").unwrap();
+    static ref ACTION_RX: Regex =
+        Regex::new(r"(?im)^[ \t]*ACTION[ \t]+([a-zA-Z_][a-zA-Z0-9_]*)[ \t]*:|\bEND_ACTION\b")
+        .unwrap();
+}
+
+/// An object of an export file, with offsets into the file.
+pub(crate) struct Object {
+    /// Folder of the object in the project, from its `@PATH`.
+    pub folder: Vec<String>,
+    /// Name of a global variable list, from its `@GLOBAL_VARIABLE_LIST`;
+    /// the source does not contain it.
+    pub gvl_name: Option<String>,
+    /// Start and end of the POU, data type or global variable list.
+    pub start: usize,
+    pub end: usize,
+    /// Actions: name, and start and end of the body.
+    pub actions: Vec<(String, usize, usize)>,
+}
+
+/// Split an export file into its objects.
+///
+/// Files without `@NESTEDCOMMENTS` markers, like those written by hand, are
+/// a single object.  Parts without any code are skipped.
+pub(crate) fn split(input: &str) -> Result<Vec<Object>, PreprocessError> {
+    let pieces = scan(input)?;
+    let meta = |piece: &Piece| -> Option<Captures> {
+        if piece.kind == Kind::Comment {
+            META_RX.captures(&input[piece.start..piece.end])
+        } else {
+            None
+        }
+    };
+    let mut starts = pieces.iter().filter(|p| meta(p).is_some_and(|c| &c[1] == "NESTEDCOMMENTS"))
+                                  .map(|p| p.start)
+                                  .collect::<Vec<_>>();
+    if starts.first() != Some(&0) {
+        starts.insert(0, 0);
+    }
+    let mut objects = vec![];
+    for (i, &start) in starts.iter().enumerate() {
+        let end = starts.get(i + 1).cloned().unwrap_or(input.len());
+        let mut object = Object { folder: vec![], gvl_name: None, start, end, actions: vec![] };
+        let mut has_code = false;
+        // name and body start of the current action
+        let mut action: Option<(String, usize)> = None;
+        for piece in pieces.iter().filter(|p| p.start >= start && p.start < end) {
+            if let Some(cap) = meta(piece) {
+                match &cap[1] {
+                    "PATH" => object.folder = parse_path(&cap[2]),
+                    "GLOBAL_VARIABLE_LIST" =>
+                        object.gvl_name = Some(cap[2].trim_matches('\'').into()),
+                    _ => {}
+                }
+            }
+            if piece.kind != Kind::Code {
+                continue;
+            }
+            let text = &input[piece.start..piece.end];
+            has_code |= action.is_none() && object.actions.is_empty() && !text.trim().is_empty();
+            for cap in ACTION_RX.captures_iter(text) {
+                let m = cap.get(0).unwrap();
+                let (m_start, m_end) = (piece.start + m.start(), piece.start + m.end());
+                match (cap.get(1), action.take()) {
+                    (Some(name), None) => {
+                        if object.actions.is_empty() {
+                            object.end = m_start;
+                        }
+                        action = Some((name.as_str().into(), m_end));
+                    }
+                    (None, Some((name, body))) => object.actions.push((name, body, m_start)),
+                    (Some(_), Some(_)) =>
+                        return Err((m_start, m_end, "ACTION without END_ACTION".into())),
+                    (None, None) =>
+                        return Err((m_start, m_end, "END_ACTION without ACTION".into())),
+                }
+            }
+        }
+        if let Some((_, body)) = action {
+            return Err((body, body, "ACTION without END_ACTION".into()));
+        }
+        if has_code {
+            objects.push(object);
+        }
+    }
+    Ok(objects)
+}
+
+/// Parse a `@PATH` value like `'\/POUs\/Motion'` into folder names.
+fn parse_path(value: &str) -> Vec<String> {
+    value.trim_matches('\'').split("\\/").filter(|p| !p.is_empty()).map(Into::into).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split;
+
+    const EXPORT: &str = "\
+(* @NESTEDCOMMENTS := 'Yes' *)
+(* @PATH := '\\/POUs\\/Motion' *)
+FUNCTION_BLOCK FB_Axis
+VAR_INPUT
+    x : INT;
+END_VAR
+x := x + 1;
+ACTION Reset:
+x := 0;
+END_ACTION
+
+(* @NESTEDCOMMENTS := 'Yes' *)
+(* @GLOBAL_VARIABLE_LIST := 'Globale_Variablen' *)
+(* @PATH := '' *)
+VAR_GLOBAL
+    gCount : INT;
+END_VAR
+
+(* @OBJECT_END := 'Globale_Variablen' *)
+(* @NESTEDCOMMENTS := 'Yes' *)
+(* @PATH := '' *)
+";
+
+    #[test]
+    fn split_objects() {
+        let objects = split(EXPORT).unwrap();
+        assert_eq!(objects.len(), 2);
+        assert_eq!(objects[0].folder, vec!["POUs", "Motion"]);
+        assert_eq!(objects[0].gvl_name, None);
+        assert!(EXPORT[objects[0].start..objects[0].end].trim_end().ends_with("x := x + 1;"));
+        assert_eq!(objects[0].actions.len(), 1);
+        let (ref name, start, end) = objects[0].actions[0];
+        assert_eq!(name, "Reset");
+        assert_eq!(EXPORT[start..end].trim(), "x := 0;");
+        assert!(objects[1].folder.is_empty());
+        assert_eq!(objects[1].gvl_name.as_ref().unwrap(), "Globale_Variablen");
+        assert!(EXPORT[objects[1].start..objects[1].end].contains("gCount : INT;"));
+    }
+
+    #[test]
+    fn single_object() {
+        let objects = split("PROGRAM MAIN\nVAR\nEND_VAR\n").unwrap();
+        assert_eq!(objects.len(), 1);
+        assert_eq!(objects[0].start, 0);
+        assert!(objects[0].actions.is_empty());
+    }
+
+    #[test]
+    fn unbalanced_actions() {
+        let err = split("PROGRAM MAIN\nACTION A:\nx := 1;\n").err().unwrap();
+        assert_eq!(err.2, "ACTION without END_ACTION");
+        let err = split("PROGRAM MAIN\nEND_ACTION\n").err().unwrap();
+        assert_eq!(err.2, "END_ACTION without ACTION");
+    }
+}
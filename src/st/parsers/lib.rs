@@ -21,62 +21,275 @@
 //
 // *****************************************************************************
 
-#![feature(box_syntax)]
-
 extern crate regex;
 extern crate walkdir;
 extern crate encoding;
 extern crate elementtree as etree;
 #[macro_use] extern crate failure;
 #[macro_use] extern crate lazy_static;
+extern crate lalrpop_util;
 
 pub mod ast;
-mod tc2;
-mod tc3;
+pub mod diagnostic;
+mod comments;
+mod export;
+mod literal;
+mod preprocess;
+mod scan;
+#[allow(clippy::all)]
+mod st;
 
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::io::{Read, Seek, SeekFrom};
+use std::io::Read;
 use std::path::{Path, PathBuf};
-use regex::{Regex, Captures};
+use std::rc::Rc;
+use std::time::Duration;
+use regex::Regex;
 use encoding::all::WINDOWS_1252;
 use failure::Error;
 
-// helper for the parser
+use diagnostic::Diagnostic;
+pub use comments::Comments;
+pub use preprocess::Defines;
+
+// helpers for the parser
 fn app<T>(mut v: Vec<T>, x: T) -> Vec<T> { v.push(x); v }
 
+fn binary(left: ast::Expr, op: ast::BinOp, right: ast::Expr) -> ast::Expr {
+    let span = left.span.to(right.span);
+    ast::Expr { kind: ast::ExprKind::Binary(Box::new(left), op, Box::new(right)), span }
+}
+
+/// Items in the body of a CASE statement.
+#[doc(hidden)]
+pub enum CaseItem {
+    Label(Vec<ast::CaseExpr>),
+    Stmt(ast::Stmt),
+}
+
+fn group_cases(first: Vec<ast::CaseExpr>, items: Vec<CaseItem>) -> Vec<ast::Case> {
+    let mut cases = vec![ast::Case(first, vec![])];
+    for item in items {
+        match item {
+            CaseItem::Label(labels) => cases.push(ast::Case(labels, vec![])),
+            CaseItem::Stmt(stmt) => cases.last_mut().unwrap().1.push(stmt),
+        }
+    }
+    cases
+}
+
+/// The dialect of Structured Text to parse.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dialect {
+    /// TwinCat 2, exported as `.exp` files.
+    TwinCat2,
+    /// TwinCat 3, with XML files referenced by a `.plcproj` project.
+    TwinCat3,
+}
+
+/// Maps offsets in the parser input back to the source file.
+///
+/// The input can be assembled from several chunks of the file, e.g. the
+/// declaration and implementation sections of a TC3 XML file.
+#[derive(Clone)]
+pub struct SourceMap {
+    /// Start offsets of each chunk, in the input and in the file.
+    chunks: Vec<(usize, usize)>,
+    /// Offsets of the start of each line in the file.
+    lines: Rc<Vec<usize>>,
+    text: Rc<String>,
+}
+
+impl SourceMap {
+    /// Create a map for the given file contents and chunks.
+    pub fn new(text: &str, chunks: Vec<(usize, usize)>) -> SourceMap {
+        let mut lines = vec![0];
+        lines.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+        SourceMap { chunks, lines: Rc::new(lines), text: Rc::new(text.into()) }
+    }
+
+    /// Create a map for other chunks of the same file.
+    pub fn with_chunks(&self, chunks: Vec<(usize, usize)>) -> SourceMap {
+        SourceMap { chunks, lines: self.lines.clone(), text: self.text.clone() }
+    }
+
+    /// Create a map where the input is the whole file.
+    pub fn identity(text: &str) -> SourceMap {
+        SourceMap::new(text, vec![(0, 0)])
+    }
+
+    /// Map an offset in the input to an offset in the file.
+    pub fn offset(&self, pos: usize) -> usize {
+        let &(input, file) = self.chunks.iter().rev().find(|c| c.0 <= pos)
+                                                      .unwrap_or(&(0, 0));
+        file + pos - input
+    }
+
+    /// Get line and column (both 1-based) of an offset in the file.
+    pub fn position(&self, offset: usize) -> (usize, usize) {
+        let line = match self.lines.binary_search(&offset) {
+            Ok(i) => i,
+            Err(i) => i - 1,
+        };
+        let start = self.lines[line];
+        let col = self.text.get(start..offset).map_or(offset - start, |s| s.chars().count());
+        (line + 1, col + 1)
+    }
+
+    /// Get the text of a line (1-based) of the file.
+    pub fn line(&self, line: usize) -> &str {
+        let start = self.lines.get(line - 1).cloned().unwrap_or(self.text.len());
+        let end = self.lines.get(line).map_or(self.text.len(), |&e| e - 1);
+        self.text[start..end].trim_end_matches('\r')
+    }
+
+    /// Create a span from start and end offsets in the input.
+    pub fn span(&self, start: usize, end: usize) -> ast::Span {
+        let file_start = self.offset(start);
+        // map the last character, since the end can be the start of the next chunk
+        let file_end = if end > start { self.offset(end - 1) + 1 } else { file_start };
+        let (line, col) = self.position(file_start);
+        ast::Span { start: file_start, end: file_end, line, col }
+    }
+}
+
 lazy_static! {
-    static ref COMMENT_RX: Regex = Regex::new(r"(?s)\(\*.*?\*\)").unwrap();
-    static ref DIRECTIVE_RX: Regex = Regex::new(r"(?s)\{.*?\}").unwrap();
+    static ref ATTRIBUTE_RX: Regex =
+        Regex::new(r"^\{attribute\s+'([^']*)'(?:\s*:=\s*'([^']*)')?\s*\}$").unwrap();
 }
 
-/// Parse an ST source string.
-fn prepare_input<'a>(input: &'a str) -> String {
-    let spaces = |cap: &Captures| " ".repeat(cap[0].len());
-    let input = COMMENT_RX.replace_all(&input, &spaces);
-    DIRECTIVE_RX.replace_all(&input, spaces).into_owned()
+/// Prepare an ST source string for parsing.
+///
+/// Conditional compilation pragmas are evaluated, and other pragmas blanked
+/// out, except for attributes, which the parser attaches to the following
+/// declaration.  Keywords are converted to uppercase.  Comments are blanked
+/// out too, and returned separately so that the parser can attach them to
+/// declarations as documentation.
+fn prepare_input(path: Option<&Path>, map: &SourceMap, input: &str,
+                 defines: &Defines) -> Result<(String, Comments), Error> {
+    let error = |(start, end, msg)| Error::from(Diagnostic::new(path, map, start, end, msg));
+    let input = preprocess::preprocess(input, defines).map_err(&error)?;
+    let input = scan::upcase_keywords(&input).map_err(&error)?;
+    Comments::extract(&input).map_err(error)
 }
 
-/// Parse a single TwinCat 2 `.exp` file.
-pub fn parse_tc2_file<P: AsRef<Path>>(path: P) -> Result<ast::POU, Error> {
+fn parse_attribute(text: &str) -> ast::Attribute {
+    let cap = ATTRIBUTE_RX.captures(text).expect("attribute token");
+    ast::Attribute { name: cap[1].into(), value: cap.get(2).map(|v| v.as_str().into()) }
+}
+
+/// Parse a TwinCat 2 `.exp` file.
+///
+/// An export of a whole project contains many POUs, data types and global
+/// variable lists, which are all returned.
+pub fn parse_tc2_file<P: AsRef<Path>>(path: P,
+                                      defines: &Defines) -> Result<Vec<ast::POU>, Error> {
+    parse_tc2_objects(path.as_ref(), defines)?.into_iter().collect()
+}
+
+/// Split a TwinCat 2 `.exp` file into its objects and parse them, with a
+/// separate result for each object.
+fn parse_tc2_objects(path: &Path,
+                     defines: &Defines) -> Result<Vec<Result<ast::POU, Error>>, Error> {
     let mut v = Vec::new();
-    fs::File::open(path.as_ref())?.read_to_end(&mut v)?;
-    let input = encoding::decode(&v, encoding::DecoderTrap::Strict, WINDOWS_1252)
+    fs::File::open(path)?.read_to_end(&mut v)?;
+    let text = encoding::decode(&v, encoding::DecoderTrap::Strict, WINDOWS_1252)
         .0.map_err(|_| format_err!("Could not decode source file"))?;
-    tc2::parse_file(&prepare_input(&input)).map_err(|e| format_err!("Parse error: {}", e))
+    let map = SourceMap::identity(&text);
+    let objects = export::split(&text).map_err(
+        |(start, end, msg)| Error::from(Diagnostic::new(Some(path), &map, start, end, msg)))?;
+    Ok(objects.into_iter().map(|obj| parse_tc2_object(path, &map, &text, obj, defines))
+                          .collect())
+}
+
+/// Parse an object of a TwinCat 2 export file, together with its actions.
+fn parse_tc2_object(path: &Path, map: &SourceMap, text: &str, object: export::Object,
+                    defines: &Defines) -> Result<ast::POU, Error> {
+    let dialect = Dialect::TwinCat2;
+    let map = map.with_chunks(vec![(0, object.start)]);
+    let (input, comments) = prepare_input(Some(path), &map, &text[object.start..object.end],
+                                          defines)?;
+    let mut result = st::fileParser::new().parse(&map, &comments, dialect, &input).map_err(
+        |e| Error::from(Diagnostic::from_parser(Some(path), &map, input.len(), e)))?;
+    result.folder = object.folder;
+    result.source = Some(ast::Source { path: Some(path.into()), map: map.clone() });
+    if let (&ast::POUType::Globals { .. }, Some(name)) = (&result.kind, object.gvl_name) {
+        result.name = name;
+    }
+
+    let mut actions = vec![];
+    for (name, start, end) in object.actions {
+        let map = map.with_chunks(vec![(0, start)]);
+        let (input, comments) = prepare_input(Some(path), &map, &text[start..end], defines)?;
+        let body = st::stmtsParser::new().parse(&map, &comments, dialect, &input).map_err(
+            |e| Error::from(Diagnostic::from_parser(Some(path), &map, input.len(), e)))?;
+        actions.push(ast::Action { name, body });
+    }
+    match result.kind {
+        ast::POUType::Program { actions: ref mut a, .. } |
+        ast::POUType::FBlock { actions: ref mut a, .. } => *a = actions,
+        _ if actions.is_empty() => {}
+        _ => bail!("{} cannot have actions", result.name),
+    }
+    Ok(result)
+}
+
+/// Parse the source of a single POU from a string.
+///
+/// For TwinCat 3, this is the declaration followed by the implementation, as
+/// the IDE shows them.  Conditional compilation pragmas are evaluated without
+/// any defines.
+pub fn parse_pou_str(dialect: Dialect, text: &str) -> Result<ast::POU, Error> {
+    let map = SourceMap::identity(text);
+    let (input, comments) = prepare_input(None, &map, text, &Defines::new())?;
+    let mut result = st::fileParser::new().parse(&map, &comments, dialect, &input).map_err(
+        |e| Error::from(Diagnostic::from_parser(None, &map, input.len(), e)))?;
+    result.source = Some(ast::Source { path: None, map });
+    Ok(result)
+}
+
+/// Parse the source of a single TwinCat 3 method from a string.
+pub fn parse_method_str(text: &str) -> Result<ast::Method, Error> {
+    let map = SourceMap::identity(text);
+    let (input, comments) = prepare_input(None, &map, text, &Defines::new())?;
+    st::methodParser::new().parse(&map, &comments, Dialect::TwinCat3, &input).map_err(
+        |e| Diagnostic::from_parser(None, &map, input.len(), e).into())
+}
+
+/// Parse a list of statements from a string.
+pub fn parse_stmts(dialect: Dialect, text: &str) -> Result<Vec<ast::Stmt>, Error> {
+    let map = SourceMap::identity(text);
+    let (input, comments) = prepare_input(None, &map, text, &Defines::new())?;
+    st::stmtsParser::new().parse(&map, &comments, dialect, &input).map_err(
+        |e| Diagnostic::from_parser(None, &map, input.len(), e).into())
+}
+
+/// Parse a single expression from a string.
+pub fn parse_expr(dialect: Dialect, text: &str) -> Result<ast::Expr, Error> {
+    let map = SourceMap::identity(text);
+    let (input, comments) = prepare_input(None, &map, text, &Defines::new())?;
+    st::exprParser::new().parse(&map, &comments, dialect, &input).map_err(
+        |e| Diagnostic::from_parser(None, &map, input.len(), e).into())
 }
 
 /// Parse a whole TwinCat 2 export directory.
-pub fn parse_tc2_project<P: AsRef<Path>>(path: P) -> (ast::Project, Vec<(PathBuf, Error)>) {
-    let mut project = ast::Project { pous: vec![] };
+pub fn parse_tc2_project<P: AsRef<Path>>(path: P,
+                                         defines: &Defines) -> (ast::Project, Vec<(PathBuf, Error)>) {
+    let mut project = ast::Project { pous: vec![], tasks: vec![], libraries: vec![] };
     let mut errors = vec![];
-    for entry in walkdir::WalkDir::new(path) {
-        if let Ok(entry) = entry {
-            if let Some(ext) = entry.path().extension() {
-                if ext == "exp" || ext == "EXP" {
-                    match parse_tc2_file(entry.path()) {
-                        Ok(pou) => project.pous.push(pou),
-                        Err(err) => errors.push((entry.path().to_path_buf(), err)),
-                    }
+    for entry in walkdir::WalkDir::new(path).into_iter().flatten() {
+        if let Some(ext) = entry.path().extension() {
+            if ext == "exp" || ext == "EXP" {
+                // errors in one object do not prevent loading the others
+                match parse_tc2_objects(entry.path(), defines) {
+                    Ok(results) => for result in results {
+                        match result {
+                            Ok(pou) => project.pous.push(pou),
+                            Err(err) => errors.push((entry.path().to_path_buf(), err)),
+                        }
+                    },
+                    Err(err) => errors.push((entry.path().to_path_buf(), err)),
                 }
             }
         }
@@ -84,47 +297,84 @@ pub fn parse_tc2_project<P: AsRef<Path>>(path: P) -> (ast::Project, Vec<(PathBuf
     (project, errors)
 }
 
-fn read_etree<P: AsRef<Path>>(path: P, top: &str) -> Result<etree::Element, Error> {
-    let mut file = fs::File::open(path.as_ref())?;
-    let mut bom = [0; 3];
-    file.read_exact(&mut bom)?;
-    if bom != [0xef, 0xbb, 0xbf] {
-        file.seek(SeekFrom::Start(0))?;
-    }
-    let tree = etree::Element::from_reader(file)?;
+/// Read an XML file, returning both its text and the parsed tree.
+fn read_etree<P: AsRef<Path>>(path: P, top: &str) -> Result<(String, etree::Element), Error> {
+    let mut text = String::new();
+    fs::File::open(path.as_ref())?.read_to_string(&mut text)?;
+    let bom = if text.starts_with('\u{feff}') { 3 } else { 0 };
+    let tree = etree::Element::from_reader(&text.as_bytes()[bom..])?;
     if tree.tag().name() == top {
-        Ok(tree)
+        Ok((text, tree))
     } else {
         bail!("Not a {} file", top)
     }
 }
 
-/// Parse a single TwinCat 3 `.TcXXX` file.
-pub fn parse_tc3_file<P: AsRef<Path>>(path: P) -> Result<Option<ast::POU>, Error> {
-    let tree = read_etree(&path, "TcPlcObject")?;
+/// Find the offset of the text of an element in the XML source.
+///
+/// The element is searched for starting at `from`, going through the given
+/// chain of tags.  Its text must be in a CDATA section, otherwise the offsets
+/// in the file could not be mapped.
+fn find_text(path: &Path, xml: &str, from: usize, tags: &[&str],
+             content: &str) -> Result<usize, Error> {
+    const CDATA: &str = "<![CDATA[";
+    let mut pos = from;
+    for tag in tags {
+        let open = format!("<{}>", tag);
+        match xml[pos..].find(&open) {
+            Some(i) => pos += i + open.len(),
+            // an empty element can be written as <Tag/>
+            None if content.is_empty() => return Ok(from),
+            None => bail!("No <{}> tag found in {}", tag, path.display()),
+        }
+    }
+    let rest = xml[pos..].trim_start();
+    if rest.starts_with(CDATA) {
+        Ok(xml.len() - rest.len() + CDATA.len())
+    } else if content.is_empty() {
+        Ok(pos)
+    } else {
+        bail!("Source in <{}> is not a CDATA section in {}", tags[tags.len() - 1], path.display())
+    }
+}
+
+/// Collect the ST source of an element from its `Declaration` and
+/// `Implementation/ST` children, together with the chunks for the source map.
+///
+/// The texts are searched in the XML source starting at `pos`, which is
+/// advanced past them, since the elements of a file are processed in order.
+fn collect_source(path: &Path, xml: &str, elem: &etree::Element,
+                  pos: &mut usize) -> Result<(String, Vec<(usize, usize)>), Error> {
     let mut input = String::new();
+    let mut chunks = vec![];
+    if let Some(decl) = elem.find("Declaration") {
+        *pos = find_text(path, xml, *pos, &["Declaration"], decl.text())?;
+        chunks.push((0, *pos));
+        input.push_str(decl.text());
+        input.push('\n');
+    }
+    if let Some(impl_) = elem.navigate(&["Implementation", "ST"]) {
+        *pos = find_text(path, xml, *pos, &["Implementation", "ST"], impl_.text())?;
+        chunks.push((input.len(), *pos));
+        input.push_str(impl_.text());
+    }
+    Ok((input, chunks))
+}
+
+/// Parse a single TwinCat 3 `.TcXXX` file.
+pub fn parse_tc3_file<P: AsRef<Path>>(path: P,
+                                      defines: &Defines) -> Result<Option<ast::POU>, Error> {
+    let (xml, tree) = read_etree(&path, "TcPlcObject")?;
+    let path = path.as_ref();
     let pou = tree.get_child(0).unwrap();
-    let mut name_override = None;
     match pou.tag().name() {
-        "POU" => {
-            let decl = pou.find("Declaration").ok_or_else(
-                || format_err!("No declaration tag found in {}", path.as_ref().display()))?;
-            let impl_ = pou.navigate(&["Implementation", "ST"]).ok_or_else(
-                || format_err!("No implementation tag found in {}", path.as_ref().display()))?;
-            input.push_str(decl.text());
-            input.push('\n');
-            input.push_str(impl_.text());
-        },
-        "DUT" => {
-            let decl = pou.find("Declaration").ok_or_else(
-                || format_err!("No declaration tag found in {}", path.as_ref().display()))?;
-            input.push_str(decl.text());
-        },
-        "GVL" => {
-            let decl = pou.find("Declaration").ok_or_else(
-                || format_err!("No declaration tag found in {}", path.as_ref().display()))?;
-            input.push_str(decl.text());
-            name_override = pou.get_attr("Name");
+        "POU" | "DUT" | "GVL" | "Itf" => {
+            if pou.find("Declaration").is_none() {
+                bail!("No declaration tag found in {}", path.display());
+            }
+            if pou.tag().name() == "POU" && pou.navigate(&["Implementation", "ST"]).is_none() {
+                bail!("No implementation tag found in {}", path.display());
+            }
         },
         "GlobalImagePool" | "GlobalTextList" | "Visu" | "VisuManager" | "Task" => {
             return Ok(None);
@@ -133,21 +383,123 @@ pub fn parse_tc3_file<P: AsRef<Path>>(path: P) -> Result<Option<ast::POU>, Error
             bail!("Not a recognized POU: {}", typ);
         },
     }
-    let mut pou = tc3::parse_file(&prepare_input(&input))
-        .map_err(|e| format_err!("Parse error: {}", e))?;
-    if let Some(name) = name_override {
-        pou.0 = name.into();
+    let dialect = Dialect::TwinCat3;
+    let mut pos = 0;
+    let (input, chunks) = collect_source(path, &xml, pou, &mut pos)?;
+    let map = SourceMap::new(&xml, chunks);
+    let (input, comments) = prepare_input(Some(path), &map, &input, defines)?;
+    let mut result = st::fileParser::new().parse(&map, &comments, dialect, &input).map_err(
+        |e| Error::from(Diagnostic::from_parser(Some(path), &map, input.len(), e)))?;
+    result.source = Some(ast::Source { path: Some(path.into()), map: map.clone() });
+    if pou.tag().name() == "GVL" {
+        if let Some(name) = pou.get_attr("Name") {
+            result.name = name.into();
+        }
+    }
+
+    // methods, properties and actions of function blocks, programs and
+    // interfaces
+    let mut methods = vec![];
+    let mut properties = vec![];
+    let mut actions = vec![];
+    for child in pou.children() {
+        match child.tag().name() {
+            "Method" => {
+                let (input, chunks) = collect_source(path, &xml, child, &mut pos)?;
+                let map = SourceMap::new(&xml, chunks);
+                let (input, comments) = prepare_input(Some(path), &map, &input, defines)?;
+                methods.push(st::methodParser::new().parse(&map, &comments, dialect, &input).map_err(
+                    |e| Error::from(Diagnostic::from_parser(Some(path), &map, input.len(), e)))?);
+            },
+            "Property" => {
+                let (input, chunks) = collect_source(path, &xml, child, &mut pos)?;
+                let map = SourceMap::new(&xml, chunks);
+                let (input, comments) = prepare_input(Some(path), &map, &input, defines)?;
+                let (attrs, doc, name, typ) =
+                    st::propertyParser::new().parse(&map, &comments, dialect, &input).map_err(
+                        |e| Error::from(Diagnostic::from_parser(Some(path), &map, input.len(), e)))?;
+                let mut prop = ast::Property { name, attrs, doc, typ, get: None, set: None };
+                for acc in child.children() {
+                    let (input, chunks) = collect_source(path, &xml, acc, &mut pos)?;
+                    let map = SourceMap::new(&xml, chunks);
+                    let (input, comments) = prepare_input(Some(path), &map, &input, defines)?;
+                    let accessor = st::accessorParser::new().parse(&map, &comments, dialect, &input).map_err(
+                        |e| Error::from(Diagnostic::from_parser(Some(path), &map, input.len(), e)))?;
+                    match acc.tag().name() {
+                        "Get" => prop.get = Some(accessor),
+                        "Set" => prop.set = Some(accessor),
+                        _ => {}
+                    }
+                }
+                properties.push(prop);
+            },
+            "Action" => {
+                let name = child.get_attr("Name").ok_or_else(
+                    || format_err!("Action without name in {}", path.display()))?;
+                let (input, chunks) = collect_source(path, &xml, child, &mut pos)?;
+                let map = SourceMap::new(&xml, chunks);
+                let (input, comments) = prepare_input(Some(path), &map, &input, defines)?;
+                let accessor = st::accessorParser::new().parse(&map, &comments, dialect, &input).map_err(
+                    |e| Error::from(Diagnostic::from_parser(Some(path), &map, input.len(), e)))?;
+                actions.push(ast::Action { name: name.into(), body: accessor.body });
+            },
+            _ => {}
+        }
+    }
+    match result.kind {
+        ast::POUType::FBlock { methods: ref mut m, properties: ref mut p,
+                               actions: ref mut a, .. } => {
+            *m = methods;
+            *p = properties;
+            *a = actions;
+        }
+        ast::POUType::Interface { methods: ref mut m, properties: ref mut p, .. }
+            if actions.is_empty() => {
+            *m = methods;
+            *p = properties;
+        }
+        ast::POUType::Program { actions: ref mut a, .. } if methods.is_empty() &&
+            properties.is_empty() => *a = actions,
+        _ if methods.is_empty() && properties.is_empty() && actions.is_empty() => {}
+        _ => bail!("{} cannot have methods, properties or actions", result.name),
     }
-    Ok(Some(pou))
+    Ok(Some(result))
+}
+
+/// Parse a TwinCat 3 `.TcTTO` task configuration file.
+pub fn parse_tc3_task<P: AsRef<Path>>(path: P) -> Result<ast::Task, Error> {
+    let (_, tree) = read_etree(&path, "TcPlcObject")?;
+    let path = path.as_ref();
+    let task = match tree.find("Task") {
+        Some(task) => task,
+        None => bail!("No task found in {}", path.display()),
+    };
+    let name = task.get_attr("Name").ok_or_else(
+        || format_err!("Task without name in {}", path.display()))?;
+    let number = |tag: &str| -> Result<u64, Error> {
+        let elem = task.find(tag).ok_or_else(
+            || format_err!("Task {} has no {}", name, tag))?;
+        elem.text().trim().parse().map_err(
+            |_| format_err!("Invalid {} for task {}: {}", tag, name, elem.text()))
+    };
+    // the cycle time is given in microseconds
+    let micros = number("CycleTime")?;
+    let cycle_time = Duration::new(micros / 1_000_000, (micros % 1_000_000) as u32 * 1000);
+    let priority = number("Priority")? as u32;
+    let programs = task.find_all("PouCall").filter_map(|call| call.find("Name"))
+                                           .map(|name| name.text().trim().into())
+                                           .collect();
+    Ok(ast::Task { name: name.into(), cycle_time, priority, programs })
 }
 
 /// Parse a TwinCat 3 `.plcproj` project.
-pub fn parse_tc3_project<P: AsRef<Path>>(path: P) -> (ast::Project, Vec<(PathBuf, Error)>) {
-    let mut project = ast::Project { pous: vec![] };
+pub fn parse_tc3_project<P: AsRef<Path>>(path: P,
+                                         defines: &Defines) -> (ast::Project, Vec<(PathBuf, Error)>) {
+    let mut project = ast::Project { pous: vec![], tasks: vec![], libraries: vec![] };
     let mut errors = vec![];
     let basedir = path.as_ref().parent().unwrap_or(Path::new("."));
     let tree = match read_etree(&path, "Project") {
-        Ok(tree) => tree,
+        Ok((_, tree)) => tree,
         Err(e) => {
             errors.push((path.as_ref().to_path_buf(), e));
             return (project, errors);
@@ -158,14 +510,507 @@ pub fn parse_tc3_project<P: AsRef<Path>>(path: P) -> (ast::Project, Vec<(PathBuf
         for comp in group.find_all((ns, "Compile")) {
             if let Some(relpath) = comp.get_attr("Include") {
                 let fullpath = basedir.join(relpath.replace("\\", "/"));
-                println!("{}", fullpath.display());
-                match parse_tc3_file(&fullpath) {
-                    Ok(Some(pou)) => project.pous.push(pou),
+                let ext = fullpath.extension().and_then(|ext| ext.to_str()).unwrap_or("");
+                if ext.eq_ignore_ascii_case("TcTTO") {
+                    match parse_tc3_task(&fullpath) {
+                        Ok(task) => project.tasks.push(task),
+                        Err(err) => errors.push((fullpath, err)),
+                    }
+                    continue;
+                }
+                match parse_tc3_file(&fullpath, defines) {
+                    Ok(Some(mut pou)) => {
+                        // the folders in the project are those on disk
+                        pou.folder = relpath.split(['\\', '/']).map(String::from).collect();
+                        pou.folder.pop();
+                        project.pous.push(pou);
+                    }
                     Ok(None) => continue,
                     Err(err) => errors.push((fullpath, err)),
                 }
             }
         }
     }
+
+    // placeholders are resolved to their default library, unless the
+    // project selects another one
+    let mut resolutions = HashMap::new();
+    for group in tree.find_all((ns, "ItemGroup")) {
+        for res in group.find_all((ns, "PlaceholderResolution")) {
+            let id = res.find((ns, "Resolution"));
+            if let (Some(name), Some(id)) = (res.get_attr("Include"), id) {
+                resolutions.insert(name.to_uppercase(), id.text().to_string());
+            }
+        }
+    }
+    for group in tree.find_all((ns, "ItemGroup")) {
+        for item in group.children() {
+            let include = match item.get_attr("Include") {
+                Some(include) => include,
+                None => continue,
+            };
+            let (name, version, default_ns) = match item.tag().name() {
+                "PlaceholderReference" => {
+                    let id = match resolutions.get(&include.to_uppercase()) {
+                        Some(id) => id.clone(),
+                        None => item.find((ns, "DefaultResolution"))
+                                    .map_or(include.into(), |id| id.text().into()),
+                    };
+                    let (name, version) = parse_library_id(&id);
+                    (name, version, include.to_string())
+                }
+                "LibraryReference" => {
+                    let (name, version) = parse_library_id(include);
+                    (name.clone(), version, name)
+                }
+                _ => continue,
+            };
+            let namespace = item.find((ns, "Namespace"))
+                                .map_or(default_ns, |n| n.text().trim().into());
+            project.libraries.push(ast::Library { name, namespace, version });
+        }
+    }
     (project, errors)
 }
+
+/// Split a library identification like `Tc2_Standard, 3.3.3.0 (Beckhoff
+/// Automation GmbH)` into name and version.  The version `*` means the newest.
+fn parse_library_id(id: &str) -> (String, Option<String>) {
+    let mut parts = id.splitn(2, ',');
+    let name = parts.next().unwrap_or("").trim().to_string();
+    let version = parts.next().map(|rest| rest.split('(').next().unwrap().trim())
+                              .filter(|v| !v.is_empty() && *v != "*")
+                              .map(String::from);
+    (name, version)
+}
+
+/// Load the sources of the libraries referenced by a TwinCat 3 project, and
+/// add their POUs to the project.  Libraries referenced by these libraries
+/// are loaded and added to the references too.
+///
+/// Libraries must be available as unpacked library projects, in a folder
+/// named like the library in one of the directories of the search path.
+/// The `.plcproj` file is searched for in a subfolder named like the
+/// referenced version first, then in the library's folder itself.
+///
+/// Libraries that are only available compiled, like the system libraries in
+/// the TwinCat library repository, are skipped since they have no source.
+pub fn load_tc3_libraries(project: &mut ast::Project, search_path: &[PathBuf],
+                          defines: &Defines) -> Vec<(PathBuf, Error)> {
+    let mut errors = vec![];
+    let mut loaded = HashSet::new();
+    let mut i = 0;
+    while i < project.libraries.len() {
+        let lib = project.libraries[i].clone();
+        i += 1;
+        if !loaded.insert(lib.name.to_uppercase()) {
+            continue;
+        }
+        let path = match find_library(&lib, search_path) {
+            LibraryFile::Project(path) => path,
+            LibraryFile::Compiled => continue,
+            LibraryFile::Missing => {
+                errors.push((PathBuf::from(&lib.name),
+                             format_err!("Library {} not found in the search path", lib.name)));
+                continue;
+            }
+        };
+        let (libproject, liberrors) = parse_tc3_project(&path, defines);
+        errors.extend(liberrors);
+        for mut pou in libproject.pous {
+            pou.namespace = Some(lib.namespace.clone());
+            project.pous.push(pou);
+        }
+        for dep in libproject.libraries {
+            if !project.libraries.contains(&dep) {
+                project.libraries.push(dep);
+            }
+        }
+    }
+    errors
+}
+
+/// What was found for a library in the search path.
+enum LibraryFile {
+    /// The `.plcproj` file of the library's source.
+    Project(PathBuf),
+    /// Only a compiled library without source.
+    Compiled,
+    Missing,
+}
+
+/// Find the `.plcproj` file of a library in the search path.
+fn find_library(lib: &ast::Library, search_path: &[PathBuf]) -> LibraryFile {
+    let mut compiled = false;
+    for dir in search_path {
+        let base = dir.join(&lib.name);
+        let mut candidates = vec![];
+        if let Some(ref version) = lib.version {
+            candidates.push(base.join(version));
+        }
+        candidates.push(base);
+        for candidate in candidates {
+            let entries = match fs::read_dir(&candidate) {
+                Ok(entries) => entries,
+                Err(_) => continue,
+            };
+            for entry in entries.filter_map(|e| e.ok()) {
+                let path = entry.path();
+                match path.extension().and_then(|ext| ext.to_str()) {
+                    Some("plcproj") => return LibraryFile::Project(path),
+                    // also `.compiled-library-ge33` for newer TwinCat versions
+                    Some(ext) if ext.starts_with("compiled-library") => compiled = true,
+                    _ => {}
+                }
+            }
+        }
+    }
+    if compiled { LibraryFile::Compiled } else { LibraryFile::Missing }
+}
+
+/// Parse a single file of the given dialect.
+///
+/// TwinCat 2 exports can contain many POUs.  TwinCat 3 files contain one, or
+/// none if they do not contain ST code, such as task configurations.
+pub fn parse_file<P: AsRef<Path>>(dialect: Dialect, path: P,
+                                  defines: &Defines) -> Result<Vec<ast::POU>, Error> {
+    match dialect {
+        Dialect::TwinCat2 => parse_tc2_file(path, defines),
+        Dialect::TwinCat3 => parse_tc3_file(path, defines).map(|pou| pou.into_iter().collect()),
+    }
+}
+
+/// Parse a project of the given dialect: a directory of exported files for
+/// TwinCat 2, or a `.plcproj` file for TwinCat 3.
+pub fn parse_project<P: AsRef<Path>>(dialect: Dialect, path: P,
+                                     defines: &Defines) -> (ast::Project, Vec<(PathBuf, Error)>) {
+    match dialect {
+        Dialect::TwinCat2 => parse_tc2_project(path, defines),
+        Dialect::TwinCat3 => parse_tc3_project(path, defines),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process;
+    use super::*;
+
+    /// Write a file into a fresh temporary directory.
+    pub fn temp_file(dir: &str, name: &str, contents: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("charon-{}-{}", process::id(), dir));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn source_spans() {
+        let path = temp_file("tc2", "MAIN.EXP", "PROGRAM MAIN\n\
+                                                 VAR\n  x : INT := 1;\nEND_VAR\n\
+                                                 x := x + 1;\n  IF x > 2 THEN\n    x := 0;\n  \
+                                                 END_IF\nEND_PROGRAM\n");
+        let pou = parse_tc2_file(&path, &Defines::new()).unwrap().remove(0);
+        let (vars, body) = match pou.kind {
+            ast::POUType::Program { vars, body, .. } => (vars, body),
+            _ => panic!("not a program"),
+        };
+        assert_eq!((vars[0].1[0].span.line, vars[0].1[0].span.col), (3, 3));
+        assert_eq!((body[0].span.line, body[0].span.col), (5, 1));
+        assert_eq!((body[1].span.line, body[1].span.col), (6, 3));
+        match body[1].kind {
+            ast::StmtKind::If(ref cond, ref then, _) => {
+                assert_eq!((cond.span.line, cond.span.col), (6, 6));
+                assert_eq!((then[0].span.line, then[0].span.col), (7, 5));
+            }
+            _ => panic!("not an IF statement"),
+        }
+
+        // offsets in later chunks of the input are mapped into the file
+        let map = SourceMap::new("ab\ncd\nef", vec![(0, 0), (2, 6)]);
+        assert_eq!(map.span(0, 2), ast::Span { start: 0, end: 2, line: 1, col: 1 });
+        assert_eq!(map.span(2, 4), ast::Span { start: 6, end: 8, line: 3, col: 1 });
+    }
+
+    #[test]
+    fn attributes() {
+        let path = temp_file("tc2", "FB_DRIVE.EXP", "\
+{attribute 'call_after_init'}
+{attribute 'pack_mode' := '1'}
+FUNCTION_BLOCK FB_Drive
+VAR
+    {attribute 'hide'}
+    x : INT;
+    y : INT;
+END_VAR
+END_FUNCTION_BLOCK");
+        let pou = parse_tc2_file(&path, &Defines::new()).unwrap().remove(0);
+        assert_eq!(pou.attrs.len(), 2);
+        assert_eq!(pou.attrs[0].name, "call_after_init");
+        assert!(pou.attrs[0].value.is_none());
+        assert_eq!(ast::find_attribute(&pou.attrs, "PACK_MODE").unwrap().value.as_ref().unwrap(),
+                   "1");
+        match pou.kind {
+            ast::POUType::FBlock { ref vars, .. } => {
+                assert_eq!(vars[0].1[0].attrs[0].name, "hide");
+                assert!(vars[0].1[1].attrs.is_empty());
+            }
+            _ => panic!("expected a function block"),
+        }
+
+        // other pragmas are ignored
+        let path = temp_file("tc2", "E_STATE.EXP", "\
+{warning 'not yet done'}
+{attribute 'qualified_only'}
+TYPE E_State : (Idle, Busy) END_TYPE");
+        let pou = parse_tc2_file(&path, &Defines::new()).unwrap().remove(0);
+        assert_eq!(pou.attrs.len(), 1);
+        assert_eq!(pou.attrs[0].name, "qualified_only");
+    }
+
+    #[test]
+    fn doc_comments() {
+        let path = temp_file("tc2", "FB_AXIS.EXP", "\
+// Moves an axis.
+(* Call cyclically. *)
+FUNCTION_BLOCK FB_Axis
+VAR_INPUT
+    // Target position.
+    target : LREAL; // in mm
+    speed : LREAL;
+END_VAR
+END_FUNCTION_BLOCK");
+        let pou = parse_tc2_file(&path, &Defines::new()).unwrap().remove(0);
+        assert_eq!(pou.doc.as_ref().unwrap(), "Moves an axis.\nCall cyclically.");
+        match pou.kind {
+            ast::POUType::FBlock { ref vars, .. } => {
+                assert_eq!(vars[0].1[0].doc.as_ref().unwrap(), "Target position.\nin mm");
+                assert!(vars[0].1[1].doc.is_none());
+            }
+            _ => panic!("expected a function block"),
+        }
+
+        let path = temp_file("tc2", "E_STATE_DOC.EXP", "\
+TYPE E_State :
+(
+    Idle, // waiting
+    (* working *)
+    Busy
+);
+END_TYPE");
+        let pou = parse_tc2_file(&path, &Defines::new()).unwrap().remove(0);
+        assert!(pou.doc.is_none());
+        match pou.kind {
+            ast::POUType::Enum { ref members, .. } => {
+                assert_eq!(members[0].doc.as_ref().unwrap(), "waiting");
+                assert_eq!(members[1].doc.as_ref().unwrap(), "working");
+            }
+            _ => panic!("expected an enum"),
+        }
+    }
+
+    #[test]
+    fn dialects() {
+        fn parse(dialect: Dialect, decl: &str, body: &str) -> Result<ast::POU, Error> {
+            let path = match dialect {
+                Dialect::TwinCat2 => temp_file("dialects", "MAIN.EXP",
+                                               &format!("{}\n{}\nEND_PROGRAM", decl, body)),
+                Dialect::TwinCat3 => temp_file("dialects", "MAIN.TcPOU", &format!(
+                    "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<TcPlcObject><POU Name=\"MAIN\">\
+                     <Declaration><![CDATA[{}]]></Declaration>\
+                     <Implementation><ST><![CDATA[{}]]></ST></Implementation></POU></TcPlcObject>",
+                    decl, body)),
+            };
+            parse_file(dialect, &path, &Defines::new()).map(|mut pous| pous.remove(0))
+        }
+        fn message(dialect: Dialect, decl: &str) -> String {
+            let err = parse(dialect, decl, ";").unwrap_err();
+            err.downcast_ref::<Diagnostic>().unwrap().message.clone()
+        }
+
+        // the common grammar is the same for both dialects
+        for &dialect in &[Dialect::TwinCat2, Dialect::TwinCat3] {
+            let pou = parse(dialect, "PROGRAM MAIN VAR x : INT; END_VAR",
+                            "CASE x OF 1, 2: x := 0; ELSE x := 1; END_CASE").unwrap();
+            assert_eq!(pou.name, "MAIN");
+        }
+
+        // TwinCat 2 initializes array members without brackets
+        let tc2 = "PROGRAM MAIN VAR s : ST := (a := 1, 2, 3, b := 4); END_VAR";
+        parse(Dialect::TwinCat2, tc2, ";").unwrap();
+        assert_eq!(message(Dialect::TwinCat3, tc2), "array initializers need brackets");
+
+        // TwinCat 3 uses array literals in brackets
+        let tc3 = "PROGRAM MAIN VAR s : ST := (a := [1, 2, 3], b := 4); END_VAR";
+        parse(Dialect::TwinCat3, tc3, ";").unwrap();
+        assert_eq!(message(Dialect::TwinCat2, tc3),
+                   "array literals in brackets are not supported by TwinCat 2");
+    }
+
+    #[test]
+    fn string_api() {
+        use ast::{BinOp, ExprKind, Lit, StmtKind};
+
+        let expr = parse_expr(Dialect::TwinCat3, "a + 2 * b").unwrap();
+        match expr.kind {
+            ExprKind::Binary(ref left, BinOp::Add, ref right) => {
+                assert!(matches!(left.kind, ExprKind::Name(ref n) if n == "a"));
+                assert!(matches!(right.kind, ExprKind::Binary(_, BinOp::Mul, _)));
+            }
+            _ => panic!("unexpected expression {:?}", expr),
+        }
+        assert_eq!((expr.span.start, expr.span.end), (0, 9));
+        let expr = parse_expr(Dialect::TwinCat2, "16#FF").unwrap();
+        assert!(matches!(expr.kind, ExprKind::Lit(Lit::Int(16, 255))));
+        // the minus is an operator, not part of the number
+        for text in &["a-1.5", "a-1e3", "a-16#5"] {
+            let expr = parse_expr(Dialect::TwinCat3, text).unwrap();
+            assert!(matches!(expr.kind, ExprKind::Binary(_, BinOp::Sub, _)), "{}", text);
+        }
+
+        let stmts = parse_stmts(Dialect::TwinCat3, "x := 1;\n(* skip *)\nif x THEN RETURN; end_if")
+            .unwrap();
+        assert_eq!(stmts.len(), 2);
+        assert!(matches!(stmts[0].kind, StmtKind::Assign(..)));
+        assert!(matches!(stmts[1].kind, StmtKind::If(..)));
+        assert_eq!((stmts[1].span.line, stmts[1].span.col), (3, 1));
+        assert!(parse_stmts(Dialect::TwinCat3, "").unwrap().is_empty());
+
+        // errors are located in the string
+        let err = parse_expr(Dialect::TwinCat3, "a +").unwrap_err();
+        let diag = err.downcast_ref::<Diagnostic>().unwrap();
+        assert!(diag.path.is_none());
+        assert_eq!(diag.message, "unexpected end of input");
+        assert!(parse_stmts(Dialect::TwinCat3, "x := 1").is_err());
+    }
+
+    #[test]
+    fn tc3_task() {
+        let path = temp_file("tc3", "PlcTask.TcTTO", r#"<?xml version="1.0" encoding="utf-8"?>
+<TcPlcObject Version="1.1.0.1">
+  <Task Name="PlcTask" Id="{00000000-0000-0000-0000-000000000000}">
+    <CycleTime>2500</CycleTime>
+    <Priority>20</Priority>
+    <PouCall><Name>MAIN</Name></PouCall>
+    <PouCall><Name>Logger</Name></PouCall>
+  </Task>
+</TcPlcObject>
+"#);
+        let task = parse_tc3_task(&path).unwrap();
+        assert_eq!(task.name, "PlcTask");
+        assert_eq!(task.cycle_time, Duration::from_micros(2500));
+        assert_eq!(task.priority, 20);
+        assert_eq!(task.programs, vec!["MAIN", "Logger"]);
+    }
+
+    #[test]
+    fn tc3_libraries() {
+        temp_file("libs/MyLib", "MyLib.plcproj", r#"<?xml version="1.0" encoding="utf-8"?>
+<Project xmlns="http://schemas.microsoft.com/developer/msbuild/2003">
+  <ItemGroup>
+    <Compile Include="Twice.TcPOU" />
+  </ItemGroup>
+</Project>
+"#);
+        temp_file("libs/MyLib", "Twice.TcPOU", r#"<?xml version="1.0" encoding="utf-8"?>
+<TcPlcObject Version="1.1.0.1">
+  <POU Name="Twice" Id="{00000000-0000-0000-0000-000000000000}">
+    <Declaration><![CDATA[FUNCTION Twice : INT
+VAR_INPUT
+    x : INT;
+END_VAR]]></Declaration>
+    <Implementation>
+      <ST><![CDATA[Twice := 2 * x;]]></ST>
+    </Implementation>
+  </POU>
+</TcPlcObject>
+"#);
+        let path = temp_file("libs/Tc2_Standard/3.3.3.0", "Tc2_Standard.compiled-library", "");
+        let search_path = vec![path.parent().unwrap().parent().unwrap().parent().unwrap().into()];
+        let lib = |name: &str, version: Option<&str>| ast::Library {
+            name: name.into(), namespace: name.into(), version: version.map(Into::into)
+        };
+        let mut project = ast::Project {
+            pous: vec![], tasks: vec![],
+            libraries: vec![lib("MyLib", None), lib("Tc2_Standard", Some("3.3.3.0")),
+                            lib("Missing", None)],
+        };
+        let errors = load_tc3_libraries(&mut project, &search_path, &Defines::new());
+        // compiled libraries are skipped, missing ones are reported
+        assert_eq!(errors.len(), 1, "{:?}", errors);
+        assert_eq!(errors[0].1.to_string(), "Library Missing not found in the search path");
+        assert_eq!(project.pous.len(), 1);
+        assert_eq!(project.pous[0].name, "Twice");
+        assert_eq!(project.pous[0].namespace.as_ref().unwrap(), "MyLib");
+    }
+
+    #[test]
+    fn tc2_export_objects() {
+        let path = temp_file("tc2", "PROJ.EXP", "\
+(* @NESTEDCOMMENTS := 'Yes' *)
+(* @PATH := '\\/POUs' *)
+FUNCTION_BLOCK FB_Axis
+VAR_INPUT
+    x : INT;
+END_VAR
+x := x + 1;
+ACTION Reset:
+x := 0;
+END_ACTION
+
+(* @NESTEDCOMMENTS := 'Yes' *)
+(* @GLOBAL_VARIABLE_LIST := 'Globale_Variablen' *)
+(* @PATH := '' *)
+VAR_GLOBAL
+    gCount : INT;
+END_VAR
+");
+        let pous = parse_tc2_file(&path, &Defines::new()).unwrap();
+        assert_eq!(pous.len(), 2);
+        assert_eq!(pous[0].name, "FB_Axis");
+        assert_eq!(pous[0].folder, vec!["POUs"]);
+        match pous[0].kind {
+            ast::POUType::FBlock { ref actions, .. } => assert_eq!(actions[0].name, "Reset"),
+            _ => panic!("expected a function block"),
+        }
+        assert_eq!(pous[1].name, "Globale_Variablen");
+        assert!(pous[1].folder.is_empty());
+    }
+
+    #[test]
+    fn tc3_source_locations() {
+        let path = temp_file("tc3", "MAIN.TcPOU", r#"<?xml version="1.0" encoding="utf-8"?>
+<TcPlcObject Version="1.1.0.1">
+  <POU Name="MAIN" Id="{00000000-0000-0000-0000-000000000000}">
+    <Declaration><![CDATA[PROGRAM MAIN
+VAR
+    x : INT;
+END_VAR]]></Declaration>
+    <Implementation>
+      <ST><![CDATA[x := x + 1;
+x := ;]]></ST>
+    </Implementation>
+  </POU>
+</TcPlcObject>
+"#);
+        let err = parse_tc3_file(&path, &Defines::new()).err().unwrap();
+        let diag = err.downcast_ref::<Diagnostic>().unwrap();
+        assert_eq!((diag.span.line, diag.span.col), (10, 6));
+        assert_eq!(diag.line, "x := ;]]></ST>");
+
+        // without CDATA, the source cannot be located in the file
+        let path = temp_file("tc3", "GVL.TcGVL", r#"<?xml version="1.0" encoding="utf-8"?>
+<TcPlcObject Version="1.1.0.1">
+  <GVL Name="GVL" Id="{00000000-0000-0000-0000-000000000000}">
+    <Declaration>VAR_GLOBAL x : BOOL := 1 &lt; 2; END_VAR</Declaration>
+  </GVL>
+</TcPlcObject>
+"#);
+        let err = parse_tc3_file(&path, &Defines::new()).err().unwrap();
+        assert!(err.to_string().starts_with("Source in <Declaration> is not a CDATA section"),
+                "{}", err);
+    }
+}
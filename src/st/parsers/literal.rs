@@ -0,0 +1,248 @@
+// *****************************************************************************
+// Charon: Beckhoff TwinCat/ST testing and simulation tools
+// Copyright (c) 2017 by the contributors (see AUTHORS)
+//
+// This program is free software; you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation; either version 2 of the License, or (at your option) any later
+// version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE.  See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program; if not, write to the Free Software Foundation, Inc.,
+// 59 Temple Place, Suite 330, Boston, MA  02111-1307  USA
+//
+// Module authors:
+//   Georg Brandl <g.brandl@fz-juelich.de>
+//
+// *****************************************************************************
+
+//! Conversion of literal tokens into values.
+
+use ast::Lit;
+
+type LitResult<T> = Result<T, &'static str>;
+
+const NS_PER_MS: i64 = 1_000_000;
+const NS_PER_S: i64 = 1_000 * NS_PER_MS;
+const S_PER_DAY: i64 = 86_400;
+
+/// Parse an integer with an optional `16#`, `8#` or `2#` base prefix and `_`
+/// separators.  Returns the base and the value.
+///
+/// Integers can use the full unsigned 64-bit range for `ULINT` and `LWORD`
+/// values, which wraps around into negative values.
+pub(crate) fn parse_int(text: &str) -> LitResult<(u16, i64)> {
+    let digits = text.replace('_', "");
+    match digits.find('#') {
+        Some(i) => {
+            let base = match &digits[..i] {
+                "2" => 2,
+                "8" => 8,
+                "16" => 16,
+                _ => return Err("invalid integer base"),
+            };
+            let (neg, num) = match &digits[i+1..] {
+                num if num.starts_with('-') => (true, &num[1..]),
+                num => (false, num),
+            };
+            let value = u64::from_str_radix(num, base as u32)
+                .map_err(|_| "integer literal out of range")? as i64;
+            Ok((base, if neg { value.wrapping_neg() } else { value }))
+        }
+        None => match digits.parse::<i64>() {
+            Ok(value) => Ok((10, value)),
+            Err(_) => Ok((10, digits.parse::<u64>().map_err(|_| "integer literal out of range")? as i64)),
+        },
+    }
+}
+
+/// Parse a floating point number with `_` separators.
+pub(crate) fn parse_float(text: &str) -> LitResult<f64> {
+    text.replace('_', "").parse().map_err(|_| "invalid floating point literal")
+}
+
+/// Parse a string literal in single (STRING) or double (WSTRING) quotes,
+/// resolving `$` escapes.
+pub(crate) fn parse_string(text: &str) -> LitResult<String> {
+    let wide = text.starts_with('"');
+    let mut result = String::new();
+    let mut chars = text[1..text.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            result.push(c);
+            continue;
+        }
+        let c = chars.next().ok_or("invalid escape in string")?;
+        match c.to_ascii_uppercase() {
+            '$' | '\'' | '"' => result.push(c),
+            'L' | 'N' => result.push('\n'),
+            'P' => result.push('\x0c'),
+            'R' => result.push('\r'),
+            'T' => result.push('\t'),
+            _ => {
+                // character code with 2 (STRING) or 4 (WSTRING) hex digits
+                let n = if wide { 4 } else { 2 };
+                let digits = Some(c).into_iter().chain(chars.by_ref().take(n - 1))
+                                                 .collect::<String>();
+                let code = match u32::from_str_radix(&digits, 16) {
+                    Ok(code) if digits.len() == n => code,
+                    _ => return Err("invalid escape in string"),
+                };
+                // STRING codes are bytes in the Windows codepage, which is
+                // the same as Unicode for the usual Latin-1 characters
+                result.push(::std::char::from_u32(code).ok_or("invalid escape in string")?);
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Parse a literal with a type prefix, such as `T#1s`, `INT#5` or
+/// `E_Mode#Auto`.
+pub(crate) fn parse_typed(text: &str) -> LitResult<Lit> {
+    let i = text.find('#').unwrap();
+    let (prefix, value) = (&text[..i], &text[i+1..]);
+    Ok(match &prefix.to_uppercase()[..] {
+        "T" | "TIME" => Lit::Time(parse_duration(value)?),
+        "LTIME" => Lit::LTime(parse_duration(value)?),
+        "D" | "DATE" => Lit::Date(parse_date(value)?),
+        "TOD" | "TIME_OF_DAY" => Lit::Tod(parse_time_of_day(value)?),
+        "DT" | "DATE_AND_TIME" => {
+            // the date and time parts are separated by the third dash
+            let split = value.match_indices('-').nth(2).ok_or("invalid date and time literal")?.0;
+            let tod = parse_time_of_day(&value[split+1..])?;
+            Lit::Dt(parse_date(&value[..split])? + tod / NS_PER_S)
+        }
+        _ => {
+            let upper = value.to_uppercase();
+            let inner = if upper == "TRUE" || upper == "FALSE" {
+                Lit::Bool(upper == "TRUE")
+            } else if value.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+                if !value.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                    return Err("invalid enum literal");
+                }
+                return Ok(Lit::Enum(prefix.into(), value.into()));
+            } else if value.contains('#') || !value.contains(['.', 'e', 'E']) {
+                let (base, value) = parse_int(value)?;
+                Lit::Int(base, value)
+            } else {
+                Lit::Float(parse_float(value)?)
+            };
+            Lit::Typed(prefix.into(), Box::new(inner))
+        }
+    })
+}
+
+/// Parse a duration like `1d2h3m4s5ms6us7ns` into nanoseconds.  The
+/// components can have fractions and be separated by `_`.
+fn parse_duration(text: &str) -> LitResult<i64> {
+    const ERR: &str = "invalid time literal";
+    let text = text.to_lowercase().replace('_', "");
+    let neg = text.starts_with('-');
+    let mut rest = if neg { &text[1..] } else { &text[..] };
+    if rest.is_empty() {
+        return Err(ERR);
+    }
+    let mut total = 0f64;
+    while !rest.is_empty() {
+        let end = rest.find(|c: char| !c.is_ascii_digit() && c != '.').ok_or(ERR)?;
+        let num: f64 = rest[..end].parse().map_err(|_| ERR)?;
+        rest = &rest[end..];
+        let units = [("ms", NS_PER_MS), ("us", 1_000), ("ns", 1), ("d", S_PER_DAY * NS_PER_S),
+                     ("h", 3600 * NS_PER_S), ("m", 60 * NS_PER_S), ("s", NS_PER_S)];
+        let &(unit, factor) = units.iter().find(|u| rest.starts_with(u.0)).ok_or(ERR)?;
+        rest = &rest[unit.len()..];
+        total += num * factor as f64;
+    }
+    let ns = total.round() as i64;
+    Ok(if neg { -ns } else { ns })
+}
+
+/// Parse a date like `2018-03-01` into seconds since 1970-01-01.
+fn parse_date(text: &str) -> LitResult<i64> {
+    const ERR: &str = "invalid date literal";
+    let parts = text.split('-').map(|p| p.replace('_', "").parse::<i64>().map_err(|_| ERR))
+                               .collect::<Result<Vec<_>, _>>()?;
+    if parts.len() != 3 {
+        return Err(ERR);
+    }
+    let (y, m, d) = (parts[0], parts[1], parts[2]);
+    let month_days = [31, if y % 4 == 0 && (y % 100 != 0 || y % 400 == 0) { 29 } else { 28 },
+                      31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    if !(1..=12).contains(&m) || d < 1 || d > month_days[m as usize - 1] {
+        return Err(ERR);
+    }
+    // days since the epoch, counting in years starting in March
+    let (y, m) = if m <= 2 { (y - 1, m + 9) } else { (y, m - 3) };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * m + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    Ok((era * 146_097 + doe - 719_468) * S_PER_DAY)
+}
+
+/// Parse a time of day like `12:30:15.5` into nanoseconds since midnight.
+fn parse_time_of_day(text: &str) -> LitResult<i64> {
+    const ERR: &str = "invalid time of day literal";
+    let parts = text.replace('_', "");
+    let parts = parts.split(':').collect::<Vec<_>>();
+    if parts.len() < 2 || parts.len() > 3 {
+        return Err(ERR);
+    }
+    let (h, m, s) = (parts[0], parts[1], parts.get(2).cloned().unwrap_or("0"));
+    let h: i64 = h.parse().map_err(|_| ERR)?;
+    let m: i64 = m.parse().map_err(|_| ERR)?;
+    let s: f64 = s.parse().map_err(|_| ERR)?;
+    if !(0..=23).contains(&h) || !(0..=59).contains(&m) || !(0. ..60.).contains(&s) {
+        return Err(ERR);
+    }
+    Ok((h * 3600 + m * 60) * NS_PER_S + (s * NS_PER_S as f64).round() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(text: &str) -> String {
+        format!("{:?}", parse_typed(text).unwrap())
+    }
+
+    #[test]
+    fn numbers_and_strings() {
+        assert_eq!(parse_int("1_000"), Ok((10, 1000)));
+        assert_eq!(parse_int("16#FF_FF"), Ok((16, 0xffff)));
+        assert_eq!(parse_int("2#1010"), Ok((2, 10)));
+        assert_eq!(parse_int("16#FFFFFFFFFFFFFFFF"), Ok((16, -1)));
+        assert_eq!(parse_int("18446744073709551615"), Ok((10, -1)));
+        assert!(parse_int("18446744073709551616").is_err());
+        assert!(parse_int("3#12").is_err());
+        assert_eq!(parse_float("1_000.5e-1"), Ok(100.05));
+        assert_eq!(parse_string("'it$'s $$5$N$41'").unwrap(), "it's $5\nA");
+        assert_eq!(parse_string("\"$0041$\"x\"").unwrap(), "A\"x");
+        assert!(parse_string("'$4'").is_err());
+    }
+
+    #[test]
+    fn typed_literals() {
+        assert_eq!(typed("T#1d2h3m4s5ms"), format!("Time({})", ((26 * 60 + 3) * 60 + 4) * NS_PER_S +
+                                                                5 * NS_PER_MS));
+        assert_eq!(typed("time#1.5s"), "Time(1500000000)");
+        assert_eq!(typed("LTIME#-2us_5ns"), "LTime(-2005)");
+        assert_eq!(typed("D#1970-01-02"), "Date(86400)");
+        assert_eq!(typed("DATE#2000-03-01"), "Date(951868800)");
+        assert_eq!(typed("TOD#12:30:15.5"), "Tod(45015500000000)");
+        assert_eq!(typed("DT#1970-01-01-00:01"), "Dt(60)");
+        assert_eq!(typed("INT#16#10"), "Typed(\"INT\", Int(16, 16))");
+        assert_eq!(typed("REAL#1.5"), "Typed(\"REAL\", Float(1.5))");
+        assert_eq!(typed("BOOL#true"), "Typed(\"BOOL\", Bool(true))");
+        assert_eq!(typed("E_Mode#Auto"), "Enum(\"E_Mode\", \"Auto\")");
+        for text in &["T#1x", "T#", "D#2001-02-29", "TOD#24:00", "E#a.b"] {
+            assert!(parse_typed(text).is_err(), "{}", text);
+        }
+    }
+}
@@ -0,0 +1,309 @@
+// *****************************************************************************
+// Charon: Beckhoff TwinCat/ST testing and simulation tools
+// Copyright (c) 2017 by the contributors (see AUTHORS)
+//
+// This program is free software; you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation; either version 2 of the License, or (at your option) any later
+// version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE.  See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program; if not, write to the Free Software Foundation, Inc.,
+// 59 Temple Place, Suite 330, Boston, MA  02111-1307  USA
+//
+// Module authors:
+//   Georg Brandl <g.brandl@fz-juelich.de>
+//
+// *****************************************************************************
+
+//! Evaluation of conditional compilation pragmas.
+
+use std::collections::{HashMap, HashSet};
+use regex::Regex;
+
+use ATTRIBUTE_RX;
+use scan::{scan, Kind};
+
+/// Names defined for conditional compilation, e.g. to select between
+/// simulation and hardware builds.
+pub type Defines = HashSet<String>;
+
+/// A preprocessing error: start and end offset in the input, and message.
+pub(crate) type PreprocessError = (usize, usize, String);
+
+lazy_static! {
+    static ref KEYWORD_RX: Regex = Regex::new(r"(?s)^\{\s*([a-zA-Z_]+)\s*(.*?)\s*\}$").unwrap();
+    static ref COND_TOKEN_RX: Regex = Regex::new(r"\s*([a-zA-Z_][a-zA-Z_0-9]*|'[^']*'|\S)").unwrap();
+}
+
+/// State of an `{IF}` block.
+struct Cond {
+    /// Start of the `{IF}` pragma.
+    start: usize,
+    /// If the enclosing code is active.
+    outer: bool,
+    /// If one of the branches has been active.
+    taken: bool,
+    /// If the `{ELSE}` branch has been reached.
+    in_else: bool,
+}
+
+/// Evaluate the conditional compilation pragmas in the input.
+///
+/// Code in inactive branches and all pragmas except attributes are replaced
+/// by spaces, keeping line breaks, so that offsets into the input stay the
+/// same.  `{define}` and `{undefine}` change the defines for the rest of the
+/// input.
+pub(crate) fn preprocess(input: &str, defines: &Defines) -> Result<String, PreprocessError> {
+    let mut defines = defines.iter().map(|d| (d.to_uppercase(), String::new()))
+                                    .collect::<HashMap<_, _>>();
+    let mut output = String::with_capacity(input.len());
+    let mut stack: Vec<Cond> = vec![];
+    let mut active = true;
+    for piece in scan(input)? {
+        let text = &input[piece.start..piece.end];
+        if piece.kind != Kind::Pragma || ATTRIBUTE_RX.is_match(text) {
+            copy(&mut output, text, active);
+            continue;
+        }
+        copy(&mut output, text, false);
+        let (keyword, arg) = match KEYWORD_RX.captures(text) {
+            Some(cap) => (cap[1].to_uppercase(), cap.get(2).unwrap().as_str()),
+            None => continue,
+        };
+        let error = |msg: &str| (piece.start, piece.end, msg.to_string());
+        match &keyword[..] {
+            "DEFINE" if active => {
+                // a value can follow the name, for use with hasvalue()
+                let mut parts = arg.splitn(2, char::is_whitespace);
+                let name = parts.next().filter(|n| !n.is_empty())
+                                .ok_or_else(|| error("missing name"))?;
+                let value = parts.next().unwrap_or("").trim();
+                let value = value.strip_prefix('\'').and_then(|v| v.strip_suffix('\''))
+                                 .unwrap_or(value);
+                defines.insert(name.to_uppercase(), value.into());
+            }
+            "UNDEFINE" if active => {
+                defines.remove(&arg.trim().to_uppercase());
+            }
+            "IF" => {
+                let cond = active && eval(arg, &defines).map_err(|e| error(&e))?;
+                stack.push(Cond { start: piece.start, outer: active, taken: cond, in_else: false });
+                active = cond;
+            }
+            "ELSIF" => {
+                let top = match stack.last_mut() {
+                    Some(ref top) if top.in_else => return Err(error("{ELSIF} after {ELSE}")),
+                    Some(top) => top,
+                    None => return Err(error("{ELSIF} without {IF}")),
+                };
+                let cond = top.outer && !top.taken && eval(arg, &defines).map_err(|e| error(&e))?;
+                top.taken |= cond;
+                active = cond;
+            }
+            "ELSE" => {
+                let top = match stack.last_mut() {
+                    Some(ref top) if top.in_else => return Err(error("duplicate {ELSE}")),
+                    Some(top) => top,
+                    None => return Err(error("{ELSE} without {IF}")),
+                };
+                active = top.outer && !top.taken;
+                top.in_else = true;
+            }
+            "END_IF" => match stack.pop() {
+                Some(top) => active = top.outer,
+                None => return Err(error("{END_IF} without {IF}")),
+            },
+            _ => {}
+        }
+    }
+    if let Some(top) = stack.last() {
+        let end = input[top.start..].find('}').map_or(input.len(), |i| top.start + i + 1);
+        return Err((top.start, end, "{IF} without {END_IF}".into()));
+    }
+    Ok(output)
+}
+
+/// Append text to the output, or spaces in its place if not active.
+fn copy(output: &mut String, text: &str, active: bool) {
+    if active {
+        output.push_str(text);
+    } else {
+        output.extend(text.chars().map(|c| if c == '\n' || c == '\r' { c } else { ' ' }));
+    }
+}
+
+/// Evaluate the condition of an `{IF}` or `{ELSIF}`.
+///
+/// Supported are `defined(name)` and `hasvalue(name, 'value')`, combined with
+/// NOT, AND, OR and parentheses.
+///
+/// Checks for declarations like `defined(variable:x)`, `defined(pou:X)` or
+/// `defined(type:T)` depend on other objects of the project, which are not
+/// known while preprocessing, and are treated as false.
+fn eval(cond: &str, defines: &HashMap<String, String>) -> Result<bool, String> {
+    // names are case insensitive, but values are not
+    let tokens = COND_TOKEN_RX.captures_iter(cond).map(|cap| match &cap[1] {
+        tok if tok.starts_with('\'') => tok.to_string(),
+        tok => tok.to_uppercase(),
+    }).collect::<Vec<_>>();
+    let mut parser = CondParser { tokens: &tokens, pos: 0, defines };
+    let result = parser.or_expr()?;
+    match tokens.get(parser.pos) {
+        None => Ok(result),
+        Some(tok) => Err(format!("unexpected `{}` in condition", tok)),
+    }
+}
+
+struct CondParser<'a> {
+    tokens: &'a [String],
+    pos: usize,
+    defines: &'a HashMap<String, String>,
+}
+
+impl<'a> CondParser<'a> {
+    fn next(&mut self) -> Option<&'a str> {
+        let tok = self.tokens.get(self.pos).map(|t| &t[..]);
+        self.pos += 1;
+        tok
+    }
+
+    fn accept(&mut self, tok: &str) -> bool {
+        if self.tokens.get(self.pos).is_some_and(|t| t == tok) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn name(&mut self, func: &str) -> Result<&'a str, String> {
+        match self.next() {
+            Some(name) if name.starts_with(|c: char| c.is_alphabetic() || c == '_') => Ok(name),
+            _ => Err(format!("expected a name in {}()", func)),
+        }
+    }
+
+    fn expect(&mut self, tok: &str) -> Result<(), String> {
+        if self.accept(tok) {
+            Ok(())
+        } else {
+            Err(format!("expected `{}` in condition", tok))
+        }
+    }
+
+    fn or_expr(&mut self) -> Result<bool, String> {
+        let mut result = self.and_expr()?;
+        while self.accept("OR") {
+            result |= self.and_expr()?;
+        }
+        Ok(result)
+    }
+
+    fn and_expr(&mut self) -> Result<bool, String> {
+        let mut result = self.not_expr()?;
+        while self.accept("AND") {
+            result &= self.not_expr()?;
+        }
+        Ok(result)
+    }
+
+    fn not_expr(&mut self) -> Result<bool, String> {
+        if self.accept("NOT") {
+            return self.not_expr().map(|v| !v);
+        }
+        if self.accept("(") {
+            let result = self.or_expr()?;
+            self.expect(")")?;
+            return Ok(result);
+        }
+        match self.next() {
+            Some("DEFINED") => {
+                self.expect("(")?;
+                let name = self.name("defined")?;
+                if self.accept(":") {
+                    // a declaration check: skip the (possibly qualified) name
+                    self.name("defined")?;
+                    while self.accept(".") {
+                        self.name("defined")?;
+                    }
+                    self.expect(")")?;
+                    return Ok(false);
+                }
+                self.expect(")")?;
+                Ok(self.defines.contains_key(name))
+            }
+            Some("HASVALUE") => {
+                self.expect("(")?;
+                let name = self.name("hasvalue")?;
+                self.expect(",")?;
+                let value = match self.next() {
+                    Some(value) if value.starts_with('\'') => &value[1..value.len()-1],
+                    _ => return Err("expected a string in hasvalue()".into()),
+                };
+                self.expect(")")?;
+                Ok(self.defines.get(name).is_some_and(|v| v == value))
+            }
+            Some(tok) => Err(format!("unsupported `{}` in condition", tok)),
+            None => Err("unexpected end of condition".into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Preprocess and return the code that is left, without whitespace.
+    fn active(input: &str, defines: &[&str]) -> String {
+        let defines = defines.iter().map(|d| d.to_string()).collect();
+        let output = preprocess(input, &defines).unwrap();
+        assert_eq!(output.len(), input.len());
+        output.split_whitespace().collect()
+    }
+
+    #[test]
+    fn conditions() {
+        let input = "{IF defined(SIM)} a {ELSIF defined(hw) AND NOT defined(test)} b \
+                     {ELSE} c {END_IF}";
+        assert_eq!(active(input, &["sim"]), "a");
+        assert_eq!(active(input, &["HW"]), "b");
+        assert_eq!(active(input, &["HW", "TEST"]), "c");
+        assert_eq!(active("{IF defined(A) OR (defined(B) AND defined(C))} x {END_IF}", &["B"]),
+                   "");
+        // nested blocks in inactive code stay inactive
+        assert_eq!(active("{IF defined(A)} {IF defined(B)} x {ELSE} y {END_IF} {END_IF} z",
+                          &["B"]), "z");
+        assert_eq!(active("{define X} {IF defined(X)} a {END_IF} {undefine X} \
+                           {IF defined(X)} b {END_IF}", &[]), "a");
+        assert_eq!(active("{define Mode 'Sim'} {IF hasvalue(MODE, 'Sim')} a {END_IF} \
+                           {IF hasvalue(mode, 'sim') OR hasvalue(B, 'Sim')} b {END_IF}", &[]), "a");
+        assert_eq!(active("{define N 2} {IF hasvalue(N,'2')} a {ELSE} b {END_IF}", &[]), "a");
+        // declarations are not known while preprocessing
+        assert_eq!(active("{IF defined(variable:GVL.x) OR defined(pou:FB_X) OR \
+                           defined(type:ST_T)} a {ELSE} b {END_IF}", &[]), "b");
+        // attributes are kept, line breaks too
+        let output = preprocess("{attribute 'x'}\n{IF defined(A)}\ny\n{END_IF}",
+                                &Defines::new()).unwrap();
+        assert_eq!(output.lines().map(str::trim).collect::<Vec<_>>(),
+                   vec!["{attribute 'x'}", "", "", ""]);
+    }
+
+    #[test]
+    fn errors() {
+        let error = |input| preprocess(input, &Defines::new()).unwrap_err();
+        assert_eq!(error("{IF defined(A)} x"), (0, 15, "{IF} without {END_IF}".into()));
+        assert_eq!(error("x {END_IF}"), (2, 10, "{END_IF} without {IF}".into()));
+        assert_eq!(error("{IF defined(A)} {ELSE} {ELSIF defined(B)} {END_IF}").2,
+                   "{ELSIF} after {ELSE}");
+        assert_eq!(error("{IF defined(A) AND} {END_IF}").2, "unexpected end of condition");
+        assert_eq!(error("{IF A} {END_IF}").2, "unsupported `A` in condition");
+        assert_eq!(error("{IF defined()} {END_IF}").2, "expected a name in defined()");
+        assert_eq!(error("{IF hasvalue(A, B)} {END_IF}").2, "expected a string in hasvalue()");
+    }
+}
@@ -0,0 +1,219 @@
+// *****************************************************************************
+// Charon: Beckhoff TwinCat/ST testing and simulation tools
+// Copyright (c) 2017 by the contributors (see AUTHORS)
+//
+// This program is free software; you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation; either version 2 of the License, or (at your option) any later
+// version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE.  See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program; if not, write to the Free Software Foundation, Inc.,
+// 59 Temple Place, Suite 330, Boston, MA  02111-1307  USA
+//
+// Module authors:
+//   Georg Brandl <g.brandl@fz-juelich.de>
+//
+// *****************************************************************************
+
+//! Splitting of the input into code, string literals, comments and pragmas.
+//!
+//! This is the first stage before parsing: the later stages need to know
+//! where comments and pragmas are, and these can contain string delimiters,
+//! and vice versa.  It is also used to normalize the case of keywords.
+
+use regex::{Captures, Regex};
+
+use preprocess::PreprocessError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Kind {
+    Code,
+    /// A `'STRING'` or `"WSTRING"` literal.
+    Str,
+    /// A `(* block *)` comment, which can be nested, or a `// line` comment.
+    Comment,
+    /// A `{pragma}`.
+    Pragma,
+}
+
+/// A piece of the input, with its start and end offset.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Piece {
+    pub kind: Kind,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Split the input into pieces of code, strings, comments and pragmas.
+///
+/// All delimiters are ASCII, so the input can be scanned bytewise.
+pub(crate) fn scan(input: &str) -> Result<Vec<Piece>, PreprocessError> {
+    let bytes = input.as_bytes();
+    let mut pieces = vec![];
+    let mut code_start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let (kind, end) = match (bytes[i], bytes.get(i + 1)) {
+            (b'(', Some(&b'*')) => (Kind::Comment, block_comment_end(bytes, i)?),
+            (b'/', Some(&b'/')) => {
+                let end = bytes[i..].iter().position(|&b| b == b'\n' || b == b'\r');
+                (Kind::Comment, end.map_or(bytes.len(), |n| i + n))
+            }
+            (quote @ b'\'', _) | (quote @ b'"', _) => (Kind::Str, string_end(bytes, i, quote)?),
+            (b'{', _) => (Kind::Pragma, pragma_end(bytes, i)?),
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        if code_start < i {
+            pieces.push(Piece { kind: Kind::Code, start: code_start, end: i });
+        }
+        pieces.push(Piece { kind, start: i, end });
+        i = end;
+        code_start = end;
+    }
+    if code_start < bytes.len() {
+        pieces.push(Piece { kind: Kind::Code, start: code_start, end: bytes.len() });
+    }
+    Ok(pieces)
+}
+
+/// The keywords of the grammar, which is written in uppercase.
+const KEYWORDS: &[&str] = &[
+    "ABSTRACT", "AND", "AND_THEN", "ARRAY", "AT", "BY", "CASE", "CONSTANT", "CONTINUE", "DO",
+    "ELSE", "ELSIF", "END_CASE", "END_FOR", "END_FUNCTION", "END_FUNCTION_BLOCK", "END_IF",
+    "END_METHOD", "END_PROGRAM", "END_REPEAT", "END_STRUCT", "END_TYPE", "END_UNION",
+    "END_VAR", "END_WHILE", "EXIT", "EXTENDS", "FALSE", "FINAL", "FOR", "FUNCTION",
+    "FUNCTION_BLOCK", "IF", "IMPLEMENTS", "INTERFACE", "INTERNAL", "METHOD", "MOD", "NOT",
+    "OF", "OR", "OR_ELSE", "PERSISTENT", "POINTER", "PRIVATE", "PROGRAM", "PROPERTY",
+    "PROTECTED", "PUBLIC", "REF=", "REFERENCE", "REPEAT", "RETAIN", "RETURN", "STRING",
+    "STRUCT", "THEN", "TO", "TRUE", "TYPE", "UNION", "UNTIL", "VAR", "VAR_EXTERNAL",
+    "VAR_GLOBAL", "VAR_INPUT", "VAR_INST", "VAR_IN_OUT", "VAR_OUTPUT", "VAR_STAT", "VAR_TEMP",
+    "WHILE", "WSTRING", "XOR",
+];
+
+lazy_static! {
+    /// A word, with a `#` before or after it if it is part of a typed
+    /// literal, and a following `=` for `REF=`.
+    static ref WORD_RX: Regex = Regex::new(r"#?\b[a-zA-Z_][a-zA-Z0-9_]*\b[#=]?").unwrap();
+}
+
+/// Convert keywords in the code to uppercase, as the grammar expects them,
+/// so that they are matched case-insensitively.  Offsets into the input stay
+/// the same.
+pub(crate) fn upcase_keywords(input: &str) -> Result<String, PreprocessError> {
+    let mut output = String::with_capacity(input.len());
+    for piece in scan(input)? {
+        let text = &input[piece.start..piece.end];
+        if piece.kind != Kind::Code {
+            output.push_str(text);
+            continue;
+        }
+        output.push_str(&WORD_RX.replace_all(text, |cap: &Captures| {
+            let upper = cap[0].to_ascii_uppercase();
+            if !upper.starts_with('#') && !upper.ends_with('#') &&
+                (KEYWORDS.contains(&&upper[..]) ||
+                 KEYWORDS.contains(&upper.trim_end_matches('=')))
+            {
+                upper
+            } else {
+                cap[0].to_string()
+            }
+        }));
+    }
+    Ok(output)
+}
+
+/// Find the end of a block comment starting at `start`, counting nested
+/// comments.
+fn block_comment_end(bytes: &[u8], start: usize) -> Result<usize, PreprocessError> {
+    let mut depth = 0;
+    let mut i = start;
+    while i + 1 < bytes.len() {
+        match (bytes[i], bytes[i + 1]) {
+            (b'(', b'*') => { depth += 1; i += 2; }
+            (b'*', b')') => {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    return Ok(i);
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    Err((start, start + 2, "unterminated comment".into()))
+}
+
+/// Find the end of a string literal starting at `start`.  Within the string,
+/// `$` escapes the following character.
+fn string_end(bytes: &[u8], start: usize, quote: u8) -> Result<usize, PreprocessError> {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'$' => i += 2,
+            b'\n' | b'\r' => break,
+            b if b == quote => return Ok(i + 1),
+            _ => i += 1,
+        }
+    }
+    Err((start, start + 1, "unterminated string literal".into()))
+}
+
+/// Find the end of a pragma starting at `start`.  Braces within quoted
+/// values do not end the pragma.
+fn pragma_end(bytes: &[u8], start: usize) -> Result<usize, PreprocessError> {
+    let mut i = start + 1;
+    let mut quoted = false;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\'' => quoted = !quoted,
+            b'}' if !quoted => return Ok(i + 1),
+            _ => {}
+        }
+        i += 1;
+    }
+    Err((start, start + 1, "unterminated pragma".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(input: &str) -> Vec<(Kind, &str)> {
+        scan(input).unwrap().into_iter().map(|p| (p.kind, &input[p.start..p.end])).collect()
+    }
+
+    #[test]
+    fn nested_comments_and_strings() {
+        assert_eq!(kinds("a (* x (* y *) z *) b"),
+                   vec![(Kind::Code, "a "), (Kind::Comment, "(* x (* y *) z *)"),
+                        (Kind::Code, " b")]);
+        // comment delimiters in strings, and quotes in comments
+        assert_eq!(kinds("s := '(* $'x'; // it's\nw := \"//\";"),
+                   vec![(Kind::Code, "s := "), (Kind::Str, "'(* $'x'"), (Kind::Code, "; "),
+                        (Kind::Comment, "// it's"), (Kind::Code, "\nw := "),
+                        (Kind::Str, "\"//\""), (Kind::Code, ";")]);
+        assert_eq!(kinds("{attribute 'x' := '}'} (* '{' *)"),
+                   vec![(Kind::Pragma, "{attribute 'x' := '}'}"), (Kind::Code, " "),
+                        (Kind::Comment, "(* '{' *)")]);
+        assert_eq!(scan("a (* (* b *)").unwrap_err().2, "unterminated comment");
+        assert_eq!(scan("a := 'b\n';").unwrap_err().2, "unterminated string literal");
+    }
+
+    #[test]
+    fn keywords_are_upcased() {
+        assert_eq!(upcase_keywords("if x then y ref= z; end_if (* if *) 'then'").unwrap(),
+                   "IF x THEN y REF= z; END_IF (* if *) 'then'");
+        // typed literals and identifiers that contain keywords are kept
+        assert_eq!(upcase_keywords("t := time#1s; to_int := For_x;").unwrap(),
+                   "t := time#1s; to_int := For_x;");
+    }
+}
@@ -1,10542 +0,0 @@
-// auto-generated: "lalrpop 0.13.1"
-use ast::*;
-use app;
-#[allow(unused_extern_crates)]
-extern crate lalrpop_util as __lalrpop_util;
-
-mod __parse__file {
-    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports)]
-
-    use ast::*;
-    use app;
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    use super::__intern_token::Token;
-    #[allow(dead_code)]
-    pub enum __Symbol<'input>
-     {
-        Term_22_28_22(&'input str),
-        Term_22_29_22(&'input str),
-        Term_22_2a_22(&'input str),
-        Term_22_2b_22(&'input str),
-        Term_22_2c_22(&'input str),
-        Term_22_2d_22(&'input str),
-        Term_22_2e_22(&'input str),
-        Term_22_2e_2e_22(&'input str),
-        Term_22_2f_22(&'input str),
-        Term_22_3a_22(&'input str),
-        Term_22_3a_3d_22(&'input str),
-        Term_22_3b_22(&'input str),
-        Term_22_3c_22(&'input str),
-        Term_22_3c_3d_22(&'input str),
-        Term_22_3c_3e_22(&'input str),
-        Term_22_3d_22(&'input str),
-        Term_22_3d_3e_22(&'input str),
-        Term_22_3e_22(&'input str),
-        Term_22_3e_3d_22(&'input str),
-        Term_22AND_22(&'input str),
-        Term_22AND__THEN_22(&'input str),
-        Term_22ARRAY_22(&'input str),
-        Term_22AT_22(&'input str),
-        Term_22CASE_22(&'input str),
-        Term_22CONSTANT_22(&'input str),
-        Term_22DO_22(&'input str),
-        Term_22ELSE_22(&'input str),
-        Term_22ELSIF_22(&'input str),
-        Term_22END__CASE_22(&'input str),
-        Term_22END__FUNCTION_22(&'input str),
-        Term_22END__FUNCTION__BLOCK_22(&'input str),
-        Term_22END__IF_22(&'input str),
-        Term_22END__PROGRAM_22(&'input str),
-        Term_22END__STRUCT_22(&'input str),
-        Term_22END__TYPE_22(&'input str),
-        Term_22END__VAR_22(&'input str),
-        Term_22END__WHILE_22(&'input str),
-        Term_22FALSE_22(&'input str),
-        Term_22FUNCTION_22(&'input str),
-        Term_22FUNCTION__BLOCK_22(&'input str),
-        Term_22IF_22(&'input str),
-        Term_22MOD_22(&'input str),
-        Term_22NOT_22(&'input str),
-        Term_22OF_22(&'input str),
-        Term_22OR_22(&'input str),
-        Term_22OR__ELSE_22(&'input str),
-        Term_22PERSISTENT_22(&'input str),
-        Term_22PROGRAM_22(&'input str),
-        Term_22RETAIN_22(&'input str),
-        Term_22RETURN_22(&'input str),
-        Term_22STRING_22(&'input str),
-        Term_22STRUCT_22(&'input str),
-        Term_22THEN_22(&'input str),
-        Term_22TRUE_22(&'input str),
-        Term_22TYPE_22(&'input str),
-        Term_22VAR_22(&'input str),
-        Term_22VAR__GLOBAL_22(&'input str),
-        Term_22VAR__INPUT_22(&'input str),
-        Term_22VAR__IN__OUT_22(&'input str),
-        Term_22VAR__OUTPUT_22(&'input str),
-        Term_22WHILE_22(&'input str),
-        Term_22XOR_22(&'input str),
-        Term_22_5b_22(&'input str),
-        Term_22_5d_22(&'input str),
-        Termr_23_22_25_5bMIQ_5d_5bBWX_5d_3f_28_5c_5c_2a_7c_5b0_2d9_5d_2b_29_22_23(&'input str),
-        Termr_23_22_5c_27_28_5b_5e_5c_27_5d_7c_5c_27_5c_27_29_2a_5c_27_22_23(&'input str),
-        Termr_23_22_2d_3f_5b0_2d9_5d_2b_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_22_23(&'input str),
-        Termr_23_22_2d_3f_5b0_2d9_5d_2b_5c_5c_2e_5b0_2d9_5d_2b_28_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_3f_22_23(&'input str),
-        Termr_23_2216_23_2d_3f_5b0_2d9a_2dfA_2dF_5d_2b_22_23(&'input str),
-        Termr_23_222_23_2d_3f_5b01_5d_2b_22_23(&'input str),
-        Termr_23_228_23_2d_3f_5b0_2d7_5d_2b_22_23(&'input str),
-        Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
-        Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ__0_2d9_5d_2a_22_23(&'input str),
-        Termr_23_22_5btT_5d_23_5b0_2d9_5d_2b_28_5c_5c_2e_5b0_2d9_5d_2b_29_3f_28ms_7cMS_7cs_7cS_7ch_7cH_7cd_7cD_29_22_23(&'input str),
-        Termerror(__lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>),
-        Nt_22CONSTANT_22_3f(::std::option::Option<&'input str>),
-        Nt_22END__FUNCTION__BLOCK_22_3f(::std::option::Option<&'input str>),
-        Nt_22END__PROGRAM_22_3f(::std::option::Option<&'input str>),
-        Nt_22PERSISTENT_22_3f(::std::option::Option<&'input str>),
-        Nt_22RETAIN_22_3f(::std::option::Option<&'input str>),
-        Nt_28_22_3a_3d_22_20_3cvar__default_3e_29(Expr),
-        Nt_28_22_3a_3d_22_20_3cvar__default_3e_29_3f(::std::option::Option<Expr>),
-        Nt_28_22AT_22_20_3cloc_3e_29(String),
-        Nt_28_22AT_22_20_3cloc_3e_29_3f(::std::option::Option<String>),
-        Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29(::std::vec::Vec<VarDef>),
-        Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2a(::std::vec::Vec<::std::vec::Vec<VarDef>>),
-        Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(::std::vec::Vec<::std::vec::Vec<VarDef>>),
-        Nt_28_3cargassign_3e_20_22_2c_22_29(Kwarg),
-        Nt_28_3cargassign_3e_20_22_2c_22_29_2a(::std::vec::Vec<Kwarg>),
-        Nt_28_3cargassign_3e_20_22_2c_22_29_2b(::std::vec::Vec<Kwarg>),
-        Nt_28_3ccase__match_3e_20_22_2c_22_29(CaseExpr),
-        Nt_28_3ccase__match_3e_20_22_2c_22_29_2a(::std::vec::Vec<CaseExpr>),
-        Nt_28_3ccase__match_3e_20_22_2c_22_29_2b(::std::vec::Vec<CaseExpr>),
-        Nt_28_3cexpr_3e_20_22_2c_22_29(Expr),
-        Nt_28_3cexpr_3e_20_22_2c_22_29_2a(::std::vec::Vec<Expr>),
-        Nt_28_3cexpr_3e_20_22_2c_22_29_2b(::std::vec::Vec<Expr>),
-        Nt_28_3cliteral__expr_3e_20_22_2c_22_29(Expr),
-        Nt_28_3cliteral__expr_3e_20_22_2c_22_29_2a(::std::vec::Vec<Expr>),
-        Nt_28_3cliteral__expr_3e_20_22_2c_22_29_2b(::std::vec::Vec<Expr>),
-        Nt____file(POU),
-        Ntand__expr(Expr),
-        Ntargassign(Kwarg),
-        Ntatom__expr(Expr),
-        Ntcall__expr(Expr),
-        Ntcase(Case),
-        Ntcase_2b(::std::vec::Vec<Case>),
-        Ntcase__else(Vec<Stmt>),
-        Ntcase__match(CaseExpr),
-        Ntcomma_3cargassign_3e(Vec<Kwarg>),
-        Ntcomma_3ccase__match_3e(Vec<CaseExpr>),
-        Ntcomma_3cexpr_3e(Vec<Expr>),
-        Ntcomp__expr(Expr),
-        Ntdecint(i64),
-        Nteq__expr(Expr),
-        Ntexpr(Expr),
-        Ntfb__vars(Vec<VarBlock>),
-        Ntfile(POU),
-        Ntfloat(f64),
-        Ntfun__vars(Vec<VarBlock>),
-        Ntfunction(POU),
-        Ntfunction__block(POU),
-        Ntglobals(POU),
-        Ntident(String),
-        Ntif__else(Vec<Stmt>),
-        Ntinteger((u16, i64)),
-        Ntliteral__expr(Expr),
-        Ntloc(String),
-        Ntlval__expr(Expr),
-        Ntprod__expr(Expr),
-        Ntprogram(POU),
-        Ntstmt(Stmt),
-        Ntstmt_2b(::std::vec::Vec<Stmt>),
-        Ntstructinit(Vec<(String, Expr)>),
-        Ntsum__expr(Expr),
-        Nttype__(Type),
-        Nttypedef(POU),
-        Ntun__expr(Expr),
-        Ntvar__def(VarDef),
-        Ntvar__def_2a(::std::vec::Vec<VarDef>),
-        Ntvar__def_2b(::std::vec::Vec<VarDef>),
-        Ntvar__default(Expr),
-        Ntxor__expr(Expr),
-    }
-    const __ACTION: &'static [i32] = &[
-        // State 0
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 9, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 11, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 2
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 3
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 7
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 9
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 11
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 12
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 13
-        -130, -130, -130, -130, -130, -130, -130, 0, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, 0, -130, -130, 0, -130, 0, 0, 0, 0, 0, 0, 0, 0, -130, 0, 0, 0, 0, 0, -130, -130, 0, -130, -130, -130, 0, 0, 0, -130, 0, 0, -130, 0, 0, -130, 0, 0, 0, 0, -130, -130, -130, -130, 0, 0, 0, 0, 0, 0, 0, 0, -130, 0, -130,
-        // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24,
-        // State 15
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 16
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 17
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -189, 0, -189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -189, 0, 0,
-        // State 19
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 20
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 21
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 22
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 48, 0, 49, 50, 51, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 23
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, -112, 0, -112, -112, -112, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0,
-        // State 24
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 25
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 26
-        55, 0, 0, 0, 0, 0, -148, 0, 0, 0, -148, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 27
-        0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 28
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -165, 0, 0, -165, -165, -165, -165, -165, -165, -165, 0, 0, 0, -165, -165, 0, 0, -165, 0, 0, 0, 0, 0, 0, 0, 0, -165, 0, 0, 0, -165, 0, 0, 0, 0, 0, 0, -165, 0, 0, 0, 0, -165, -165, -165, -165, -165, -165, -165, -165, -165, 0,
-        // State 29
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 30
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -164, 0, 0, -164, -164, -164, -164, -164, -164, -164, 0, 0, 0, -164, -164, 0, 0, -164, 0, 0, 0, 0, 0, 0, 0, 0, -164, 0, 0, 0, -164, 0, 0, 0, 0, 0, 0, -164, 0, 0, 0, 0, -164, -164, -164, -164, -164, -164, -164, -164, -164, 0,
-        // State 31
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 32
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 33
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 95, 0, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 35
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 36
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 37
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 38
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 39
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -190, 0, -190, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -190, 0, 0,
-        // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 41
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 42
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -177, -177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -177,
-        // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 105,
-        // State 44
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 45
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -176, -176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -176,
-        // State 46
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 47
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 48
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 49
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 50
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 51
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 52
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 53
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -163, 0, 0, -163, -163, -163, -163, -163, -163, -163, 0, 0, 0, -163, -163, 0, 0, -163, 0, 0, 0, 0, 0, 0, 0, 0, -163, 0, 0, 0, -163, 0, 0, 0, 0, 0, 0, -163, 0, 0, 0, 0, -163, -163, -163, -163, -163, -163, -163, -163, -163, 0,
-        // State 54
-        78, 129, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 55
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -162, 0, 0, -162, -162, -162, -162, -162, -162, -162, 0, 0, 0, -162, -162, 0, 0, -162, 0, 0, 0, 0, 0, 0, 0, 0, -162, 0, 0, 0, -162, 0, 0, 0, 0, 0, 0, -162, 0, 0, 0, 0, -162, -162, -162, -162, -162, -162, -162, -162, -162, 0,
-        // State 56
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 89, 14, 0, 0,
-        // State 57
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 58
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 59
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -166, 0, 0, -166, -166, -166, -166, -166, -166, -166, 0, 0, 0, -166, -166, 0, 0, -166, 0, 0, 0, 0, 0, 0, 0, 0, -166, 0, 0, 0, -166, 0, 0, 0, 0, 0, 0, -166, 0, 0, 0, 0, -166, -166, -166, -166, -166, -166, -166, -166, -166, 0,
-        // State 60
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 61
-        0, -193, 0, 0, -193, 0, 0, 0, 0, 0, 0, -193, 0, 0, 0, 0, 0, 0, 0, 134, 135, 0, 0, 0, 0, -193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -193, -193, -193, 0, 0, 0, 0, 0, 0, -193, 0, 0, 0, 0, 0, 0, 0, 0, -193, 0, -193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 62
-        0, -182, -182, -182, -182, -182, 0, 0, -182, 0, 0, -182, -182, -182, -182, -182, 0, -182, -182, -182, -182, 0, 0, 0, 0, -182, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -182, 0, -182, -182, -182, 0, 0, 0, 0, 0, 0, -182, 0, 0, 0, 0, 0, 0, 0, 0, -182, 0, -182, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 63
-        0, -71, -71, -71, -71, -71, 0, 0, -71, 0, 0, -71, -71, -71, -71, -71, 0, -71, -71, -71, -71, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, -71, -71, -71, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 64
-        0, -100, 0, 0, -100, 0, 0, 0, 0, 0, 0, -100, 0, 0, 136, 137, 0, 0, 0, -100, -100, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, -100, -100, 0, 0, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, -100, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 65
-        0, -137, -137, -137, -137, -137, 0, -137, -137, -137, 0, -137, -137, -137, -137, -137, 0, -137, -137, -137, -137, 0, 0, 0, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -137, 0, -137, -137, -137, 0, 0, 0, 0, 0, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, -137, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 66
-        0, -66, 0, 0, -66, 0, 0, 0, 0, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, -66, -66, 0, 0, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, -66, -66, 0, 0, 0, 0, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 67
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 139, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 68
-        0, -139, -139, -139, -139, -139, 0, -139, -139, -139, 0, -139, -139, -139, -139, -139, 0, -139, -139, -139, -139, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139, 0, -139, -139, -139, 0, 0, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, -139, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 69
-        55, -148, -148, -148, -148, -148, -148, 0, -148, 0, 0, -148, -148, -148, -148, -148, 0, -148, -148, -148, -148, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, 0, -148, -148, -148, 0, 0, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, -148, -148, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 70
-        0, -138, -138, -138, -138, -138, 0, -138, -138, -138, 0, -138, -138, -138, -138, -138, 0, -138, -138, -138, -138, 0, 0, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -138, 0, -138, -138, -138, 0, 0, 0, 0, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, -138, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 71
-        0, -73, -73, -73, -73, -73, 0, 0, -73, 0, 0, -73, -73, -73, -73, -73, 0, -73, -73, -73, -73, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, -73, -73, -73, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 72
-        0, -72, -72, -72, -72, -72, 57, 0, -72, 0, 0, -72, -72, -72, -72, -72, 0, -72, -72, -72, -72, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, -72, -72, -72, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, -72, 59, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 73
-        0, -173, 141, -173, -173, -173, 0, 0, 142, 0, 0, -173, -173, -173, -173, -173, 0, -173, -173, -173, -173, 0, 0, 0, 0, -173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 0, -173, -173, -173, 0, 0, 0, 0, 0, 0, -173, 0, 0, 0, 0, 0, 0, 0, 0, -173, 0, -173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 74
-        0, -96, 0, 144, -96, 145, 0, 0, 0, 0, 0, -96, 146, 147, -96, -96, 0, 148, 149, -96, -96, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, -96, -96, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 75
-        0, -152, -152, -152, -152, -152, 0, 0, -152, 0, 0, -152, -152, -152, -152, -152, 0, -152, -152, -152, -152, 0, 0, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -152, 0, -152, -152, -152, 0, 0, 0, 0, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, -152, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 76
-        0, -103, 0, 0, -103, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, -103, -103, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 150, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 77
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 78
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 79
-        0, -141, -141, -141, -141, -141, 0, -141, -141, -141, 0, -141, -141, -141, -141, -141, 0, -141, -141, -141, -141, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, -141, -141, -141, 0, 0, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 80
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 81
-        0, -140, -140, -140, -140, -140, 0, -140, -140, -140, 0, -140, -140, -140, -140, -140, 0, -140, -140, -140, -140, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, 0, -140, -140, -140, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, -140, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 82
-        0, -143, -143, -143, -143, -143, 0, -143, -143, -143, 0, -143, -143, -143, -143, -143, 0, -143, -143, -143, -143, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143, 0, -143, -143, -143, 0, 0, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, -143, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 83
-        0, -119, -119, -119, -119, -119, 0, -119, -119, -119, 0, -119, -119, -119, -119, -119, 0, -119, -119, -119, -119, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, 0, -119, -119, -119, 0, 0, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, -119, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 84
-        0, -118, -118, -118, -118, -118, 0, -118, -118, -118, 0, -118, -118, -118, -118, -118, 0, -118, -118, -118, -118, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, -118, -118, -118, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 85
-        0, -134, -134, -134, -134, -134, 0, -134, -134, -134, 0, -134, -134, -134, -134, -134, 0, -134, -134, -134, -134, 0, 0, 0, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134, 0, -134, -134, -134, 0, 0, 0, 0, 0, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, -134, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 86
-        0, -136, -136, -136, -136, -136, 0, -136, -136, -136, 0, -136, -136, -136, -136, -136, 0, -136, -136, -136, -136, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -136, 0, -136, -136, -136, 0, 0, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, -136, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 87
-        0, -135, -135, -135, -135, -135, 0, -135, -135, -135, 0, -135, -135, -135, -135, -135, 0, -135, -135, -135, -135, 0, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -135, 0, -135, -135, -135, 0, 0, 0, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, -135, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 88
-        0, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, 0, -97, -97, -97, -97, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, 0, -97, -97, -97, 0, 0, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, -97, -97, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 89
-        0, -142, -142, -142, -142, -142, 0, -142, -142, -142, 0, -142, -142, -142, -142, -142, 0, -142, -142, -142, -142, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, -142, -142, -142, 0, 0, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 90
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 91
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -157, 0, 0, -157, -157, -157, -157, -157, -157, -157, 0, 0, 0, -157, -157, 0, 0, -157, 0, 0, 0, 0, 0, 0, 0, 0, -157, 0, 0, 0, -157, 0, 0, 0, 0, 0, 0, -157, 0, 0, 0, 0, -157, -157, -157, -157, -157, -157, -157, -157, -157, 0,
-        // State 92
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 93
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0,
-        // State 94
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 95
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 96
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 97
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 98
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 99
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 166, 167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 100
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 101
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 102
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 103
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 170, 0, 171, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 104
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, 0, -124, 0, 0, 0, 0, 0, -124, 0, -124, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -124, 0, 0,
-        // State 105
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86, 87, 88, 89, 0, 0, 0,
-        // State 106
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86, 87, 88, 89, 0, 0, 0,
-        // State 107
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 108
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 109
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, -110, 0, -110, -110, -110, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0,
-        // State 110
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 111
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, -106, 0, -106, -106, -106, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0,
-        // State 112
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 113
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, -104, 0, -104, -104, -104, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0,
-        // State 114
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 115
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, -108, 0, -108, -108, -108, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0,
-        // State 116
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 117
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 118
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0,
-        // State 119
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 120
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 182, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 121
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 122
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 123
-        0, -86, 0, 0, 187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 124
-        0, 188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 125
-        0, 189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 126
-        0, -90, 0, 0, 190, 0, 0, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 127
-        55, -148, -148, -148, -148, -148, -148, 0, -148, 0, 191, 0, -148, -148, -148, -148, 192, -148, -148, -148, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, 0, 0, -148, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 128
-        0, -78, -78, -78, -78, -78, 0, 0, -78, 0, 0, -78, -78, -78, -78, -78, 0, -78, -78, -78, -78, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, -78, -78, -78, 0, 0, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 129
-        0, -145, -145, -145, -145, -145, -145, 0, -145, 0, -145, -145, -145, -145, -145, -145, 0, -145, -145, -145, -145, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, 0, -145, -145, -145, 0, 0, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, -145, -145, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 130
-        0, -146, -146, -146, -146, -146, -146, 0, -146, 0, -146, -146, -146, -146, -146, -146, 0, -146, -146, -146, -146, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -146, 0, -146, -146, -146, 0, 0, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, -146, -146, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 131
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 132
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 133
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 134
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 135
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 136
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 137
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 0, 90, 0,
-        // State 138
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 139
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 140
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 141
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 142
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 143
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 144
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 145
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 146
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 147
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 148
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 149
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 150
-        0, 217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 151
-        55, -148, -148, -148, 0, -148, -148, 0, -148, 0, 218, 0, -148, -148, -148, -148, 0, -148, -148, -148, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, 0, 0, -148, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 152
-        0, 219, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 153
-        0, -180, -180, -180, -180, -180, 0, 0, -180, 0, 0, -180, -180, -180, -180, -180, 0, -180, -180, -180, -180, 0, 0, 0, 0, -180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -180, 0, -180, -180, -180, 0, 0, 0, 0, 0, 0, -180, 0, 0, 0, 0, 0, 0, 0, 0, -180, 0, -180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 154
-        0, -181, -181, -181, -181, -181, 0, 0, -181, 0, 0, -181, -181, -181, -181, -181, 0, -181, -181, -181, -181, 0, 0, 0, 0, -181, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -181, 0, -181, -181, -181, 0, 0, 0, 0, 0, 0, -181, 0, 0, 0, 0, 0, 0, 0, 0, -181, 0, -181, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 155
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 156
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0,
-        // State 157
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 221, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 158
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0,
-        // State 159
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 222, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 160
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0,
-        // State 161
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 162
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 163
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 164
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 226, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 165
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 166
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -186, 0, -186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -186, 0, 0,
-        // State 167
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 168
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 169
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 232, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 170
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 234, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 171
-        0, 0, 0, 0, 0, 0, 0, 235, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 172
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 236, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 173
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, -111, 0, -111, -111, -111, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0,
-        // State 174
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, -107, 0, -107, -107, -107, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0,
-        // State 175
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, -105, 0, -105, -105, -105, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0,
-        // State 176
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, -109, 0, -109, -109, -109, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0,
-        // State 177
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0,
-        // State 178
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 237, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 179
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0,
-        // State 180
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 238, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 181
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0,
-        // State 182
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 183
-        0, -87, 0, 0, 241, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 184
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 185
-        0, -91, 0, 0, 242, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 186
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0,
-        // State 187
-        0, -77, -77, -77, -77, -77, 0, 0, -77, 0, 0, -77, -77, -77, -77, -77, 0, -77, -77, -77, -77, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, -77, -77, -77, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 188
-        0, -76, -76, -76, -76, -76, 0, 0, -76, 0, 0, -76, -76, -76, -76, -76, 0, -76, -76, -76, -76, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, -76, -76, -76, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 189
-        -56, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, -56, -56, -56, -56, -56, -56, -56, -56, 0,
-        // State 190
-        78, -68, 0, 0, -68, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 191
-        78, -70, 0, 0, -70, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 192
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -161, 0, 0, -161, -161, -161, -161, -161, -161, -161, 0, 0, 0, -161, -161, 0, 0, -161, 0, 0, 0, 0, 0, 0, 0, 0, -161, 0, 0, 0, -161, 0, 0, 0, 0, 0, 0, -161, 0, 0, 0, 0, -161, -161, -161, -161, -161, -161, -161, -161, -161, 0,
-        // State 193
-        0, -147, -147, -147, -147, -147, -147, 0, -147, 0, -147, -147, -147, -147, -147, -147, 0, -147, -147, -147, -147, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -147, 0, -147, -147, -147, 0, 0, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, -147, -147, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 194
-        0, -64, 0, 0, -64, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, -64, -64, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, -64, -64, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 195
-        0, -65, 0, 0, -65, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, -65, -65, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, -65, -65, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 196
-        0, -99, 0, 0, -99, 0, 0, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, -99, -99, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, -99, -99, 0, 0, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, -99, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 197
-        0, -98, 0, 0, -98, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, -98, -98, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, -98, -98, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 198
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 0, 90, 0,
-        // State 199
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, -80, -80, -80, -80, -80, -80, 0, -80, 0,
-        // State 200
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 0, 249, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 0, 90, 0,
-        // State 201
-        0, 0, 0, 0, 250, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 202
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 251, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 203
-        0, 0, 0, 0, -85, 0, 0, 252, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 204
-        0, -101, 0, 0, -101, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, -101, -101, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 150, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 205
-        0, -102, 0, 0, -102, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, -102, -102, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 150, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 206
-        0, -149, -149, -149, -149, -149, 0, 0, -149, 0, 0, -149, -149, -149, -149, -149, 0, -149, -149, -149, -149, 0, 0, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -149, 0, -149, -149, -149, 0, 0, 0, 0, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, -149, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 207
-        0, -150, -150, -150, -150, -150, 0, 0, -150, 0, 0, -150, -150, -150, -150, -150, 0, -150, -150, -150, -150, 0, 0, 0, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -150, 0, -150, -150, -150, 0, 0, 0, 0, 0, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, -150, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 208
-        0, -151, -151, -151, -151, -151, 0, 0, -151, 0, 0, -151, -151, -151, -151, -151, 0, -151, -151, -151, -151, 0, 0, 0, 0, -151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -151, 0, -151, -151, -151, 0, 0, 0, 0, 0, 0, -151, 0, 0, 0, 0, 0, 0, 0, 0, -151, 0, -151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 209
-        0, -171, 141, -171, -171, -171, 0, 0, 142, 0, 0, -171, -171, -171, -171, -171, 0, -171, -171, -171, -171, 0, 0, 0, 0, -171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 0, -171, -171, -171, 0, 0, 0, 0, 0, 0, -171, 0, 0, 0, 0, 0, 0, 0, 0, -171, 0, -171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 210
-        0, -172, 141, -172, -172, -172, 0, 0, 142, 0, 0, -172, -172, -172, -172, -172, 0, -172, -172, -172, -172, 0, 0, 0, 0, -172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 0, -172, -172, -172, 0, 0, 0, 0, 0, 0, -172, 0, 0, 0, 0, 0, 0, 0, 0, -172, 0, -172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 211
-        0, -93, 0, 144, -93, 145, 0, 0, 0, 0, 0, -93, 0, 0, -93, -93, 0, 0, 0, -93, -93, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, -93, -93, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 212
-        0, -95, 0, 144, -95, 145, 0, 0, 0, 0, 0, -95, 0, 0, -95, -95, 0, 0, 0, -95, -95, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, -95, -95, 0, 0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 213
-        0, -92, 0, 144, -92, 145, 0, 0, 0, 0, 0, -92, 0, 0, -92, -92, 0, 0, 0, -92, -92, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, -92, -92, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 214
-        0, -94, 0, 144, -94, 145, 0, 0, 0, 0, 0, -94, 0, 0, -94, -94, 0, 0, 0, -94, -94, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, -94, -94, 0, 0, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 215
-        0, -192, 0, 0, -192, 0, 0, 0, 0, 0, 0, -192, 0, 0, 0, 0, 0, 0, 0, 134, 135, 0, 0, 0, 0, -192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -192, -192, -192, 0, 0, 0, 0, 0, 0, -192, 0, 0, 0, 0, 0, 0, 0, 0, -192, 0, -192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 216
-        0, -74, -74, -74, -74, -74, 0, 0, -74, 0, 0, -74, -74, -74, -74, -74, 0, -74, -74, -74, -74, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, -74, -74, -74, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 217
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 0, 90, 0,
-        // State 218
-        0, -75, -75, -75, -75, -75, 0, 0, -75, 0, 0, -75, -75, -75, -75, -75, 0, -75, -75, -75, -75, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, -75, -75, -75, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 219
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 256, 257, 0, 0, 0, 258, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 220
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0,
-        // State 221
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0,
-        // State 222
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 223
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0,
-        // State 224
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 260, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 225
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 261, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 226
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -191, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 227
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 262, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 228
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 263, 264, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 229
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 230
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 265, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 231
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, -122, 0, -122, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122, 0, 0,
-        // State 232
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 266, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 233
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, -120, 0, -120, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, 0, 0,
-        // State 234
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86, 87, 88, 89, 0, 0, 0,
-        // State 235
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -175, -175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -175,
-        // State 236
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0,
-        // State 237
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0,
-        // State 238
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 268, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 239
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0,
-        // State 240
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0,
-        // State 241
-        -57, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, -57, -57, -57, -57, -57, -57, -57, -57, 0,
-        // State 242
-        0, -67, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 243
-        0, -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 244
-        0, 0, 0, 0, 269, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 245
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, -81, -81, -81, -81, -81, -81, 0, -81, 0,
-        // State 246
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -159, 0, 0, -159, -159, -159, -159, -159, -159, -159, 0, 0, 0, -159, -159, 0, 0, -159, 0, 0, 0, 0, 0, 0, 0, 0, -159, 0, 0, 0, -159, 0, 0, 0, 0, 0, 0, -159, 0, 0, 0, 0, -159, -159, -159, -159, -159, -159, -159, -159, -159, 0,
-        // State 247
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 248
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, -83, -83, -83, -83, -83, -83, -83, 0, 0, 0, -83, -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, 0, -83, -83, -83, -83, -83, -83, -83, -83, -83, 0,
-        // State 249
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, -51, -51, -51, -51, -51, -51, 0, -51, 0,
-        // State 250
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 251
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 0, 90, 0,
-        // State 252
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 0, 90, 0,
-        // State 253
-        0, -169, 0, 0, 274, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 254
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -158, 0, 0, -158, -158, -158, -158, -158, -158, -158, 0, 0, 0, -158, -158, 0, 0, -158, 0, 0, 0, 0, 0, 0, 0, 0, -158, 0, 0, 0, -158, 0, 0, 0, 0, 0, 0, -158, 0, 0, 0, 0, -158, -158, -158, -158, -158, -158, -158, -158, -158, 0,
-        // State 255
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 256
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 257
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, 0, 0, -133, -133, -133, -133, -133, -133, -133, 0, 0, 0, -133, -133, 0, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, -133, 0, 0, 0, -133, 0, 0, 0, 0, 0, 0, -133, 0, 0, 0, 0, -133, -133, -133, -133, -133, -133, -133, -133, -133, 0,
-        // State 258
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0,
-        // State 259
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -160, 0, 0, -160, -160, -160, -160, -160, -160, -160, 0, 0, 0, -160, -160, 0, 0, -160, 0, 0, 0, 0, 0, 0, 0, 0, -160, 0, 0, 0, -160, 0, 0, 0, 0, 0, 0, -160, 0, 0, 0, 0, -160, -160, -160, -160, -160, -160, -160, -160, -160, 0,
-        // State 260
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 261
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -184, 0, -184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -184, 0, 0,
-        // State 262
-        78, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 85, 86, 87, 88, 89, 14, 90, 0,
-        // State 263
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -185, 0, -185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -185, 0, 0,
-        // State 264
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, -123, 0, -123, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, 0, 0,
-        // State 265
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, -121, 0, -121, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0,
-        // State 266
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 278, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 267
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0,
-        // State 268
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, -52, -52, -52, -52, -52, -52, 0, -52, 0,
-        // State 269
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 279, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 270
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, -79, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, -79, -79, -79, -79, -79, -79, -79, 14, -79, 0,
-        // State 271
-        0, 0, 0, 0, -84, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 272
-        0, -170, 0, 0, 280, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 273
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, -61, -61, -61, -61, -61, -61, 14, -61, 0,
-        // State 274
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 283, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 275
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 0, 0, 0, 0, 0, 0, 284, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 276
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 285, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 277
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 286, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 278
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, -82, -82, -82, -82, -82, -82, -82, 0, 0, 0, -82, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, -82, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0, -82, -82, -82, -82, -82, -82, -82, -82, -82, 0,
-        // State 279
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, -62, -62, -62, -62, -62, -62, 14, -62, 0,
-        // State 280
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 218, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 281
-        0, -167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 282
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132, 0, 0, -132, -132, -132, -132, -132, -132, -132, 0, 0, 0, -132, -132, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, -132, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0, -132, 0, 0, 0, 0, -132, -132, -132, -132, -132, -132, -132, -132, -132, 0,
-        // State 283
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 284
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -183, 0, -183, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -183, 0, 0,
-        // State 285
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 286
-        0, -168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 287
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 256, 257, 0, 0, 0, 258, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
-        // State 288
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -174, -174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -174,
-        // State 289
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, 0, 0, -131, -131, -131, -131, -131, -131, -131, 0, 0, 0, -131, -131, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, -131, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, -131, 0, 0, 0, 0, -131, -131, -131, -131, -131, -131, -131, -131, -131, 0,
-    ];
-    const __EOF_ACTION: &'static [i32] = &[
-        0,
-        -63,
-        -117,
-        -116,
-        -113,
-        -115,
-        -114,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        -165,
-        -155,
-        -164,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        -129,
-        0,
-        0,
-        0,
-        0,
-        0,
-        -127,
-        0,
-        0,
-        0,
-        0,
-        -156,
-        0,
-        -163,
-        0,
-        -162,
-        0,
-        0,
-        0,
-        -166,
-        -153,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        -157,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        -128,
-        0,
-        0,
-        0,
-        0,
-        -126,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        -154,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        -179,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        -161,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        -125,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        -159,
-        0,
-        -83,
-        0,
-        0,
-        0,
-        0,
-        0,
-        -158,
-        0,
-        0,
-        -133,
-        0,
-        -160,
-        -178,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        -82,
-        0,
-        0,
-        0,
-        -132,
-        0,
-        0,
-        0,
-        0,
-        0,
-        0,
-        -131,
-    ];
-    const __GOTO: &'static [i32] = &[
-        // State 0
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 3, 4, 5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0,
-        // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 2
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 3
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 7
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 9
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 11
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 20, 0, 0,
-        // State 12
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 13
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 15
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 16
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 17
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 19
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 20
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 42, 0, 0,
-        // State 21
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0,
-        // State 22
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 29, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 23
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 24
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 29, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 25
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 26
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 27
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 28
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 29
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 30
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 31
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 65, 66, 67, 68, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 77,
-        // State 32
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 65, 66, 67, 91, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 77,
-        // State 33
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 93, 0, 0,
-        // State 35
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 65, 66, 67, 97, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 77,
-        // State 36
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 0,
-        // State 37
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0,
-        // State 38
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 39
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 41
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 42
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 44
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 45
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 46
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 47
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 109, 0, 0,
-        // State 48
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 111, 0, 0,
-        // State 49
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 113, 0, 0,
-        // State 50
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 115, 0, 0,
-        // State 51
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 52
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 118, 0, 0,
-        // State 53
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 62, 124, 63, 64, 0, 0, 0, 0, 125, 0, 126, 65, 66, 67, 127, 0, 0, 69, 0, 0, 0, 0, 128, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 77,
-        // State 55
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 56
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 57
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 65, 66, 67, 132, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 77,
-        // State 58
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 65, 66, 67, 133, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 77,
-        // State 59
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 60
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 61
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 62
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 63
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 64
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 65
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 66
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 67
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 68
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 69
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 70
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 71
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 72
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 73
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 74
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 75
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 76
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 77
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 65, 66, 67, 151, 0, 0, 69, 0, 0, 0, 0, 152, 0, 71, 72, 0, 73, 74, 0, 0, 0, 153, 75, 0, 0, 76, 0, 0, 0, 0, 77,
-        // State 78
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 154, 0, 0, 0, 0, 0,
-        // State 79
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 80
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 155, 0, 0, 0, 0, 0,
-        // State 81
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 82
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 83
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 84
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 85
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 86
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 87
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 88
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 89
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 90
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 91
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 92
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 93
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 94
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 158, 0, 0,
-        // State 95
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 160, 0, 0,
-        // State 96
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 97
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 98
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 165, 0, 0,
-        // State 99
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 100
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 101
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 102
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 103
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 29, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 104
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 105
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 106
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 107
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 108
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 109
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 110
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 111
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 112
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 113
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 114
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 115
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 116
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 117
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 118
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 119
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 179, 0, 0,
-        // State 120
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 181, 0, 0,
-        // State 121
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 122
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 65, 66, 67, 186, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 77,
-        // State 123
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 124
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 125
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 126
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 127
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 128
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 129
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 130
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 131
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 132
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 133
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 65, 66, 195, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 0,
-        // State 134
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 65, 66, 196, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 0,
-        // State 135
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 197, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 0,
-        // State 136
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 198, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 0,
-        // State 137
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 199, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200, 201, 0, 202, 0, 203, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 71, 204, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 138
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 65, 66, 67, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 205,
-        // State 139
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 65, 66, 67, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 206,
-        // State 140
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 207, 0, 0, 0, 0, 0,
-        // State 141
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 208, 0, 0, 0, 0, 0,
-        // State 142
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 209, 0, 0, 0, 0, 0,
-        // State 143
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 210, 0, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0,
-        // State 144
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 211, 0, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0,
-        // State 145
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 212, 0, 0, 76, 0, 0, 0, 0, 0,
-        // State 146
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 213, 0, 0, 76, 0, 0, 0, 0, 0,
-        // State 147
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 214, 0, 0, 76, 0, 0, 0, 0, 0,
-        // State 148
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 215, 0, 0, 76, 0, 0, 0, 0, 0,
-        // State 149
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 216, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 65, 66, 67, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 0,
-        // State 150
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 151
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 152
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 153
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 154
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 155
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 29, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 156
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 157
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 158
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 159
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 160
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 161
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 223, 0, 0,
-        // State 162
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 29, 225, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 163
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 164
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 165
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 62, 0, 63, 64, 0, 0, 0, 0, 0, 0, 227, 65, 66, 67, 127, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 228, 77,
-        // State 166
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 167
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 229, 0, 0, 0, 0, 0, 0, 0,
-        // State 168
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 169
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 231, 0, 0,
-        // State 170
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 233, 0, 0,
-        // State 171
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 172
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 173
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 174
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 175
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 176
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 177
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 178
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 179
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 180
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 181
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 182
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 239, 0, 0,
-        // State 183
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 184
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 185
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 186
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 187
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 188
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 189
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 190
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 65, 66, 67, 243, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 77,
-        // State 191
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 65, 66, 67, 244, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 77,
-        // State 192
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 193
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 194
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 195
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 196
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 197
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 198
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 245, 0, 0, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 71, 204, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 199
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 200
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 199, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 246, 0, 247, 202, 0, 203, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 71, 204, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 201
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 202
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 203
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 204
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 205
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 206
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 207
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 208
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 209
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 210
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 211
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 212
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 213
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 214
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 215
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 216
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 217
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 253, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 71, 254, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 218
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 219
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 255, 0, 0, 0, 28, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 220
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 221
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 222
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 223
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 224
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 225
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 226
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 227
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 228
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 229
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 230
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 231
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 232
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 233
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 234
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 267, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 235
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 236
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 237
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 238
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
-        // State 239
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 240
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 241
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 242
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 243
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 244
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 245
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 246
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 247
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 29, 270, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 248
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 249
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 250
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 29, 271, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 251
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 71, 272, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 252
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 71, 273, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 253
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 254
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 255
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 29, 275, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 256
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 65, 66, 67, 276, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 0, 77,
-        // State 257
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 258
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 259
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 260
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 261
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 262
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 62, 0, 63, 64, 0, 0, 0, 0, 0, 0, 227, 65, 66, 67, 127, 0, 0, 69, 0, 0, 0, 0, 70, 0, 71, 72, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 0, 0, 0, 277, 77,
-        // State 263
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 264
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 265
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 266
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 267
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 268
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 269
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 270
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 271
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 272
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 273
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 281, 0, 0, 0, 0, 0, 0, 0, 0, 0, 282, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 274
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 275
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 276
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 277
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 278
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 279
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 281, 0, 0, 0, 0, 0, 0, 0, 0, 0, 287, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 280
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 281
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 282
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 283
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 29, 288, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 284
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 285
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 289, 0, 0, 0, 0, 0, 0, 0,
-        // State 286
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 287
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 290, 0, 0, 0, 28, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 288
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 289
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
-        const __TERMINAL: &'static [&'static str] = &[
-            r###""(""###,
-            r###"")""###,
-            r###""*""###,
-            r###""+""###,
-            r###"",""###,
-            r###""-""###,
-            r###"".""###,
-            r###""..""###,
-            r###""/""###,
-            r###"":""###,
-            r###"":=""###,
-            r###"";""###,
-            r###""<""###,
-            r###""<=""###,
-            r###""<>""###,
-            r###""=""###,
-            r###""=>""###,
-            r###"">""###,
-            r###"">=""###,
-            r###""AND""###,
-            r###""AND_THEN""###,
-            r###""ARRAY""###,
-            r###""AT""###,
-            r###""CASE""###,
-            r###""CONSTANT""###,
-            r###""DO""###,
-            r###""ELSE""###,
-            r###""ELSIF""###,
-            r###""END_CASE""###,
-            r###""END_FUNCTION""###,
-            r###""END_FUNCTION_BLOCK""###,
-            r###""END_IF""###,
-            r###""END_PROGRAM""###,
-            r###""END_STRUCT""###,
-            r###""END_TYPE""###,
-            r###""END_VAR""###,
-            r###""END_WHILE""###,
-            r###""FALSE""###,
-            r###""FUNCTION""###,
-            r###""FUNCTION_BLOCK""###,
-            r###""IF""###,
-            r###""MOD""###,
-            r###""NOT""###,
-            r###""OF""###,
-            r###""OR""###,
-            r###""OR_ELSE""###,
-            r###""PERSISTENT""###,
-            r###""PROGRAM""###,
-            r###""RETAIN""###,
-            r###""RETURN""###,
-            r###""STRING""###,
-            r###""STRUCT""###,
-            r###""THEN""###,
-            r###""TRUE""###,
-            r###""TYPE""###,
-            r###""VAR""###,
-            r###""VAR_GLOBAL""###,
-            r###""VAR_INPUT""###,
-            r###""VAR_IN_OUT""###,
-            r###""VAR_OUTPUT""###,
-            r###""WHILE""###,
-            r###""XOR""###,
-            r###""[""###,
-            r###""]""###,
-            r###"r#"%[MIQ][BWX]?(\\*|[0-9]+)"#"###,
-            r###"r#"\'([^\']|\'\')*\'"#"###,
-            r###"r#"-?[0-9]+[eE][-+]?[0-9]+"#"###,
-            r###"r#"-?[0-9]+\\.[0-9]+([eE][-+]?[0-9]+)?"#"###,
-            r###"r#"16#-?[0-9a-fA-F]+"#"###,
-            r###"r#"2#-?[01]+"#"###,
-            r###"r#"8#-?[0-7]+"#"###,
-            r###"r#"[0-9]+"#"###,
-            r###"r#"[a-zA-Z_][a-zA-Z_0-9]*"#"###,
-            r###"r#"[tT]#[0-9]+(\\.[0-9]+)?(ms|MS|s|S|h|H|d|D)"#"###,
-        ];
-        __ACTION[(__state * 75)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
-            if state == 0 {
-                None
-            } else {
-                Some(terminal.to_string())
-            }
-        }).collect()
-    }
-    #[allow(dead_code)]
-    pub fn parse_file<
-        'input,
-    >(
-        input: &'input str,
-    ) -> Result<POU, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
-    {
-        let mut __tokens = super::__intern_token::__Matcher::new(input);
-        let mut __states = vec![0_i32];
-        let mut __symbols = vec![];
-        let mut __integer;
-        let mut __lookahead;
-        let mut __last_location = Default::default();
-        '__shift: loop {
-            __lookahead = match __tokens.next() {
-                Some(Ok(v)) => v,
-                None => break '__shift,
-                Some(Err(e)) => return Err(e),
-            };
-            __last_location = __lookahead.2.clone();
-            __integer = match __lookahead.1 {
-                Token(10, _) if true => 0,
-                Token(11, _) if true => 1,
-                Token(12, _) if true => 2,
-                Token(13, _) if true => 3,
-                Token(14, _) if true => 4,
-                Token(15, _) if true => 5,
-                Token(16, _) if true => 6,
-                Token(17, _) if true => 7,
-                Token(18, _) if true => 8,
-                Token(19, _) if true => 9,
-                Token(20, _) if true => 10,
-                Token(21, _) if true => 11,
-                Token(22, _) if true => 12,
-                Token(23, _) if true => 13,
-                Token(24, _) if true => 14,
-                Token(25, _) if true => 15,
-                Token(26, _) if true => 16,
-                Token(27, _) if true => 17,
-                Token(28, _) if true => 18,
-                Token(29, _) if true => 19,
-                Token(30, _) if true => 20,
-                Token(31, _) if true => 21,
-                Token(32, _) if true => 22,
-                Token(33, _) if true => 23,
-                Token(34, _) if true => 24,
-                Token(35, _) if true => 25,
-                Token(36, _) if true => 26,
-                Token(37, _) if true => 27,
-                Token(38, _) if true => 28,
-                Token(39, _) if true => 29,
-                Token(40, _) if true => 30,
-                Token(41, _) if true => 31,
-                Token(42, _) if true => 32,
-                Token(43, _) if true => 33,
-                Token(44, _) if true => 34,
-                Token(45, _) if true => 35,
-                Token(46, _) if true => 36,
-                Token(47, _) if true => 37,
-                Token(48, _) if true => 38,
-                Token(49, _) if true => 39,
-                Token(50, _) if true => 40,
-                Token(51, _) if true => 41,
-                Token(52, _) if true => 42,
-                Token(53, _) if true => 43,
-                Token(54, _) if true => 44,
-                Token(55, _) if true => 45,
-                Token(56, _) if true => 46,
-                Token(57, _) if true => 47,
-                Token(58, _) if true => 48,
-                Token(59, _) if true => 49,
-                Token(60, _) if true => 50,
-                Token(61, _) if true => 51,
-                Token(62, _) if true => 52,
-                Token(63, _) if true => 53,
-                Token(64, _) if true => 54,
-                Token(65, _) if true => 55,
-                Token(66, _) if true => 56,
-                Token(67, _) if true => 57,
-                Token(68, _) if true => 58,
-                Token(69, _) if true => 59,
-                Token(70, _) if true => 60,
-                Token(71, _) if true => 61,
-                Token(72, _) if true => 62,
-                Token(73, _) if true => 63,
-                Token(0, _) if true => 64,
-                Token(1, _) if true => 65,
-                Token(2, _) if true => 66,
-                Token(3, _) if true => 67,
-                Token(4, _) if true => 68,
-                Token(5, _) if true => 69,
-                Token(6, _) if true => 70,
-                Token(7, _) if true => 71,
-                Token(8, _) if true => 72,
-                Token(9, _) if true => 73,
-                _ => {
-                    let __state = *__states.last().unwrap() as usize;
-                    let __error = __lalrpop_util::ParseError::UnrecognizedToken {
-                        token: Some(__lookahead),
-                        expected: __expected_tokens(__state),
-                    };
-                    return Err(__error);
-                }
-            };
-            '__inner: loop {
-                let __state = *__states.last().unwrap() as usize;
-                let __action = __ACTION[__state * 75 + __integer];
-                if __action > 0 {
-                    let __symbol = match __integer {
-                        0 => match __lookahead.1 {
-                            Token(10, __tok0) => __Symbol::Term_22_28_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        1 => match __lookahead.1 {
-                            Token(11, __tok0) => __Symbol::Term_22_29_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        2 => match __lookahead.1 {
-                            Token(12, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        3 => match __lookahead.1 {
-                            Token(13, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        4 => match __lookahead.1 {
-                            Token(14, __tok0) => __Symbol::Term_22_2c_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        5 => match __lookahead.1 {
-                            Token(15, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        6 => match __lookahead.1 {
-                            Token(16, __tok0) => __Symbol::Term_22_2e_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        7 => match __lookahead.1 {
-                            Token(17, __tok0) => __Symbol::Term_22_2e_2e_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        8 => match __lookahead.1 {
-                            Token(18, __tok0) => __Symbol::Term_22_2f_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        9 => match __lookahead.1 {
-                            Token(19, __tok0) => __Symbol::Term_22_3a_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        10 => match __lookahead.1 {
-                            Token(20, __tok0) => __Symbol::Term_22_3a_3d_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        11 => match __lookahead.1 {
-                            Token(21, __tok0) => __Symbol::Term_22_3b_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        12 => match __lookahead.1 {
-                            Token(22, __tok0) => __Symbol::Term_22_3c_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        13 => match __lookahead.1 {
-                            Token(23, __tok0) => __Symbol::Term_22_3c_3d_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        14 => match __lookahead.1 {
-                            Token(24, __tok0) => __Symbol::Term_22_3c_3e_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        15 => match __lookahead.1 {
-                            Token(25, __tok0) => __Symbol::Term_22_3d_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        16 => match __lookahead.1 {
-                            Token(26, __tok0) => __Symbol::Term_22_3d_3e_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        17 => match __lookahead.1 {
-                            Token(27, __tok0) => __Symbol::Term_22_3e_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        18 => match __lookahead.1 {
-                            Token(28, __tok0) => __Symbol::Term_22_3e_3d_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        19 => match __lookahead.1 {
-                            Token(29, __tok0) => __Symbol::Term_22AND_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        20 => match __lookahead.1 {
-                            Token(30, __tok0) => __Symbol::Term_22AND__THEN_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        21 => match __lookahead.1 {
-                            Token(31, __tok0) => __Symbol::Term_22ARRAY_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        22 => match __lookahead.1 {
-                            Token(32, __tok0) => __Symbol::Term_22AT_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        23 => match __lookahead.1 {
-                            Token(33, __tok0) => __Symbol::Term_22CASE_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        24 => match __lookahead.1 {
-                            Token(34, __tok0) => __Symbol::Term_22CONSTANT_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        25 => match __lookahead.1 {
-                            Token(35, __tok0) => __Symbol::Term_22DO_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        26 => match __lookahead.1 {
-                            Token(36, __tok0) => __Symbol::Term_22ELSE_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        27 => match __lookahead.1 {
-                            Token(37, __tok0) => __Symbol::Term_22ELSIF_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        28 => match __lookahead.1 {
-                            Token(38, __tok0) => __Symbol::Term_22END__CASE_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        29 => match __lookahead.1 {
-                            Token(39, __tok0) => __Symbol::Term_22END__FUNCTION_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        30 => match __lookahead.1 {
-                            Token(40, __tok0) => __Symbol::Term_22END__FUNCTION__BLOCK_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        31 => match __lookahead.1 {
-                            Token(41, __tok0) => __Symbol::Term_22END__IF_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        32 => match __lookahead.1 {
-                            Token(42, __tok0) => __Symbol::Term_22END__PROGRAM_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        33 => match __lookahead.1 {
-                            Token(43, __tok0) => __Symbol::Term_22END__STRUCT_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        34 => match __lookahead.1 {
-                            Token(44, __tok0) => __Symbol::Term_22END__TYPE_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        35 => match __lookahead.1 {
-                            Token(45, __tok0) => __Symbol::Term_22END__VAR_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        36 => match __lookahead.1 {
-                            Token(46, __tok0) => __Symbol::Term_22END__WHILE_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        37 => match __lookahead.1 {
-                            Token(47, __tok0) => __Symbol::Term_22FALSE_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        38 => match __lookahead.1 {
-                            Token(48, __tok0) => __Symbol::Term_22FUNCTION_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        39 => match __lookahead.1 {
-                            Token(49, __tok0) => __Symbol::Term_22FUNCTION__BLOCK_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        40 => match __lookahead.1 {
-                            Token(50, __tok0) => __Symbol::Term_22IF_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        41 => match __lookahead.1 {
-                            Token(51, __tok0) => __Symbol::Term_22MOD_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        42 => match __lookahead.1 {
-                            Token(52, __tok0) => __Symbol::Term_22NOT_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        43 => match __lookahead.1 {
-                            Token(53, __tok0) => __Symbol::Term_22OF_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        44 => match __lookahead.1 {
-                            Token(54, __tok0) => __Symbol::Term_22OR_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        45 => match __lookahead.1 {
-                            Token(55, __tok0) => __Symbol::Term_22OR__ELSE_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        46 => match __lookahead.1 {
-                            Token(56, __tok0) => __Symbol::Term_22PERSISTENT_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        47 => match __lookahead.1 {
-                            Token(57, __tok0) => __Symbol::Term_22PROGRAM_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        48 => match __lookahead.1 {
-                            Token(58, __tok0) => __Symbol::Term_22RETAIN_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        49 => match __lookahead.1 {
-                            Token(59, __tok0) => __Symbol::Term_22RETURN_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        50 => match __lookahead.1 {
-                            Token(60, __tok0) => __Symbol::Term_22STRING_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        51 => match __lookahead.1 {
-                            Token(61, __tok0) => __Symbol::Term_22STRUCT_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        52 => match __lookahead.1 {
-                            Token(62, __tok0) => __Symbol::Term_22THEN_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        53 => match __lookahead.1 {
-                            Token(63, __tok0) => __Symbol::Term_22TRUE_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        54 => match __lookahead.1 {
-                            Token(64, __tok0) => __Symbol::Term_22TYPE_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        55 => match __lookahead.1 {
-                            Token(65, __tok0) => __Symbol::Term_22VAR_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        56 => match __lookahead.1 {
-                            Token(66, __tok0) => __Symbol::Term_22VAR__GLOBAL_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        57 => match __lookahead.1 {
-                            Token(67, __tok0) => __Symbol::Term_22VAR__INPUT_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        58 => match __lookahead.1 {
-                            Token(68, __tok0) => __Symbol::Term_22VAR__IN__OUT_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        59 => match __lookahead.1 {
-                            Token(69, __tok0) => __Symbol::Term_22VAR__OUTPUT_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        60 => match __lookahead.1 {
-                            Token(70, __tok0) => __Symbol::Term_22WHILE_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        61 => match __lookahead.1 {
-                            Token(71, __tok0) => __Symbol::Term_22XOR_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        62 => match __lookahead.1 {
-                            Token(72, __tok0) => __Symbol::Term_22_5b_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        63 => match __lookahead.1 {
-                            Token(73, __tok0) => __Symbol::Term_22_5d_22((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        64 => match __lookahead.1 {
-                            Token(0, __tok0) => __Symbol::Termr_23_22_25_5bMIQ_5d_5bBWX_5d_3f_28_5c_5c_2a_7c_5b0_2d9_5d_2b_29_22_23((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        65 => match __lookahead.1 {
-                            Token(1, __tok0) => __Symbol::Termr_23_22_5c_27_28_5b_5e_5c_27_5d_7c_5c_27_5c_27_29_2a_5c_27_22_23((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        66 => match __lookahead.1 {
-                            Token(2, __tok0) => __Symbol::Termr_23_22_2d_3f_5b0_2d9_5d_2b_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_22_23((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        67 => match __lookahead.1 {
-                            Token(3, __tok0) => __Symbol::Termr_23_22_2d_3f_5b0_2d9_5d_2b_5c_5c_2e_5b0_2d9_5d_2b_28_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_3f_22_23((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        68 => match __lookahead.1 {
-                            Token(4, __tok0) => __Symbol::Termr_23_2216_23_2d_3f_5b0_2d9a_2dfA_2dF_5d_2b_22_23((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        69 => match __lookahead.1 {
-                            Token(5, __tok0) => __Symbol::Termr_23_222_23_2d_3f_5b01_5d_2b_22_23((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        70 => match __lookahead.1 {
-                            Token(6, __tok0) => __Symbol::Termr_23_228_23_2d_3f_5b0_2d7_5d_2b_22_23((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        71 => match __lookahead.1 {
-                            Token(7, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        72 => match __lookahead.1 {
-                            Token(8, __tok0) => __Symbol::Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ__0_2d9_5d_2a_22_23((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        73 => match __lookahead.1 {
-                            Token(9, __tok0) => __Symbol::Termr_23_22_5btT_5d_23_5b0_2d9_5d_2b_28_5c_5c_2e_5b0_2d9_5d_2b_29_3f_28ms_7cMS_7cs_7cS_7ch_7cH_7cd_7cD_29_22_23((__tok0)),
-                            _ => unreachable!(),
-                        },
-                        _ => unreachable!(),
-                    };
-                    __states.push(__action - 1);
-                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
-                    continue '__shift;
-                } else if __action < 0 {
-                    if let Some(_) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
-                        return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead });
-                    }
-                } else {
-                    let __state = *__states.last().unwrap() as usize;
-                    let __error = __lalrpop_util::ParseError::UnrecognizedToken {
-                        token: Some(__lookahead.clone()),
-                        expected: __expected_tokens(__state),
-                    };
-                    let mut __dropped_tokens = Vec::new();
-                    loop {
-                        let __state = *__states.last().unwrap() as usize;
-                        let __action = __ACTION[(__state + 1) * 75 - 1];
-                        if __action >= 0 {
-                            break;
-                        }
-                        if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
-                            return r;
-                        }
-                    }
-                    let __original_state_len = __states.len();
-                    let mut __error_state;
-                    loop {
-                        match __states.last().cloned() {
-                            Some(__state) => {
-                                __error_state = __ACTION[(__state as usize + 1) * 75 - 1];
-                                if __error_state > 0  {
-                                    break;
-                                }
-                                __states.pop();
-                            }
-                            None => {
-                                return Err(__error);
-                            }
-                        }
-                    }
-                    let __start = __lookahead.0.clone();
-                    let __end = __lookahead.2.clone();
-                    loop {
-                        if __ACTION[(__error_state as usize - 1) * 75 + __integer] != 0 {
-                            let __new_len = __symbols.len() - (__original_state_len - __states.len());
-                            __symbols.truncate(__new_len);
-                            __states.push(__error_state - 1);
-                            let __recovery = __lalrpop_util::ErrorRecovery {
-                                error: __error,
-                                dropped_tokens: __dropped_tokens,
-                            };
-                            __symbols.push((__start, __Symbol::Termerror(__recovery), __end));
-                            continue '__inner;
-                        }
-                        __dropped_tokens.push(__lookahead);
-                        __lookahead = match __tokens.next() {
-                            Some(Ok(v)) => v,
-                            None => break '__shift,
-                            Some(Err(e)) => return Err(e),
-                        };
-                        __last_location = __lookahead.2.clone();
-                        __integer = match __lookahead.1 {
-                            Token(10, _) if true => 0,
-                            Token(11, _) if true => 1,
-                            Token(12, _) if true => 2,
-                            Token(13, _) if true => 3,
-                            Token(14, _) if true => 4,
-                            Token(15, _) if true => 5,
-                            Token(16, _) if true => 6,
-                            Token(17, _) if true => 7,
-                            Token(18, _) if true => 8,
-                            Token(19, _) if true => 9,
-                            Token(20, _) if true => 10,
-                            Token(21, _) if true => 11,
-                            Token(22, _) if true => 12,
-                            Token(23, _) if true => 13,
-                            Token(24, _) if true => 14,
-                            Token(25, _) if true => 15,
-                            Token(26, _) if true => 16,
-                            Token(27, _) if true => 17,
-                            Token(28, _) if true => 18,
-                            Token(29, _) if true => 19,
-                            Token(30, _) if true => 20,
-                            Token(31, _) if true => 21,
-                            Token(32, _) if true => 22,
-                            Token(33, _) if true => 23,
-                            Token(34, _) if true => 24,
-                            Token(35, _) if true => 25,
-                            Token(36, _) if true => 26,
-                            Token(37, _) if true => 27,
-                            Token(38, _) if true => 28,
-                            Token(39, _) if true => 29,
-                            Token(40, _) if true => 30,
-                            Token(41, _) if true => 31,
-                            Token(42, _) if true => 32,
-                            Token(43, _) if true => 33,
-                            Token(44, _) if true => 34,
-                            Token(45, _) if true => 35,
-                            Token(46, _) if true => 36,
-                            Token(47, _) if true => 37,
-                            Token(48, _) if true => 38,
-                            Token(49, _) if true => 39,
-                            Token(50, _) if true => 40,
-                            Token(51, _) if true => 41,
-                            Token(52, _) if true => 42,
-                            Token(53, _) if true => 43,
-                            Token(54, _) if true => 44,
-                            Token(55, _) if true => 45,
-                            Token(56, _) if true => 46,
-                            Token(57, _) if true => 47,
-                            Token(58, _) if true => 48,
-                            Token(59, _) if true => 49,
-                            Token(60, _) if true => 50,
-                            Token(61, _) if true => 51,
-                            Token(62, _) if true => 52,
-                            Token(63, _) if true => 53,
-                            Token(64, _) if true => 54,
-                            Token(65, _) if true => 55,
-                            Token(66, _) if true => 56,
-                            Token(67, _) if true => 57,
-                            Token(68, _) if true => 58,
-                            Token(69, _) if true => 59,
-                            Token(70, _) if true => 60,
-                            Token(71, _) if true => 61,
-                            Token(72, _) if true => 62,
-                            Token(73, _) if true => 63,
-                            Token(0, _) if true => 64,
-                            Token(1, _) if true => 65,
-                            Token(2, _) if true => 66,
-                            Token(3, _) if true => 67,
-                            Token(4, _) if true => 68,
-                            Token(5, _) if true => 69,
-                            Token(6, _) if true => 70,
-                            Token(7, _) if true => 71,
-                            Token(8, _) if true => 72,
-                            Token(9, _) if true => 73,
-                            _ => {
-                                let __state = *__states.last().unwrap() as usize;
-                                let __error = __lalrpop_util::ParseError::UnrecognizedToken {
-                                    token: Some(__lookahead),
-                                    expected: __expected_tokens(__state),
-                                };
-                                return Err(__error);
-                            }
-                        };
-                    }
-                }
-            }
-        }
-        loop {
-            let __state = *__states.last().unwrap() as usize;
-            let __action = __EOF_ACTION[__state];
-            if __action < 0 {
-                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
-                    return r;
-                }
-            } else {
-                let __state = *__states.last().unwrap() as usize;
-                let __error = __lalrpop_util::ParseError::UnrecognizedToken {
-                    token: None,
-                    expected: __expected_tokens(__state),
-                };
-                loop {
-                    let __state = *__states.last().unwrap() as usize;
-                    let __action = __ACTION[(__state + 1) * 75 - 1];
-                    if __action >= 0 {
-                        break;
-                    }
-                    if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
-                        return r;
-                    }
-                }
-                let __original_state_len = __states.len();
-                let mut __error_state;
-                loop {
-                    match __states.last().cloned() {
-                        Some(__state) => {
-                            __error_state = __ACTION[(__state as usize + 1) * 75 - 1];
-                            if __error_state > 0 && __EOF_ACTION[(__error_state as usize - 1)] != 0  {
-                                break;
-                            }
-                            __states.pop();
-                        }
-                        None => {
-                            return Err(__error);
-                        }
-                    }
-                }
-                let __new_len = __symbols.len() - (__original_state_len - __states.len());
-                __symbols.truncate(__new_len);
-                __states.push(__error_state - 1);
-                let __recovery = __lalrpop_util::ErrorRecovery {
-                    error: __error,
-                    dropped_tokens: Vec::new(),
-                };
-                __symbols.push((__last_location.clone(), __Symbol::Termerror(__recovery), __last_location.clone()));
-            }
-        }
-    }
-    pub fn __reduce<
-        'input,
-    >(
-        input: &'input str,
-        __action: i32,
-        __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i32>,
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: ::std::marker::PhantomData<()>,
-    ) -> Option<Result<POU,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
-    {
-        let __nonterminal = match -__action {
-            1 => {
-                // "CONSTANT"? = "CONSTANT" => ActionFn(132);
-                let __sym0 = __pop_Term_22CONSTANT_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action132::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Nt_22CONSTANT_22_3f(__nt), __end));
-                0
-            }
-            2 => {
-                // "CONSTANT"? =  => ActionFn(133);
-                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-                let __nt = super::__action133::<>(input, &__start, &__end);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 0);
-                __symbols.push((__start, __Symbol::Nt_22CONSTANT_22_3f(__nt), __end));
-                0
-            }
-            3 => {
-                // "END_FUNCTION_BLOCK"? = "END_FUNCTION_BLOCK" => ActionFn(115);
-                let __sym0 = __pop_Term_22END__FUNCTION__BLOCK_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action115::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Nt_22END__FUNCTION__BLOCK_22_3f(__nt), __end));
-                1
-            }
-            4 => {
-                // "END_FUNCTION_BLOCK"? =  => ActionFn(116);
-                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-                let __nt = super::__action116::<>(input, &__start, &__end);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 0);
-                __symbols.push((__start, __Symbol::Nt_22END__FUNCTION__BLOCK_22_3f(__nt), __end));
-                1
-            }
-            5 => {
-                // "END_PROGRAM"? = "END_PROGRAM" => ActionFn(117);
-                let __sym0 = __pop_Term_22END__PROGRAM_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action117::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Nt_22END__PROGRAM_22_3f(__nt), __end));
-                2
-            }
-            6 => {
-                // "END_PROGRAM"? =  => ActionFn(118);
-                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-                let __nt = super::__action118::<>(input, &__start, &__end);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 0);
-                __symbols.push((__start, __Symbol::Nt_22END__PROGRAM_22_3f(__nt), __end));
-                2
-            }
-            7 => {
-                // "PERSISTENT"? = "PERSISTENT" => ActionFn(126);
-                let __sym0 = __pop_Term_22PERSISTENT_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action126::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Nt_22PERSISTENT_22_3f(__nt), __end));
-                3
-            }
-            8 => {
-                // "PERSISTENT"? =  => ActionFn(127);
-                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-                let __nt = super::__action127::<>(input, &__start, &__end);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 0);
-                __symbols.push((__start, __Symbol::Nt_22PERSISTENT_22_3f(__nt), __end));
-                3
-            }
-            9 => {
-                // "RETAIN"? = "RETAIN" => ActionFn(128);
-                let __sym0 = __pop_Term_22RETAIN_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action128::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Nt_22RETAIN_22_3f(__nt), __end));
-                4
-            }
-            10 => {
-                // "RETAIN"? =  => ActionFn(129);
-                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-                let __nt = super::__action129::<>(input, &__start, &__end);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 0);
-                __symbols.push((__start, __Symbol::Nt_22RETAIN_22_3f(__nt), __end));
-                4
-            }
-            11 => {
-                // (":=" <var_default>) = ":=", var_default => ActionFn(111);
-                let __sym1 = __pop_Ntvar__default(__symbols);
-                let __sym0 = __pop_Term_22_3a_3d_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action111::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Nt_28_22_3a_3d_22_20_3cvar__default_3e_29(__nt), __end));
-                5
-            }
-            12 => {
-                // (":=" <var_default>)? = ":=", var_default => ActionFn(165);
-                let __sym1 = __pop_Ntvar__default(__symbols);
-                let __sym0 = __pop_Term_22_3a_3d_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action165::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Nt_28_22_3a_3d_22_20_3cvar__default_3e_29_3f(__nt), __end));
-                6
-            }
-            13 => {
-                // (":=" <var_default>)? =  => ActionFn(110);
-                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-                let __nt = super::__action110::<>(input, &__start, &__end);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 0);
-                __symbols.push((__start, __Symbol::Nt_28_22_3a_3d_22_20_3cvar__default_3e_29_3f(__nt), __end));
-                6
-            }
-            14 => {
-                // ("AT" <loc>) = "AT", loc => ActionFn(114);
-                let __sym1 = __pop_Ntloc(__symbols);
-                let __sym0 = __pop_Term_22AT_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action114::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Nt_28_22AT_22_20_3cloc_3e_29(__nt), __end));
-                7
-            }
-            15 => {
-                // ("AT" <loc>)? = "AT", loc => ActionFn(168);
-                let __sym1 = __pop_Ntloc(__symbols);
-                let __sym0 = __pop_Term_22AT_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action168::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Nt_28_22AT_22_20_3cloc_3e_29_3f(__nt), __end));
-                8
-            }
-            16 => {
-                // ("AT" <loc>)? =  => ActionFn(113);
-                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-                let __nt = super::__action113::<>(input, &__start, &__end);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 0);
-                __symbols.push((__start, __Symbol::Nt_28_22AT_22_20_3cloc_3e_29_3f(__nt), __end));
-                8
-            }
-            17 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR") = "VAR", "RETAIN", "PERSISTENT", "END_VAR" => ActionFn(173);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Term_22PERSISTENT_22(__symbols);
-                let __sym1 = __pop_Term_22RETAIN_22(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action173::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29(__nt), __end));
-                9
-            }
-            18 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR") = "VAR", "RETAIN", "PERSISTENT", var_def+, "END_VAR" => ActionFn(174);
-                let __sym4 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym3 = __pop_Ntvar__def_2b(__symbols);
-                let __sym2 = __pop_Term_22PERSISTENT_22(__symbols);
-                let __sym1 = __pop_Term_22RETAIN_22(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym4.2.clone();
-                let __nt = super::__action174::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 5);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29(__nt), __end));
-                9
-            }
-            19 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR") = "VAR", "PERSISTENT", "END_VAR" => ActionFn(175);
-                let __sym2 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym1 = __pop_Term_22PERSISTENT_22(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action175::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29(__nt), __end));
-                9
-            }
-            20 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR") = "VAR", "PERSISTENT", var_def+, "END_VAR" => ActionFn(176);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Ntvar__def_2b(__symbols);
-                let __sym1 = __pop_Term_22PERSISTENT_22(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action176::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29(__nt), __end));
-                9
-            }
-            21 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR") = "VAR", "RETAIN", "END_VAR" => ActionFn(177);
-                let __sym2 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym1 = __pop_Term_22RETAIN_22(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action177::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29(__nt), __end));
-                9
-            }
-            22 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR") = "VAR", "RETAIN", var_def+, "END_VAR" => ActionFn(178);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Ntvar__def_2b(__symbols);
-                let __sym1 = __pop_Term_22RETAIN_22(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action178::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29(__nt), __end));
-                9
-            }
-            23 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR") = "VAR", "END_VAR" => ActionFn(179);
-                let __sym1 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action179::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29(__nt), __end));
-                9
-            }
-            24 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR") = "VAR", var_def+, "END_VAR" => ActionFn(180);
-                let __sym2 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym1 = __pop_Ntvar__def_2b(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action180::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29(__nt), __end));
-                9
-            }
-            25 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")* =  => ActionFn(121);
-                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-                let __nt = super::__action121::<>(input, &__start, &__end);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 0);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2a(__nt), __end));
-                10
-            }
-            26 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")* = ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ => ActionFn(122);
-                let __sym0 = __pop_Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action122::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2a(__nt), __end));
-                10
-            }
-            27 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = "VAR", "RETAIN", "PERSISTENT", "END_VAR" => ActionFn(193);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Term_22PERSISTENT_22(__symbols);
-                let __sym1 = __pop_Term_22RETAIN_22(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action193::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            28 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = "VAR", "RETAIN", "PERSISTENT", var_def+, "END_VAR" => ActionFn(194);
-                let __sym4 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym3 = __pop_Ntvar__def_2b(__symbols);
-                let __sym2 = __pop_Term_22PERSISTENT_22(__symbols);
-                let __sym1 = __pop_Term_22RETAIN_22(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym4.2.clone();
-                let __nt = super::__action194::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 5);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            29 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = "VAR", "PERSISTENT", "END_VAR" => ActionFn(195);
-                let __sym2 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym1 = __pop_Term_22PERSISTENT_22(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action195::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            30 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = "VAR", "PERSISTENT", var_def+, "END_VAR" => ActionFn(196);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Ntvar__def_2b(__symbols);
-                let __sym1 = __pop_Term_22PERSISTENT_22(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action196::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            31 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = "VAR", "RETAIN", "END_VAR" => ActionFn(197);
-                let __sym2 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym1 = __pop_Term_22RETAIN_22(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action197::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            32 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = "VAR", "RETAIN", var_def+, "END_VAR" => ActionFn(198);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Ntvar__def_2b(__symbols);
-                let __sym1 = __pop_Term_22RETAIN_22(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action198::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            33 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = "VAR", "END_VAR" => ActionFn(199);
-                let __sym1 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action199::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            34 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = "VAR", var_def+, "END_VAR" => ActionFn(200);
-                let __sym2 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym1 = __pop_Ntvar__def_2b(__symbols);
-                let __sym0 = __pop_Term_22VAR_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action200::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            35 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+, "VAR", "RETAIN", "PERSISTENT", "END_VAR" => ActionFn(201);
-                let __sym4 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym3 = __pop_Term_22PERSISTENT_22(__symbols);
-                let __sym2 = __pop_Term_22RETAIN_22(__symbols);
-                let __sym1 = __pop_Term_22VAR_22(__symbols);
-                let __sym0 = __pop_Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym4.2.clone();
-                let __nt = super::__action201::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 5);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            36 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+, "VAR", "RETAIN", "PERSISTENT", var_def+, "END_VAR" => ActionFn(202);
-                let __sym5 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym4 = __pop_Ntvar__def_2b(__symbols);
-                let __sym3 = __pop_Term_22PERSISTENT_22(__symbols);
-                let __sym2 = __pop_Term_22RETAIN_22(__symbols);
-                let __sym1 = __pop_Term_22VAR_22(__symbols);
-                let __sym0 = __pop_Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym5.2.clone();
-                let __nt = super::__action202::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 6);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            37 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+, "VAR", "PERSISTENT", "END_VAR" => ActionFn(203);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Term_22PERSISTENT_22(__symbols);
-                let __sym1 = __pop_Term_22VAR_22(__symbols);
-                let __sym0 = __pop_Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action203::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            38 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+, "VAR", "PERSISTENT", var_def+, "END_VAR" => ActionFn(204);
-                let __sym4 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym3 = __pop_Ntvar__def_2b(__symbols);
-                let __sym2 = __pop_Term_22PERSISTENT_22(__symbols);
-                let __sym1 = __pop_Term_22VAR_22(__symbols);
-                let __sym0 = __pop_Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym4.2.clone();
-                let __nt = super::__action204::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 5);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            39 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+, "VAR", "RETAIN", "END_VAR" => ActionFn(205);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Term_22RETAIN_22(__symbols);
-                let __sym1 = __pop_Term_22VAR_22(__symbols);
-                let __sym0 = __pop_Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action205::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            40 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+, "VAR", "RETAIN", var_def+, "END_VAR" => ActionFn(206);
-                let __sym4 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym3 = __pop_Ntvar__def_2b(__symbols);
-                let __sym2 = __pop_Term_22RETAIN_22(__symbols);
-                let __sym1 = __pop_Term_22VAR_22(__symbols);
-                let __sym0 = __pop_Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym4.2.clone();
-                let __nt = super::__action206::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 5);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            41 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+, "VAR", "END_VAR" => ActionFn(207);
-                let __sym2 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym1 = __pop_Term_22VAR_22(__symbols);
-                let __sym0 = __pop_Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action207::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            42 => {
-                // ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+ = ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+, "VAR", var_def+, "END_VAR" => ActionFn(208);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Ntvar__def_2b(__symbols);
-                let __sym1 = __pop_Term_22VAR_22(__symbols);
-                let __sym0 = __pop_Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action208::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__nt), __end));
-                11
-            }
-            43 => {
-                // (<argassign> ",") = argassign, "," => ActionFn(144);
-                let __sym1 = __pop_Term_22_2c_22(__symbols);
-                let __sym0 = __pop_Ntargassign(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action144::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Nt_28_3cargassign_3e_20_22_2c_22_29(__nt), __end));
-                12
-            }
-            44 => {
-                // (<argassign> ",")* =  => ActionFn(142);
-                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-                let __nt = super::__action142::<>(input, &__start, &__end);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 0);
-                __symbols.push((__start, __Symbol::Nt_28_3cargassign_3e_20_22_2c_22_29_2a(__nt), __end));
-                13
-            }
-            45 => {
-                // (<argassign> ",")* = (<argassign> ",")+ => ActionFn(143);
-                let __sym0 = __pop_Nt_28_3cargassign_3e_20_22_2c_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action143::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Nt_28_3cargassign_3e_20_22_2c_22_29_2a(__nt), __end));
-                13
-            }
-            46 => {
-                // (<argassign> ",")+ = argassign, "," => ActionFn(213);
-                let __sym1 = __pop_Term_22_2c_22(__symbols);
-                let __sym0 = __pop_Ntargassign(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action213::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Nt_28_3cargassign_3e_20_22_2c_22_29_2b(__nt), __end));
-                14
-            }
-            47 => {
-                // (<argassign> ",")+ = (<argassign> ",")+, argassign, "," => ActionFn(214);
-                let __sym2 = __pop_Term_22_2c_22(__symbols);
-                let __sym1 = __pop_Ntargassign(__symbols);
-                let __sym0 = __pop_Nt_28_3cargassign_3e_20_22_2c_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action214::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Nt_28_3cargassign_3e_20_22_2c_22_29_2b(__nt), __end));
-                14
-            }
-            48 => {
-                // (<case_match> ",") = case_match, "," => ActionFn(141);
-                let __sym1 = __pop_Term_22_2c_22(__symbols);
-                let __sym0 = __pop_Ntcase__match(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action141::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Nt_28_3ccase__match_3e_20_22_2c_22_29(__nt), __end));
-                15
-            }
-            49 => {
-                // (<case_match> ",")* =  => ActionFn(139);
-                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-                let __nt = super::__action139::<>(input, &__start, &__end);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 0);
-                __symbols.push((__start, __Symbol::Nt_28_3ccase__match_3e_20_22_2c_22_29_2a(__nt), __end));
-                16
-            }
-            50 => {
-                // (<case_match> ",")* = (<case_match> ",")+ => ActionFn(140);
-                let __sym0 = __pop_Nt_28_3ccase__match_3e_20_22_2c_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action140::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Nt_28_3ccase__match_3e_20_22_2c_22_29_2a(__nt), __end));
-                16
-            }
-            51 => {
-                // (<case_match> ",")+ = case_match, "," => ActionFn(217);
-                let __sym1 = __pop_Term_22_2c_22(__symbols);
-                let __sym0 = __pop_Ntcase__match(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action217::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Nt_28_3ccase__match_3e_20_22_2c_22_29_2b(__nt), __end));
-                17
-            }
-            52 => {
-                // (<case_match> ",")+ = (<case_match> ",")+, case_match, "," => ActionFn(218);
-                let __sym2 = __pop_Term_22_2c_22(__symbols);
-                let __sym1 = __pop_Ntcase__match(__symbols);
-                let __sym0 = __pop_Nt_28_3ccase__match_3e_20_22_2c_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action218::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Nt_28_3ccase__match_3e_20_22_2c_22_29_2b(__nt), __end));
-                17
-            }
-            53 => {
-                // (<expr> ",") = expr, "," => ActionFn(138);
-                let __sym1 = __pop_Term_22_2c_22(__symbols);
-                let __sym0 = __pop_Ntexpr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action138::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Nt_28_3cexpr_3e_20_22_2c_22_29(__nt), __end));
-                18
-            }
-            54 => {
-                // (<expr> ",")* =  => ActionFn(136);
-                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-                let __nt = super::__action136::<>(input, &__start, &__end);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 0);
-                __symbols.push((__start, __Symbol::Nt_28_3cexpr_3e_20_22_2c_22_29_2a(__nt), __end));
-                19
-            }
-            55 => {
-                // (<expr> ",")* = (<expr> ",")+ => ActionFn(137);
-                let __sym0 = __pop_Nt_28_3cexpr_3e_20_22_2c_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action137::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Nt_28_3cexpr_3e_20_22_2c_22_29_2a(__nt), __end));
-                19
-            }
-            56 => {
-                // (<expr> ",")+ = expr, "," => ActionFn(221);
-                let __sym1 = __pop_Term_22_2c_22(__symbols);
-                let __sym0 = __pop_Ntexpr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action221::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Nt_28_3cexpr_3e_20_22_2c_22_29_2b(__nt), __end));
-                20
-            }
-            57 => {
-                // (<expr> ",")+ = (<expr> ",")+, expr, "," => ActionFn(222);
-                let __sym2 = __pop_Term_22_2c_22(__symbols);
-                let __sym1 = __pop_Ntexpr(__symbols);
-                let __sym0 = __pop_Nt_28_3cexpr_3e_20_22_2c_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action222::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Nt_28_3cexpr_3e_20_22_2c_22_29_2b(__nt), __end));
-                20
-            }
-            58 => {
-                // (<literal_expr> ",") = literal_expr, "," => ActionFn(103);
-                let __sym1 = __pop_Term_22_2c_22(__symbols);
-                let __sym0 = __pop_Ntliteral__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action103::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Nt_28_3cliteral__expr_3e_20_22_2c_22_29(__nt), __end));
-                21
-            }
-            59 => {
-                // (<literal_expr> ",")* =  => ActionFn(101);
-                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-                let __nt = super::__action101::<>(input, &__start, &__end);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 0);
-                __symbols.push((__start, __Symbol::Nt_28_3cliteral__expr_3e_20_22_2c_22_29_2a(__nt), __end));
-                22
-            }
-            60 => {
-                // (<literal_expr> ",")* = (<literal_expr> ",")+ => ActionFn(102);
-                let __sym0 = __pop_Nt_28_3cliteral__expr_3e_20_22_2c_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action102::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Nt_28_3cliteral__expr_3e_20_22_2c_22_29_2a(__nt), __end));
-                22
-            }
-            61 => {
-                // (<literal_expr> ",")+ = literal_expr, "," => ActionFn(225);
-                let __sym1 = __pop_Term_22_2c_22(__symbols);
-                let __sym0 = __pop_Ntliteral__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action225::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Nt_28_3cliteral__expr_3e_20_22_2c_22_29_2b(__nt), __end));
-                23
-            }
-            62 => {
-                // (<literal_expr> ",")+ = (<literal_expr> ",")+, literal_expr, "," => ActionFn(226);
-                let __sym2 = __pop_Term_22_2c_22(__symbols);
-                let __sym1 = __pop_Ntliteral__expr(__symbols);
-                let __sym0 = __pop_Nt_28_3cliteral__expr_3e_20_22_2c_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action226::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Nt_28_3cliteral__expr_3e_20_22_2c_22_29_2b(__nt), __end));
-                23
-            }
-            63 => {
-                // __file = file => ActionFn(0);
-                let __sym0 = __pop_Ntfile(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(input, __sym0);
-                return Some(Ok(__nt));
-            }
-            64 => {
-                // and_expr = and_expr, "AND", eq_expr => ActionFn(54);
-                let __sym2 = __pop_Nteq__expr(__symbols);
-                let __sym1 = __pop_Term_22AND_22(__symbols);
-                let __sym0 = __pop_Ntand__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action54::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntand__expr(__nt), __end));
-                25
-            }
-            65 => {
-                // and_expr = and_expr, "AND_THEN", eq_expr => ActionFn(55);
-                let __sym2 = __pop_Nteq__expr(__symbols);
-                let __sym1 = __pop_Term_22AND__THEN_22(__symbols);
-                let __sym0 = __pop_Ntand__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action55::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntand__expr(__nt), __end));
-                25
-            }
-            66 => {
-                // and_expr = eq_expr => ActionFn(56);
-                let __sym0 = __pop_Nteq__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action56::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntand__expr(__nt), __end));
-                25
-            }
-            67 => {
-                // argassign = ident, ":=", expr => ActionFn(82);
-                let __sym2 = __pop_Ntexpr(__symbols);
-                let __sym1 = __pop_Term_22_3a_3d_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action82::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntargassign(__nt), __end));
-                26
-            }
-            68 => {
-                // argassign = ident, ":=" => ActionFn(83);
-                let __sym1 = __pop_Term_22_3a_3d_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action83::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Ntargassign(__nt), __end));
-                26
-            }
-            69 => {
-                // argassign = ident, "=>", expr => ActionFn(84);
-                let __sym2 = __pop_Ntexpr(__symbols);
-                let __sym1 = __pop_Term_22_3d_3e_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action84::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntargassign(__nt), __end));
-                26
-            }
-            70 => {
-                // argassign = ident, "=>" => ActionFn(85);
-                let __sym1 = __pop_Term_22_3d_3e_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action85::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Ntargassign(__nt), __end));
-                26
-            }
-            71 => {
-                // atom_expr = call_expr => ActionFn(94);
-                let __sym0 = __pop_Ntcall__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action94::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntatom__expr(__nt), __end));
-                27
-            }
-            72 => {
-                // atom_expr = lval_expr => ActionFn(95);
-                let __sym0 = __pop_Ntlval__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action95::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntatom__expr(__nt), __end));
-                27
-            }
-            73 => {
-                // atom_expr = literal_expr => ActionFn(96);
-                let __sym0 = __pop_Ntliteral__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action96::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntatom__expr(__nt), __end));
-                27
-            }
-            74 => {
-                // atom_expr = "(", expr, ")" => ActionFn(97);
-                let __sym2 = __pop_Term_22_29_22(__symbols);
-                let __sym1 = __pop_Ntexpr(__symbols);
-                let __sym0 = __pop_Term_22_28_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action97::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntatom__expr(__nt), __end));
-                27
-            }
-            75 => {
-                // atom_expr = "(", structinit, ")" => ActionFn(98);
-                let __sym2 = __pop_Term_22_29_22(__symbols);
-                let __sym1 = __pop_Ntstructinit(__symbols);
-                let __sym0 = __pop_Term_22_28_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action98::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntatom__expr(__nt), __end));
-                27
-            }
-            76 => {
-                // call_expr = ident, "(", comma<expr>, ")" => ActionFn(79);
-                let __sym3 = __pop_Term_22_29_22(__symbols);
-                let __sym2 = __pop_Ntcomma_3cexpr_3e(__symbols);
-                let __sym1 = __pop_Term_22_28_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action79::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntcall__expr(__nt), __end));
-                28
-            }
-            77 => {
-                // call_expr = ident, "(", comma<argassign>, ")" => ActionFn(80);
-                let __sym3 = __pop_Term_22_29_22(__symbols);
-                let __sym2 = __pop_Ntcomma_3cargassign_3e(__symbols);
-                let __sym1 = __pop_Term_22_28_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action80::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntcall__expr(__nt), __end));
-                28
-            }
-            78 => {
-                // call_expr = ident, "(", ")" => ActionFn(81);
-                let __sym2 = __pop_Term_22_29_22(__symbols);
-                let __sym1 = __pop_Term_22_28_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action81::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntcall__expr(__nt), __end));
-                28
-            }
-            79 => {
-                // case = comma<case_match>, ":", stmt+ => ActionFn(38);
-                let __sym2 = __pop_Ntstmt_2b(__symbols);
-                let __sym1 = __pop_Term_22_3a_22(__symbols);
-                let __sym0 = __pop_Ntcomma_3ccase__match_3e(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action38::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntcase(__nt), __end));
-                29
-            }
-            80 => {
-                // case+ = case => ActionFn(106);
-                let __sym0 = __pop_Ntcase(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action106::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntcase_2b(__nt), __end));
-                30
-            }
-            81 => {
-                // case+ = case+, case => ActionFn(107);
-                let __sym1 = __pop_Ntcase(__symbols);
-                let __sym0 = __pop_Ntcase_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action107::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Ntcase_2b(__nt), __end));
-                30
-            }
-            82 => {
-                // case_else = "ELSE", stmt+, "END_CASE" => ActionFn(41);
-                let __sym2 = __pop_Term_22END__CASE_22(__symbols);
-                let __sym1 = __pop_Ntstmt_2b(__symbols);
-                let __sym0 = __pop_Term_22ELSE_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action41::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntcase__else(__nt), __end));
-                31
-            }
-            83 => {
-                // case_else = "END_CASE" => ActionFn(42);
-                let __sym0 = __pop_Term_22END__CASE_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action42::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntcase__else(__nt), __end));
-                31
-            }
-            84 => {
-                // case_match = literal_expr, "..", literal_expr => ActionFn(39);
-                let __sym2 = __pop_Ntliteral__expr(__symbols);
-                let __sym1 = __pop_Term_22_2e_2e_22(__symbols);
-                let __sym0 = __pop_Ntliteral__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action39::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntcase__match(__nt), __end));
-                32
-            }
-            85 => {
-                // case_match = literal_expr => ActionFn(40);
-                let __sym0 = __pop_Ntliteral__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action40::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntcase__match(__nt), __end));
-                32
-            }
-            86 => {
-                // comma<argassign> = argassign => ActionFn(215);
-                let __sym0 = __pop_Ntargassign(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action215::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntcomma_3cargassign_3e(__nt), __end));
-                33
-            }
-            87 => {
-                // comma<argassign> = (<argassign> ",")+, argassign => ActionFn(216);
-                let __sym1 = __pop_Ntargassign(__symbols);
-                let __sym0 = __pop_Nt_28_3cargassign_3e_20_22_2c_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action216::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Ntcomma_3cargassign_3e(__nt), __end));
-                33
-            }
-            88 => {
-                // comma<case_match> = case_match => ActionFn(219);
-                let __sym0 = __pop_Ntcase__match(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action219::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntcomma_3ccase__match_3e(__nt), __end));
-                34
-            }
-            89 => {
-                // comma<case_match> = (<case_match> ",")+, case_match => ActionFn(220);
-                let __sym1 = __pop_Ntcase__match(__symbols);
-                let __sym0 = __pop_Nt_28_3ccase__match_3e_20_22_2c_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action220::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Ntcomma_3ccase__match_3e(__nt), __end));
-                34
-            }
-            90 => {
-                // comma<expr> = expr => ActionFn(223);
-                let __sym0 = __pop_Ntexpr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action223::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntcomma_3cexpr_3e(__nt), __end));
-                35
-            }
-            91 => {
-                // comma<expr> = (<expr> ",")+, expr => ActionFn(224);
-                let __sym1 = __pop_Ntexpr(__symbols);
-                let __sym0 = __pop_Nt_28_3cexpr_3e_20_22_2c_22_29_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action224::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Ntcomma_3cexpr_3e(__nt), __end));
-                35
-            }
-            92 => {
-                // comp_expr = sum_expr, ">", sum_expr => ActionFn(60);
-                let __sym2 = __pop_Ntsum__expr(__symbols);
-                let __sym1 = __pop_Term_22_3e_22(__symbols);
-                let __sym0 = __pop_Ntsum__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action60::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntcomp__expr(__nt), __end));
-                36
-            }
-            93 => {
-                // comp_expr = sum_expr, "<", sum_expr => ActionFn(61);
-                let __sym2 = __pop_Ntsum__expr(__symbols);
-                let __sym1 = __pop_Term_22_3c_22(__symbols);
-                let __sym0 = __pop_Ntsum__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action61::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntcomp__expr(__nt), __end));
-                36
-            }
-            94 => {
-                // comp_expr = sum_expr, ">=", sum_expr => ActionFn(62);
-                let __sym2 = __pop_Ntsum__expr(__symbols);
-                let __sym1 = __pop_Term_22_3e_3d_22(__symbols);
-                let __sym0 = __pop_Ntsum__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action62::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntcomp__expr(__nt), __end));
-                36
-            }
-            95 => {
-                // comp_expr = sum_expr, "<=", sum_expr => ActionFn(63);
-                let __sym2 = __pop_Ntsum__expr(__symbols);
-                let __sym1 = __pop_Term_22_3c_3d_22(__symbols);
-                let __sym0 = __pop_Ntsum__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action63::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntcomp__expr(__nt), __end));
-                36
-            }
-            96 => {
-                // comp_expr = sum_expr => ActionFn(64);
-                let __sym0 = __pop_Ntsum__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action64::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntcomp__expr(__nt), __end));
-                36
-            }
-            97 => {
-                // decint = r#"[0-9]+"# => ActionFn(91);
-                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action91::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntdecint(__nt), __end));
-                37
-            }
-            98 => {
-                // eq_expr = comp_expr, "=", comp_expr => ActionFn(57);
-                let __sym2 = __pop_Ntcomp__expr(__symbols);
-                let __sym1 = __pop_Term_22_3d_22(__symbols);
-                let __sym0 = __pop_Ntcomp__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action57::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Nteq__expr(__nt), __end));
-                38
-            }
-            99 => {
-                // eq_expr = comp_expr, "<>", comp_expr => ActionFn(58);
-                let __sym2 = __pop_Ntcomp__expr(__symbols);
-                let __sym1 = __pop_Term_22_3c_3e_22(__symbols);
-                let __sym0 = __pop_Ntcomp__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action58::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Nteq__expr(__nt), __end));
-                38
-            }
-            100 => {
-                // eq_expr = comp_expr => ActionFn(59);
-                let __sym0 = __pop_Ntcomp__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action59::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Nteq__expr(__nt), __end));
-                38
-            }
-            101 => {
-                // expr = expr, "OR", xor_expr => ActionFn(49);
-                let __sym2 = __pop_Ntxor__expr(__symbols);
-                let __sym1 = __pop_Term_22OR_22(__symbols);
-                let __sym0 = __pop_Ntexpr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action49::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntexpr(__nt), __end));
-                39
-            }
-            102 => {
-                // expr = expr, "OR_ELSE", xor_expr => ActionFn(50);
-                let __sym2 = __pop_Ntxor__expr(__symbols);
-                let __sym1 = __pop_Term_22OR__ELSE_22(__symbols);
-                let __sym0 = __pop_Ntexpr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action50::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntexpr(__nt), __end));
-                39
-            }
-            103 => {
-                // expr = xor_expr => ActionFn(51);
-                let __sym0 = __pop_Ntxor__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action51::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntexpr(__nt), __end));
-                39
-            }
-            104 => {
-                // fb_vars = fb_vars, "VAR_IN_OUT", "END_VAR" => ActionFn(181);
-                let __sym2 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym1 = __pop_Term_22VAR__IN__OUT_22(__symbols);
-                let __sym0 = __pop_Ntfb__vars(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action181::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntfb__vars(__nt), __end));
-                40
-            }
-            105 => {
-                // fb_vars = fb_vars, "VAR_IN_OUT", var_def+, "END_VAR" => ActionFn(182);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Ntvar__def_2b(__symbols);
-                let __sym1 = __pop_Term_22VAR__IN__OUT_22(__symbols);
-                let __sym0 = __pop_Ntfb__vars(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action182::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntfb__vars(__nt), __end));
-                40
-            }
-            106 => {
-                // fb_vars = fb_vars, "VAR_INPUT", "END_VAR" => ActionFn(183);
-                let __sym2 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym1 = __pop_Term_22VAR__INPUT_22(__symbols);
-                let __sym0 = __pop_Ntfb__vars(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action183::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntfb__vars(__nt), __end));
-                40
-            }
-            107 => {
-                // fb_vars = fb_vars, "VAR_INPUT", var_def+, "END_VAR" => ActionFn(184);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Ntvar__def_2b(__symbols);
-                let __sym1 = __pop_Term_22VAR__INPUT_22(__symbols);
-                let __sym0 = __pop_Ntfb__vars(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action184::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntfb__vars(__nt), __end));
-                40
-            }
-            108 => {
-                // fb_vars = fb_vars, "VAR_OUTPUT", "END_VAR" => ActionFn(185);
-                let __sym2 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym1 = __pop_Term_22VAR__OUTPUT_22(__symbols);
-                let __sym0 = __pop_Ntfb__vars(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action185::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntfb__vars(__nt), __end));
-                40
-            }
-            109 => {
-                // fb_vars = fb_vars, "VAR_OUTPUT", var_def+, "END_VAR" => ActionFn(186);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Ntvar__def_2b(__symbols);
-                let __sym1 = __pop_Term_22VAR__OUTPUT_22(__symbols);
-                let __sym0 = __pop_Ntfb__vars(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action186::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntfb__vars(__nt), __end));
-                40
-            }
-            110 => {
-                // fb_vars = fb_vars, "VAR", "END_VAR" => ActionFn(187);
-                let __sym2 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym1 = __pop_Term_22VAR_22(__symbols);
-                let __sym0 = __pop_Ntfb__vars(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action187::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntfb__vars(__nt), __end));
-                40
-            }
-            111 => {
-                // fb_vars = fb_vars, "VAR", var_def+, "END_VAR" => ActionFn(188);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Ntvar__def_2b(__symbols);
-                let __sym1 = __pop_Term_22VAR_22(__symbols);
-                let __sym0 = __pop_Ntfb__vars(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action188::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntfb__vars(__nt), __end));
-                40
-            }
-            112 => {
-                // fb_vars = error => ActionFn(15);
-                let __sym0 = __pop_Termerror(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action15::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntfb__vars(__nt), __end));
-                40
-            }
-            113 => {
-                // file = globals => ActionFn(1);
-                let __sym0 = __pop_Ntglobals(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action1::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntfile(__nt), __end));
-                41
-            }
-            114 => {
-                // file = typedef => ActionFn(2);
-                let __sym0 = __pop_Nttypedef(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action2::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntfile(__nt), __end));
-                41
-            }
-            115 => {
-                // file = program => ActionFn(3);
-                let __sym0 = __pop_Ntprogram(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action3::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntfile(__nt), __end));
-                41
-            }
-            116 => {
-                // file = function_block => ActionFn(4);
-                let __sym0 = __pop_Ntfunction__block(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action4::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntfile(__nt), __end));
-                41
-            }
-            117 => {
-                // file = function => ActionFn(5);
-                let __sym0 = __pop_Ntfunction(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action5::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntfile(__nt), __end));
-                41
-            }
-            118 => {
-                // float = r#"-?[0-9]+\\.[0-9]+([eE][-+]?[0-9]+)?"# => ActionFn(92);
-                let __sym0 = __pop_Termr_23_22_2d_3f_5b0_2d9_5d_2b_5c_5c_2e_5b0_2d9_5d_2b_28_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_3f_22_23(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action92::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntfloat(__nt), __end));
-                42
-            }
-            119 => {
-                // float = r#"-?[0-9]+[eE][-+]?[0-9]+"# => ActionFn(93);
-                let __sym0 = __pop_Termr_23_22_2d_3f_5b0_2d9_5d_2b_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_22_23(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action93::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntfloat(__nt), __end));
-                42
-            }
-            120 => {
-                // fun_vars = fun_vars, "VAR_INPUT", "END_VAR" => ActionFn(189);
-                let __sym2 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym1 = __pop_Term_22VAR__INPUT_22(__symbols);
-                let __sym0 = __pop_Ntfun__vars(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action189::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntfun__vars(__nt), __end));
-                43
-            }
-            121 => {
-                // fun_vars = fun_vars, "VAR_INPUT", var_def+, "END_VAR" => ActionFn(190);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Ntvar__def_2b(__symbols);
-                let __sym1 = __pop_Term_22VAR__INPUT_22(__symbols);
-                let __sym0 = __pop_Ntfun__vars(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action190::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntfun__vars(__nt), __end));
-                43
-            }
-            122 => {
-                // fun_vars = fun_vars, "VAR", "END_VAR" => ActionFn(191);
-                let __sym2 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym1 = __pop_Term_22VAR_22(__symbols);
-                let __sym0 = __pop_Ntfun__vars(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action191::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntfun__vars(__nt), __end));
-                43
-            }
-            123 => {
-                // fun_vars = fun_vars, "VAR", var_def+, "END_VAR" => ActionFn(192);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Ntvar__def_2b(__symbols);
-                let __sym1 = __pop_Term_22VAR_22(__symbols);
-                let __sym0 = __pop_Ntfun__vars(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action192::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntfun__vars(__nt), __end));
-                43
-            }
-            124 => {
-                // fun_vars = error => ActionFn(19);
-                let __sym0 = __pop_Termerror(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action19::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntfun__vars(__nt), __end));
-                43
-            }
-            125 => {
-                // function = "FUNCTION", ident, ":", type_, fun_vars, stmt+, "END_FUNCTION" => ActionFn(16);
-                let __sym6 = __pop_Term_22END__FUNCTION_22(__symbols);
-                let __sym5 = __pop_Ntstmt_2b(__symbols);
-                let __sym4 = __pop_Ntfun__vars(__symbols);
-                let __sym3 = __pop_Nttype__(__symbols);
-                let __sym2 = __pop_Term_22_3a_22(__symbols);
-                let __sym1 = __pop_Ntident(__symbols);
-                let __sym0 = __pop_Term_22FUNCTION_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym6.2.clone();
-                let __nt = super::__action16::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 7);
-                __symbols.push((__start, __Symbol::Ntfunction(__nt), __end));
-                44
-            }
-            126 => {
-                // function_block = "FUNCTION_BLOCK", ident, fb_vars, stmt+, "END_FUNCTION_BLOCK" => ActionFn(155);
-                let __sym4 = __pop_Term_22END__FUNCTION__BLOCK_22(__symbols);
-                let __sym3 = __pop_Ntstmt_2b(__symbols);
-                let __sym2 = __pop_Ntfb__vars(__symbols);
-                let __sym1 = __pop_Ntident(__symbols);
-                let __sym0 = __pop_Term_22FUNCTION__BLOCK_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym4.2.clone();
-                let __nt = super::__action155::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 5);
-                __symbols.push((__start, __Symbol::Ntfunction__block(__nt), __end));
-                45
-            }
-            127 => {
-                // function_block = "FUNCTION_BLOCK", ident, fb_vars, stmt+ => ActionFn(156);
-                let __sym3 = __pop_Ntstmt_2b(__symbols);
-                let __sym2 = __pop_Ntfb__vars(__symbols);
-                let __sym1 = __pop_Ntident(__symbols);
-                let __sym0 = __pop_Term_22FUNCTION__BLOCK_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action156::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntfunction__block(__nt), __end));
-                45
-            }
-            128 => {
-                // globals = "VAR_GLOBAL", "CONSTANT", var_def+, "END_VAR" => ActionFn(153);
-                let __sym3 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym2 = __pop_Ntvar__def_2b(__symbols);
-                let __sym1 = __pop_Term_22CONSTANT_22(__symbols);
-                let __sym0 = __pop_Term_22VAR__GLOBAL_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action153::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntglobals(__nt), __end));
-                46
-            }
-            129 => {
-                // globals = "VAR_GLOBAL", var_def+, "END_VAR" => ActionFn(154);
-                let __sym2 = __pop_Term_22END__VAR_22(__symbols);
-                let __sym1 = __pop_Ntvar__def_2b(__symbols);
-                let __sym0 = __pop_Term_22VAR__GLOBAL_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action154::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntglobals(__nt), __end));
-                46
-            }
-            130 => {
-                // ident = r#"[a-zA-Z_][a-zA-Z_0-9]*"# => ActionFn(86);
-                let __sym0 = __pop_Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ__0_2d9_5d_2a_22_23(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action86::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntident(__nt), __end));
-                47
-            }
-            131 => {
-                // if_else = "ELSIF", expr, "THEN", stmt+, if_else => ActionFn(35);
-                let __sym4 = __pop_Ntif__else(__symbols);
-                let __sym3 = __pop_Ntstmt_2b(__symbols);
-                let __sym2 = __pop_Term_22THEN_22(__symbols);
-                let __sym1 = __pop_Ntexpr(__symbols);
-                let __sym0 = __pop_Term_22ELSIF_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym4.2.clone();
-                let __nt = super::__action35::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 5);
-                __symbols.push((__start, __Symbol::Ntif__else(__nt), __end));
-                48
-            }
-            132 => {
-                // if_else = "ELSE", stmt+, "END_IF" => ActionFn(36);
-                let __sym2 = __pop_Term_22END__IF_22(__symbols);
-                let __sym1 = __pop_Ntstmt_2b(__symbols);
-                let __sym0 = __pop_Term_22ELSE_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action36::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntif__else(__nt), __end));
-                48
-            }
-            133 => {
-                // if_else = "END_IF" => ActionFn(37);
-                let __sym0 = __pop_Term_22END__IF_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action37::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntif__else(__nt), __end));
-                48
-            }
-            134 => {
-                // integer = r#"16#-?[0-9a-fA-F]+"# => ActionFn(87);
-                let __sym0 = __pop_Termr_23_2216_23_2d_3f_5b0_2d9a_2dfA_2dF_5d_2b_22_23(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action87::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntinteger(__nt), __end));
-                49
-            }
-            135 => {
-                // integer = r#"8#-?[0-7]+"# => ActionFn(88);
-                let __sym0 = __pop_Termr_23_228_23_2d_3f_5b0_2d7_5d_2b_22_23(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action88::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntinteger(__nt), __end));
-                49
-            }
-            136 => {
-                // integer = r#"2#-?[01]+"# => ActionFn(89);
-                let __sym0 = __pop_Termr_23_222_23_2d_3f_5b01_5d_2b_22_23(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action89::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntinteger(__nt), __end));
-                49
-            }
-            137 => {
-                // integer = decint => ActionFn(90);
-                let __sym0 = __pop_Ntdecint(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action90::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntinteger(__nt), __end));
-                49
-            }
-            138 => {
-                // literal_expr = integer => ActionFn(43);
-                let __sym0 = __pop_Ntinteger(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action43::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntliteral__expr(__nt), __end));
-                50
-            }
-            139 => {
-                // literal_expr = float => ActionFn(44);
-                let __sym0 = __pop_Ntfloat(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action44::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntliteral__expr(__nt), __end));
-                50
-            }
-            140 => {
-                // literal_expr = "TRUE" => ActionFn(45);
-                let __sym0 = __pop_Term_22TRUE_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action45::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntliteral__expr(__nt), __end));
-                50
-            }
-            141 => {
-                // literal_expr = "FALSE" => ActionFn(46);
-                let __sym0 = __pop_Term_22FALSE_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action46::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntliteral__expr(__nt), __end));
-                50
-            }
-            142 => {
-                // literal_expr = r#"[tT]#[0-9]+(\\.[0-9]+)?(ms|MS|s|S|h|H|d|D)"# => ActionFn(47);
-                let __sym0 = __pop_Termr_23_22_5btT_5d_23_5b0_2d9_5d_2b_28_5c_5c_2e_5b0_2d9_5d_2b_29_3f_28ms_7cMS_7cs_7cS_7ch_7cH_7cd_7cD_29_22_23(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action47::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntliteral__expr(__nt), __end));
-                50
-            }
-            143 => {
-                // literal_expr = r#"\'([^\']|\'\')*\'"# => ActionFn(48);
-                let __sym0 = __pop_Termr_23_22_5c_27_28_5b_5e_5c_27_5d_7c_5c_27_5c_27_29_2a_5c_27_22_23(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action48::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntliteral__expr(__nt), __end));
-                50
-            }
-            144 => {
-                // loc = r#"%[MIQ][BWX]?(\\*|[0-9]+)"# => ActionFn(22);
-                let __sym0 = __pop_Termr_23_22_25_5bMIQ_5d_5bBWX_5d_3f_28_5c_5c_2a_7c_5b0_2d9_5d_2b_29_22_23(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action22::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntloc(__nt), __end));
-                51
-            }
-            145 => {
-                // lval_expr = lval_expr, ".", decint => ActionFn(75);
-                let __sym2 = __pop_Ntdecint(__symbols);
-                let __sym1 = __pop_Term_22_2e_22(__symbols);
-                let __sym0 = __pop_Ntlval__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action75::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntlval__expr(__nt), __end));
-                52
-            }
-            146 => {
-                // lval_expr = lval_expr, ".", ident => ActionFn(76);
-                let __sym2 = __pop_Ntident(__symbols);
-                let __sym1 = __pop_Term_22_2e_22(__symbols);
-                let __sym0 = __pop_Ntlval__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action76::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntlval__expr(__nt), __end));
-                52
-            }
-            147 => {
-                // lval_expr = lval_expr, "[", expr, "]" => ActionFn(77);
-                let __sym3 = __pop_Term_22_5d_22(__symbols);
-                let __sym2 = __pop_Ntexpr(__symbols);
-                let __sym1 = __pop_Term_22_5b_22(__symbols);
-                let __sym0 = __pop_Ntlval__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action77::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntlval__expr(__nt), __end));
-                52
-            }
-            148 => {
-                // lval_expr = ident => ActionFn(78);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action78::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntlval__expr(__nt), __end));
-                52
-            }
-            149 => {
-                // prod_expr = prod_expr, "*", un_expr => ActionFn(68);
-                let __sym2 = __pop_Ntun__expr(__symbols);
-                let __sym1 = __pop_Term_22_2a_22(__symbols);
-                let __sym0 = __pop_Ntprod__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action68::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntprod__expr(__nt), __end));
-                53
-            }
-            150 => {
-                // prod_expr = prod_expr, "/", un_expr => ActionFn(69);
-                let __sym2 = __pop_Ntun__expr(__symbols);
-                let __sym1 = __pop_Term_22_2f_22(__symbols);
-                let __sym0 = __pop_Ntprod__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action69::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntprod__expr(__nt), __end));
-                53
-            }
-            151 => {
-                // prod_expr = prod_expr, "MOD", un_expr => ActionFn(70);
-                let __sym2 = __pop_Ntun__expr(__symbols);
-                let __sym1 = __pop_Term_22MOD_22(__symbols);
-                let __sym0 = __pop_Ntprod__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action70::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntprod__expr(__nt), __end));
-                53
-            }
-            152 => {
-                // prod_expr = un_expr => ActionFn(71);
-                let __sym0 = __pop_Ntun__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action71::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntprod__expr(__nt), __end));
-                53
-            }
-            153 => {
-                // program = "PROGRAM", ident, stmt+, "END_PROGRAM" => ActionFn(209);
-                let __sym3 = __pop_Term_22END__PROGRAM_22(__symbols);
-                let __sym2 = __pop_Ntstmt_2b(__symbols);
-                let __sym1 = __pop_Ntident(__symbols);
-                let __sym0 = __pop_Term_22PROGRAM_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action209::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntprogram(__nt), __end));
-                54
-            }
-            154 => {
-                // program = "PROGRAM", ident, ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+, stmt+, "END_PROGRAM" => ActionFn(210);
-                let __sym4 = __pop_Term_22END__PROGRAM_22(__symbols);
-                let __sym3 = __pop_Ntstmt_2b(__symbols);
-                let __sym2 = __pop_Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__symbols);
-                let __sym1 = __pop_Ntident(__symbols);
-                let __sym0 = __pop_Term_22PROGRAM_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym4.2.clone();
-                let __nt = super::__action210::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 5);
-                __symbols.push((__start, __Symbol::Ntprogram(__nt), __end));
-                54
-            }
-            155 => {
-                // program = "PROGRAM", ident, stmt+ => ActionFn(211);
-                let __sym2 = __pop_Ntstmt_2b(__symbols);
-                let __sym1 = __pop_Ntident(__symbols);
-                let __sym0 = __pop_Term_22PROGRAM_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action211::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntprogram(__nt), __end));
-                54
-            }
-            156 => {
-                // program = "PROGRAM", ident, ("VAR" "RETAIN"? "PERSISTENT"? <var_def*> "END_VAR")+, stmt+ => ActionFn(212);
-                let __sym3 = __pop_Ntstmt_2b(__symbols);
-                let __sym2 = __pop_Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__symbols);
-                let __sym1 = __pop_Ntident(__symbols);
-                let __sym0 = __pop_Term_22PROGRAM_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action212::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntprogram(__nt), __end));
-                54
-            }
-            157 => {
-                // stmt = "RETURN", ";" => ActionFn(27);
-                let __sym1 = __pop_Term_22_3b_22(__symbols);
-                let __sym0 = __pop_Term_22RETURN_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action27::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Ntstmt(__nt), __end));
-                55
-            }
-            158 => {
-                // stmt = "IF", expr, "THEN", stmt+, if_else => ActionFn(28);
-                let __sym4 = __pop_Ntif__else(__symbols);
-                let __sym3 = __pop_Ntstmt_2b(__symbols);
-                let __sym2 = __pop_Term_22THEN_22(__symbols);
-                let __sym1 = __pop_Ntexpr(__symbols);
-                let __sym0 = __pop_Term_22IF_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym4.2.clone();
-                let __nt = super::__action28::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 5);
-                __symbols.push((__start, __Symbol::Ntstmt(__nt), __end));
-                55
-            }
-            159 => {
-                // stmt = "CASE", expr, "OF", case+, case_else => ActionFn(29);
-                let __sym4 = __pop_Ntcase__else(__symbols);
-                let __sym3 = __pop_Ntcase_2b(__symbols);
-                let __sym2 = __pop_Term_22OF_22(__symbols);
-                let __sym1 = __pop_Ntexpr(__symbols);
-                let __sym0 = __pop_Term_22CASE_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym4.2.clone();
-                let __nt = super::__action29::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 5);
-                __symbols.push((__start, __Symbol::Ntstmt(__nt), __end));
-                55
-            }
-            160 => {
-                // stmt = "WHILE", expr, "DO", stmt+, "END_WHILE" => ActionFn(30);
-                let __sym4 = __pop_Term_22END__WHILE_22(__symbols);
-                let __sym3 = __pop_Ntstmt_2b(__symbols);
-                let __sym2 = __pop_Term_22DO_22(__symbols);
-                let __sym1 = __pop_Ntexpr(__symbols);
-                let __sym0 = __pop_Term_22WHILE_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym4.2.clone();
-                let __nt = super::__action30::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 5);
-                __symbols.push((__start, __Symbol::Ntstmt(__nt), __end));
-                55
-            }
-            161 => {
-                // stmt = lval_expr, ":=", expr, ";" => ActionFn(31);
-                let __sym3 = __pop_Term_22_3b_22(__symbols);
-                let __sym2 = __pop_Ntexpr(__symbols);
-                let __sym1 = __pop_Term_22_3a_3d_22(__symbols);
-                let __sym0 = __pop_Ntlval__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action31::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntstmt(__nt), __end));
-                55
-            }
-            162 => {
-                // stmt = ident, ";" => ActionFn(32);
-                let __sym1 = __pop_Term_22_3b_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action32::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Ntstmt(__nt), __end));
-                55
-            }
-            163 => {
-                // stmt = call_expr, ";" => ActionFn(33);
-                let __sym1 = __pop_Term_22_3b_22(__symbols);
-                let __sym0 = __pop_Ntcall__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action33::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Ntstmt(__nt), __end));
-                55
-            }
-            164 => {
-                // stmt = ";" => ActionFn(34);
-                let __sym0 = __pop_Term_22_3b_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action34::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntstmt(__nt), __end));
-                55
-            }
-            165 => {
-                // stmt+ = stmt => ActionFn(119);
-                let __sym0 = __pop_Ntstmt(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action119::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntstmt_2b(__nt), __end));
-                56
-            }
-            166 => {
-                // stmt+ = stmt+, stmt => ActionFn(120);
-                let __sym1 = __pop_Ntstmt(__symbols);
-                let __sym0 = __pop_Ntstmt_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action120::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Ntstmt_2b(__nt), __end));
-                56
-            }
-            167 => {
-                // structinit = ident, ":=", literal_expr, ",", structinit => ActionFn(227);
-                let __sym4 = __pop_Ntstructinit(__symbols);
-                let __sym3 = __pop_Term_22_2c_22(__symbols);
-                let __sym2 = __pop_Ntliteral__expr(__symbols);
-                let __sym1 = __pop_Term_22_3a_3d_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym4.2.clone();
-                let __nt = super::__action227::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 5);
-                __symbols.push((__start, __Symbol::Ntstructinit(__nt), __end));
-                57
-            }
-            168 => {
-                // structinit = ident, ":=", (<literal_expr> ",")+, literal_expr, ",", structinit => ActionFn(228);
-                let __sym5 = __pop_Ntstructinit(__symbols);
-                let __sym4 = __pop_Term_22_2c_22(__symbols);
-                let __sym3 = __pop_Ntliteral__expr(__symbols);
-                let __sym2 = __pop_Nt_28_3cliteral__expr_3e_20_22_2c_22_29_2b(__symbols);
-                let __sym1 = __pop_Term_22_3a_3d_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym5.2.clone();
-                let __nt = super::__action228::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 6);
-                __symbols.push((__start, __Symbol::Ntstructinit(__nt), __end));
-                57
-            }
-            169 => {
-                // structinit = ident, ":=", literal_expr => ActionFn(229);
-                let __sym2 = __pop_Ntliteral__expr(__symbols);
-                let __sym1 = __pop_Term_22_3a_3d_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action229::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntstructinit(__nt), __end));
-                57
-            }
-            170 => {
-                // structinit = ident, ":=", (<literal_expr> ",")+, literal_expr => ActionFn(230);
-                let __sym3 = __pop_Ntliteral__expr(__symbols);
-                let __sym2 = __pop_Nt_28_3cliteral__expr_3e_20_22_2c_22_29_2b(__symbols);
-                let __sym1 = __pop_Term_22_3a_3d_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action230::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntstructinit(__nt), __end));
-                57
-            }
-            171 => {
-                // sum_expr = sum_expr, "+", prod_expr => ActionFn(65);
-                let __sym2 = __pop_Ntprod__expr(__symbols);
-                let __sym1 = __pop_Term_22_2b_22(__symbols);
-                let __sym0 = __pop_Ntsum__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action65::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntsum__expr(__nt), __end));
-                58
-            }
-            172 => {
-                // sum_expr = sum_expr, "-", prod_expr => ActionFn(66);
-                let __sym2 = __pop_Ntprod__expr(__symbols);
-                let __sym1 = __pop_Term_22_2d_22(__symbols);
-                let __sym0 = __pop_Ntsum__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action66::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntsum__expr(__nt), __end));
-                58
-            }
-            173 => {
-                // sum_expr = prod_expr => ActionFn(67);
-                let __sym0 = __pop_Ntprod__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action67::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntsum__expr(__nt), __end));
-                58
-            }
-            174 => {
-                // type_ = "ARRAY", "[", integer, "..", integer, "]", "OF", type_ => ActionFn(23);
-                let __sym7 = __pop_Nttype__(__symbols);
-                let __sym6 = __pop_Term_22OF_22(__symbols);
-                let __sym5 = __pop_Term_22_5d_22(__symbols);
-                let __sym4 = __pop_Ntinteger(__symbols);
-                let __sym3 = __pop_Term_22_2e_2e_22(__symbols);
-                let __sym2 = __pop_Ntinteger(__symbols);
-                let __sym1 = __pop_Term_22_5b_22(__symbols);
-                let __sym0 = __pop_Term_22ARRAY_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym7.2.clone();
-                let __nt = super::__action23::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 8);
-                __symbols.push((__start, __Symbol::Nttype__(__nt), __end));
-                59
-            }
-            175 => {
-                // type_ = "STRING", "[", integer, "]" => ActionFn(24);
-                let __sym3 = __pop_Term_22_5d_22(__symbols);
-                let __sym2 = __pop_Ntinteger(__symbols);
-                let __sym1 = __pop_Term_22_5b_22(__symbols);
-                let __sym0 = __pop_Term_22STRING_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action24::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Nttype__(__nt), __end));
-                59
-            }
-            176 => {
-                // type_ = "STRING" => ActionFn(25);
-                let __sym0 = __pop_Term_22STRING_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action25::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Nttype__(__nt), __end));
-                59
-            }
-            177 => {
-                // type_ = ident => ActionFn(26);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action26::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Nttype__(__nt), __end));
-                59
-            }
-            178 => {
-                // typedef = "TYPE", ident, ":", "STRUCT", var_def+, "END_STRUCT", "END_TYPE" => ActionFn(7);
-                let __sym6 = __pop_Term_22END__TYPE_22(__symbols);
-                let __sym5 = __pop_Term_22END__STRUCT_22(__symbols);
-                let __sym4 = __pop_Ntvar__def_2b(__symbols);
-                let __sym3 = __pop_Term_22STRUCT_22(__symbols);
-                let __sym2 = __pop_Term_22_3a_22(__symbols);
-                let __sym1 = __pop_Ntident(__symbols);
-                let __sym0 = __pop_Term_22TYPE_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym6.2.clone();
-                let __nt = super::__action7::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 7);
-                __symbols.push((__start, __Symbol::Nttypedef(__nt), __end));
-                60
-            }
-            179 => {
-                // typedef = "TYPE", ident, ":", type_, "END_TYPE" => ActionFn(8);
-                let __sym4 = __pop_Term_22END__TYPE_22(__symbols);
-                let __sym3 = __pop_Nttype__(__symbols);
-                let __sym2 = __pop_Term_22_3a_22(__symbols);
-                let __sym1 = __pop_Ntident(__symbols);
-                let __sym0 = __pop_Term_22TYPE_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym4.2.clone();
-                let __nt = super::__action8::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 5);
-                __symbols.push((__start, __Symbol::Nttypedef(__nt), __end));
-                60
-            }
-            180 => {
-                // un_expr = "-", un_expr => ActionFn(72);
-                let __sym1 = __pop_Ntun__expr(__symbols);
-                let __sym0 = __pop_Term_22_2d_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action72::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Ntun__expr(__nt), __end));
-                61
-            }
-            181 => {
-                // un_expr = "NOT", un_expr => ActionFn(73);
-                let __sym1 = __pop_Ntun__expr(__symbols);
-                let __sym0 = __pop_Term_22NOT_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action73::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Ntun__expr(__nt), __end));
-                61
-            }
-            182 => {
-                // un_expr = atom_expr => ActionFn(74);
-                let __sym0 = __pop_Ntatom__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action74::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntun__expr(__nt), __end));
-                61
-            }
-            183 => {
-                // var_def = ident, "AT", loc, ":", type_, ":=", var_default, ";" => ActionFn(169);
-                let __sym7 = __pop_Term_22_3b_22(__symbols);
-                let __sym6 = __pop_Ntvar__default(__symbols);
-                let __sym5 = __pop_Term_22_3a_3d_22(__symbols);
-                let __sym4 = __pop_Nttype__(__symbols);
-                let __sym3 = __pop_Term_22_3a_22(__symbols);
-                let __sym2 = __pop_Ntloc(__symbols);
-                let __sym1 = __pop_Term_22AT_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym7.2.clone();
-                let __nt = super::__action169::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 8);
-                __symbols.push((__start, __Symbol::Ntvar__def(__nt), __end));
-                62
-            }
-            184 => {
-                // var_def = ident, ":", type_, ":=", var_default, ";" => ActionFn(170);
-                let __sym5 = __pop_Term_22_3b_22(__symbols);
-                let __sym4 = __pop_Ntvar__default(__symbols);
-                let __sym3 = __pop_Term_22_3a_3d_22(__symbols);
-                let __sym2 = __pop_Nttype__(__symbols);
-                let __sym1 = __pop_Term_22_3a_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym5.2.clone();
-                let __nt = super::__action170::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 6);
-                __symbols.push((__start, __Symbol::Ntvar__def(__nt), __end));
-                62
-            }
-            185 => {
-                // var_def = ident, "AT", loc, ":", type_, ";" => ActionFn(171);
-                let __sym5 = __pop_Term_22_3b_22(__symbols);
-                let __sym4 = __pop_Nttype__(__symbols);
-                let __sym3 = __pop_Term_22_3a_22(__symbols);
-                let __sym2 = __pop_Ntloc(__symbols);
-                let __sym1 = __pop_Term_22AT_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym5.2.clone();
-                let __nt = super::__action171::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 6);
-                __symbols.push((__start, __Symbol::Ntvar__def(__nt), __end));
-                62
-            }
-            186 => {
-                // var_def = ident, ":", type_, ";" => ActionFn(172);
-                let __sym3 = __pop_Term_22_3b_22(__symbols);
-                let __sym2 = __pop_Nttype__(__symbols);
-                let __sym1 = __pop_Term_22_3a_22(__symbols);
-                let __sym0 = __pop_Ntident(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action172::<>(input, __sym0, __sym1, __sym2, __sym3);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::Ntvar__def(__nt), __end));
-                62
-            }
-            187 => {
-                // var_def* =  => ActionFn(124);
-                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-                let __nt = super::__action124::<>(input, &__start, &__end);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 0);
-                __symbols.push((__start, __Symbol::Ntvar__def_2a(__nt), __end));
-                63
-            }
-            188 => {
-                // var_def* = var_def+ => ActionFn(125);
-                let __sym0 = __pop_Ntvar__def_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action125::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntvar__def_2a(__nt), __end));
-                63
-            }
-            189 => {
-                // var_def+ = var_def => ActionFn(130);
-                let __sym0 = __pop_Ntvar__def(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action130::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntvar__def_2b(__nt), __end));
-                64
-            }
-            190 => {
-                // var_def+ = var_def+, var_def => ActionFn(131);
-                let __sym1 = __pop_Ntvar__def(__symbols);
-                let __sym0 = __pop_Ntvar__def_2b(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym1.2.clone();
-                let __nt = super::__action131::<>(input, __sym0, __sym1);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::Ntvar__def_2b(__nt), __end));
-                64
-            }
-            191 => {
-                // var_default = comma<expr> => ActionFn(21);
-                let __sym0 = __pop_Ntcomma_3cexpr_3e(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action21::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntvar__default(__nt), __end));
-                65
-            }
-            192 => {
-                // xor_expr = xor_expr, "XOR", and_expr => ActionFn(52);
-                let __sym2 = __pop_Ntand__expr(__symbols);
-                let __sym1 = __pop_Term_22XOR_22(__symbols);
-                let __sym0 = __pop_Ntxor__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action52::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::Ntxor__expr(__nt), __end));
-                66
-            }
-            193 => {
-                // xor_expr = and_expr => ActionFn(53);
-                let __sym0 = __pop_Ntand__expr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action53::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::Ntxor__expr(__nt), __end));
-                66
-            }
-            _ => panic!("invalid action code {}", __action)
-        };
-        let __state = *__states.last().unwrap() as usize;
-        let __next_state = __GOTO[__state * 67 + __nonterminal] - 1;
-        __states.push(__next_state);
-        None
-    }
-    fn __pop_Term_22_28_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_29_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_2a_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2a_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_2b_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_2c_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2c_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_2d_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_2e_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2e_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_2e_2e_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2e_2e_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_2f_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2f_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_3a_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3a_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_3a_3d_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3a_3d_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_3b_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3b_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_3c_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3c_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_3c_3d_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3c_3d_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_3c_3e_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3c_3e_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_3d_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3d_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_3d_3e_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3d_3e_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_3e_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3e_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_3e_3d_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3e_3d_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22AND_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22AND_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22AND__THEN_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22AND__THEN_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22ARRAY_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22ARRAY_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22AT_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22AT_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22CASE_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22CASE_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22CONSTANT_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22CONSTANT_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22DO_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22DO_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22ELSE_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22ELSE_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22ELSIF_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22ELSIF_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22END__CASE_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22END__CASE_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22END__FUNCTION_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22END__FUNCTION_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22END__FUNCTION__BLOCK_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22END__FUNCTION__BLOCK_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22END__IF_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22END__IF_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22END__PROGRAM_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22END__PROGRAM_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22END__STRUCT_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22END__STRUCT_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22END__TYPE_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22END__TYPE_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22END__VAR_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22END__VAR_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22END__WHILE_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22END__WHILE_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22FALSE_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22FALSE_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22FUNCTION_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22FUNCTION_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22FUNCTION__BLOCK_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22FUNCTION__BLOCK_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22IF_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22IF_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22MOD_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22MOD_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22NOT_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22NOT_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22OF_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22OF_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22OR_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22OR_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22OR__ELSE_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22OR__ELSE_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22PERSISTENT_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22PERSISTENT_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22PROGRAM_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22PROGRAM_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22RETAIN_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22RETAIN_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22RETURN_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22RETURN_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22STRING_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22STRING_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22STRUCT_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22STRUCT_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22THEN_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22THEN_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22TRUE_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22TRUE_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22TYPE_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22TYPE_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22VAR_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22VAR_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22VAR__GLOBAL_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22VAR__GLOBAL_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22VAR__INPUT_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22VAR__INPUT_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22VAR__IN__OUT_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22VAR__IN__OUT_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22VAR__OUTPUT_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22VAR__OUTPUT_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22WHILE_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22WHILE_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22XOR_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22XOR_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_5b_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_5b_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_5d_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_5d_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Termr_23_22_25_5bMIQ_5d_5bBWX_5d_3f_28_5c_5c_2a_7c_5b0_2d9_5d_2b_29_22_23<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Termr_23_22_25_5bMIQ_5d_5bBWX_5d_3f_28_5c_5c_2a_7c_5b0_2d9_5d_2b_29_22_23(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Termr_23_22_5c_27_28_5b_5e_5c_27_5d_7c_5c_27_5c_27_29_2a_5c_27_22_23<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Termr_23_22_5c_27_28_5b_5e_5c_27_5d_7c_5c_27_5c_27_29_2a_5c_27_22_23(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Termr_23_22_2d_3f_5b0_2d9_5d_2b_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_22_23<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Termr_23_22_2d_3f_5b0_2d9_5d_2b_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Termr_23_22_2d_3f_5b0_2d9_5d_2b_5c_5c_2e_5b0_2d9_5d_2b_28_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_3f_22_23<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Termr_23_22_2d_3f_5b0_2d9_5d_2b_5c_5c_2e_5b0_2d9_5d_2b_28_5beE_5d_5b_2d_2b_5d_3f_5b0_2d9_5d_2b_29_3f_22_23(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Termr_23_2216_23_2d_3f_5b0_2d9a_2dfA_2dF_5d_2b_22_23<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Termr_23_2216_23_2d_3f_5b0_2d9a_2dfA_2dF_5d_2b_22_23(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Termr_23_222_23_2d_3f_5b01_5d_2b_22_23<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Termr_23_222_23_2d_3f_5b01_5d_2b_22_23(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Termr_23_228_23_2d_3f_5b0_2d7_5d_2b_22_23<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Termr_23_228_23_2d_3f_5b0_2d7_5d_2b_22_23(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ__0_2d9_5d_2a_22_23<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ__0_2d9_5d_2a_22_23(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Termr_23_22_5btT_5d_23_5b0_2d9_5d_2b_28_5c_5c_2e_5b0_2d9_5d_2b_29_3f_28ms_7cMS_7cs_7cS_7ch_7cH_7cd_7cD_29_22_23<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Termr_23_22_5btT_5d_23_5b0_2d9_5d_2b_28_5c_5c_2e_5b0_2d9_5d_2b_29_3f_28ms_7cMS_7cs_7cS_7ch_7cH_7cd_7cD_29_22_23(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Termerror<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Termerror(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_22CONSTANT_22_3f<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::option::Option<&'input str>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_22CONSTANT_22_3f(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_22END__FUNCTION__BLOCK_22_3f<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::option::Option<&'input str>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_22END__FUNCTION__BLOCK_22_3f(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_22END__PROGRAM_22_3f<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::option::Option<&'input str>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_22END__PROGRAM_22_3f(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_22PERSISTENT_22_3f<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::option::Option<&'input str>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_22PERSISTENT_22_3f(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_22RETAIN_22_3f<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::option::Option<&'input str>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_22RETAIN_22_3f(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_22_3a_3d_22_20_3cvar__default_3e_29<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_22_3a_3d_22_20_3cvar__default_3e_29(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_22_3a_3d_22_20_3cvar__default_3e_29_3f<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::option::Option<Expr>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_22_3a_3d_22_20_3cvar__default_3e_29_3f(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_22AT_22_20_3cloc_3e_29<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, String, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_22AT_22_20_3cloc_3e_29(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_22AT_22_20_3cloc_3e_29_3f<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::option::Option<String>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_22AT_22_20_3cloc_3e_29_3f(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<VarDef>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2a<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2a(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_22VAR_22_20_22RETAIN_22_3f_20_22PERSISTENT_22_3f_20_3cvar__def_2a_3e_20_22END__VAR_22_29_2b(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_3cargassign_3e_20_22_2c_22_29<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Kwarg, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_3cargassign_3e_20_22_2c_22_29(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_3cargassign_3e_20_22_2c_22_29_2a<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<Kwarg>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_3cargassign_3e_20_22_2c_22_29_2a(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_3cargassign_3e_20_22_2c_22_29_2b<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<Kwarg>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_3cargassign_3e_20_22_2c_22_29_2b(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_3ccase__match_3e_20_22_2c_22_29<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, CaseExpr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_3ccase__match_3e_20_22_2c_22_29(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_3ccase__match_3e_20_22_2c_22_29_2a<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<CaseExpr>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_3ccase__match_3e_20_22_2c_22_29_2a(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_3ccase__match_3e_20_22_2c_22_29_2b<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<CaseExpr>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_3ccase__match_3e_20_22_2c_22_29_2b(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_3cexpr_3e_20_22_2c_22_29<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_3cexpr_3e_20_22_2c_22_29(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_3cexpr_3e_20_22_2c_22_29_2a<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<Expr>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_3cexpr_3e_20_22_2c_22_29_2a(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_3cexpr_3e_20_22_2c_22_29_2b<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<Expr>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_3cexpr_3e_20_22_2c_22_29_2b(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_3cliteral__expr_3e_20_22_2c_22_29<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_3cliteral__expr_3e_20_22_2c_22_29(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_3cliteral__expr_3e_20_22_2c_22_29_2a<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<Expr>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_3cliteral__expr_3e_20_22_2c_22_29_2a(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt_28_3cliteral__expr_3e_20_22_2c_22_29_2b<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<Expr>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt_28_3cliteral__expr_3e_20_22_2c_22_29_2b(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nt____file<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, POU, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt____file(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntand__expr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntand__expr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntargassign<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Kwarg, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntargassign(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntatom__expr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntatom__expr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntcall__expr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntcall__expr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntcase<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Case, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntcase(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntcase_2b<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<Case>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntcase_2b(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntcase__else<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<Stmt>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntcase__else(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntcase__match<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, CaseExpr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntcase__match(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntcomma_3cargassign_3e<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<Kwarg>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntcomma_3cargassign_3e(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntcomma_3ccase__match_3e<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<CaseExpr>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntcomma_3ccase__match_3e(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntcomma_3cexpr_3e<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<Expr>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntcomma_3cexpr_3e(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntcomp__expr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntcomp__expr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntdecint<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, i64, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntdecint(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nteq__expr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nteq__expr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntexpr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntexpr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntfb__vars<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<VarBlock>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntfb__vars(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntfile<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, POU, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntfile(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntfloat<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, f64, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntfloat(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntfun__vars<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<VarBlock>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntfun__vars(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntfunction<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, POU, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntfunction(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntfunction__block<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, POU, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntfunction__block(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntglobals<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, POU, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntglobals(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntident<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, String, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntident(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntif__else<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<Stmt>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntif__else(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntinteger<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (u16, i64), usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntinteger(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntliteral__expr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntliteral__expr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntloc<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, String, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntloc(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntlval__expr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntlval__expr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntprod__expr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntprod__expr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntprogram<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, POU, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntprogram(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntstmt<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Stmt, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntstmt(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntstmt_2b<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<Stmt>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntstmt_2b(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntstructinit<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<(String, Expr)>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntstructinit(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntsum__expr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntsum__expr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nttype__<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Type, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nttype__(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Nttypedef<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, POU, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nttypedef(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntun__expr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntun__expr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntvar__def<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, VarDef, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntvar__def(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntvar__def_2a<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<VarDef>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntvar__def_2a(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntvar__def_2b<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<VarDef>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntvar__def_2b(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntvar__default<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntvar__default(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Ntxor__expr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Ntxor__expr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-}
-pub use self::__parse__file::parse_file;
-mod __intern_token {
-    #![allow(unused_imports)]
-    use ast::*;
-    use app;
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    extern crate regex as __regex;
-    use std::fmt as __fmt;
-
-    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-    pub struct Token<'input>(pub usize, pub &'input str);
-    impl<'a> __fmt::Display for Token<'a> {
-        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
-            __fmt::Display::fmt(self.1, formatter)
-        }
-    }
-
-    pub struct __Matcher<'input> {
-        text: &'input str,
-        consumed: usize,
-        regex_set: __regex::RegexSet,
-        regex_vec: Vec<__regex::Regex>,
-    }
-
-    impl<'input> __Matcher<'input> {
-        pub fn new(s: &'input str) -> __Matcher<'input> {
-            let __strs: &[&str] = &[
-                "^(?u:%)(?u:[I-IM-MQ-Q])(?u:[B-BW-X])?((?u:\\*)|(?u:[0-9])+)",
-                "^(?u:\')((?u:[\u{0}-\\&\\(-\u{10ffff}])|(?u:\'\'))*(?u:\')",
-                "^(?u:\\-)?(?u:[0-9])+(?u:[E-Ee-e])(?u:[-\\+-\\+])?(?u:[0-9])+",
-                "^(?u:\\-)?(?u:[0-9])+(?u:\\.)(?u:[0-9])+((?u:[E-Ee-e])(?u:[-\\+-\\+])?(?u:[0-9])+)?",
-                "^(?u:16\\#)(?u:\\-)?(?u:[0-9A-Fa-f])+",
-                "^(?u:2\\#)(?u:\\-)?(?u:[0-1])+",
-                "^(?u:8\\#)(?u:\\-)?(?u:[0-7])+",
-                "^(?u:[0-9])+",
-                "^(?u:[A-Z_-_a-z])(?u:[0-9A-Z_-_a-z])*",
-                "^(?u:[T-Tt-t])(?u:\\#)(?u:[0-9])+((?u:\\.)(?u:[0-9])+)?((?u:ms)|(?u:MS)|(?u:s)|(?u:S)|(?u:h)|(?u:H)|(?u:d)|(?u:D))",
-                "^(?u:\\()",
-                "^(?u:\\))",
-                "^(?u:\\*)",
-                "^(?u:\\+)",
-                "^(?u:,)",
-                "^(?u:\\-)",
-                "^(?u:\\.)",
-                "^(?u:\\.\\.)",
-                "^(?u:/)",
-                "^(?u::)",
-                "^(?u::=)",
-                "^(?u:;)",
-                "^(?u:<)",
-                "^(?u:<=)",
-                "^(?u:<>)",
-                "^(?u:=)",
-                "^(?u:=>)",
-                "^(?u:>)",
-                "^(?u:>=)",
-                "^(?u:AND)",
-                "^(?u:AND_THEN)",
-                "^(?u:ARRAY)",
-                "^(?u:AT)",
-                "^(?u:CASE)",
-                "^(?u:CONSTANT)",
-                "^(?u:DO)",
-                "^(?u:ELSE)",
-                "^(?u:ELSIF)",
-                "^(?u:END_CASE)",
-                "^(?u:END_FUNCTION)",
-                "^(?u:END_FUNCTION_BLOCK)",
-                "^(?u:END_IF)",
-                "^(?u:END_PROGRAM)",
-                "^(?u:END_STRUCT)",
-                "^(?u:END_TYPE)",
-                "^(?u:END_VAR)",
-                "^(?u:END_WHILE)",
-                "^(?u:FALSE)",
-                "^(?u:FUNCTION)",
-                "^(?u:FUNCTION_BLOCK)",
-                "^(?u:IF)",
-                "^(?u:MOD)",
-                "^(?u:NOT)",
-                "^(?u:OF)",
-                "^(?u:OR)",
-                "^(?u:OR_ELSE)",
-                "^(?u:PERSISTENT)",
-                "^(?u:PROGRAM)",
-                "^(?u:RETAIN)",
-                "^(?u:RETURN)",
-                "^(?u:STRING)",
-                "^(?u:STRUCT)",
-                "^(?u:THEN)",
-                "^(?u:TRUE)",
-                "^(?u:TYPE)",
-                "^(?u:VAR)",
-                "^(?u:VAR_GLOBAL)",
-                "^(?u:VAR_INPUT)",
-                "^(?u:VAR_IN_OUT)",
-                "^(?u:VAR_OUTPUT)",
-                "^(?u:WHILE)",
-                "^(?u:XOR)",
-                "^(?u:\\[)",
-                "^(?u:\\])",
-            ];
-            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
-            let __regex_vec = vec![
-                __regex::Regex::new("^(?u:%)(?u:[I-IM-MQ-Q])(?u:[B-BW-X])?((?u:\\*)|(?u:[0-9])+)").unwrap(),
-                __regex::Regex::new("^(?u:\')((?u:[\u{0}-\\&\\(-\u{10ffff}])|(?u:\'\'))*(?u:\')").unwrap(),
-                __regex::Regex::new("^(?u:\\-)?(?u:[0-9])+(?u:[E-Ee-e])(?u:[-\\+-\\+])?(?u:[0-9])+").unwrap(),
-                __regex::Regex::new("^(?u:\\-)?(?u:[0-9])+(?u:\\.)(?u:[0-9])+((?u:[E-Ee-e])(?u:[-\\+-\\+])?(?u:[0-9])+)?").unwrap(),
-                __regex::Regex::new("^(?u:16\\#)(?u:\\-)?(?u:[0-9A-Fa-f])+").unwrap(),
-                __regex::Regex::new("^(?u:2\\#)(?u:\\-)?(?u:[0-1])+").unwrap(),
-                __regex::Regex::new("^(?u:8\\#)(?u:\\-)?(?u:[0-7])+").unwrap(),
-                __regex::Regex::new("^(?u:[0-9])+").unwrap(),
-                __regex::Regex::new("^(?u:[A-Z_-_a-z])(?u:[0-9A-Z_-_a-z])*").unwrap(),
-                __regex::Regex::new("^(?u:[T-Tt-t])(?u:\\#)(?u:[0-9])+((?u:\\.)(?u:[0-9])+)?((?u:ms)|(?u:MS)|(?u:s)|(?u:S)|(?u:h)|(?u:H)|(?u:d)|(?u:D))").unwrap(),
-                __regex::Regex::new("^(?u:\\()").unwrap(),
-                __regex::Regex::new("^(?u:\\))").unwrap(),
-                __regex::Regex::new("^(?u:\\*)").unwrap(),
-                __regex::Regex::new("^(?u:\\+)").unwrap(),
-                __regex::Regex::new("^(?u:,)").unwrap(),
-                __regex::Regex::new("^(?u:\\-)").unwrap(),
-                __regex::Regex::new("^(?u:\\.)").unwrap(),
-                __regex::Regex::new("^(?u:\\.\\.)").unwrap(),
-                __regex::Regex::new("^(?u:/)").unwrap(),
-                __regex::Regex::new("^(?u::)").unwrap(),
-                __regex::Regex::new("^(?u::=)").unwrap(),
-                __regex::Regex::new("^(?u:;)").unwrap(),
-                __regex::Regex::new("^(?u:<)").unwrap(),
-                __regex::Regex::new("^(?u:<=)").unwrap(),
-                __regex::Regex::new("^(?u:<>)").unwrap(),
-                __regex::Regex::new("^(?u:=)").unwrap(),
-                __regex::Regex::new("^(?u:=>)").unwrap(),
-                __regex::Regex::new("^(?u:>)").unwrap(),
-                __regex::Regex::new("^(?u:>=)").unwrap(),
-                __regex::Regex::new("^(?u:AND)").unwrap(),
-                __regex::Regex::new("^(?u:AND_THEN)").unwrap(),
-                __regex::Regex::new("^(?u:ARRAY)").unwrap(),
-                __regex::Regex::new("^(?u:AT)").unwrap(),
-                __regex::Regex::new("^(?u:CASE)").unwrap(),
-                __regex::Regex::new("^(?u:CONSTANT)").unwrap(),
-                __regex::Regex::new("^(?u:DO)").unwrap(),
-                __regex::Regex::new("^(?u:ELSE)").unwrap(),
-                __regex::Regex::new("^(?u:ELSIF)").unwrap(),
-                __regex::Regex::new("^(?u:END_CASE)").unwrap(),
-                __regex::Regex::new("^(?u:END_FUNCTION)").unwrap(),
-                __regex::Regex::new("^(?u:END_FUNCTION_BLOCK)").unwrap(),
-                __regex::Regex::new("^(?u:END_IF)").unwrap(),
-                __regex::Regex::new("^(?u:END_PROGRAM)").unwrap(),
-                __regex::Regex::new("^(?u:END_STRUCT)").unwrap(),
-                __regex::Regex::new("^(?u:END_TYPE)").unwrap(),
-                __regex::Regex::new("^(?u:END_VAR)").unwrap(),
-                __regex::Regex::new("^(?u:END_WHILE)").unwrap(),
-                __regex::Regex::new("^(?u:FALSE)").unwrap(),
-                __regex::Regex::new("^(?u:FUNCTION)").unwrap(),
-                __regex::Regex::new("^(?u:FUNCTION_BLOCK)").unwrap(),
-                __regex::Regex::new("^(?u:IF)").unwrap(),
-                __regex::Regex::new("^(?u:MOD)").unwrap(),
-                __regex::Regex::new("^(?u:NOT)").unwrap(),
-                __regex::Regex::new("^(?u:OF)").unwrap(),
-                __regex::Regex::new("^(?u:OR)").unwrap(),
-                __regex::Regex::new("^(?u:OR_ELSE)").unwrap(),
-                __regex::Regex::new("^(?u:PERSISTENT)").unwrap(),
-                __regex::Regex::new("^(?u:PROGRAM)").unwrap(),
-                __regex::Regex::new("^(?u:RETAIN)").unwrap(),
-                __regex::Regex::new("^(?u:RETURN)").unwrap(),
-                __regex::Regex::new("^(?u:STRING)").unwrap(),
-                __regex::Regex::new("^(?u:STRUCT)").unwrap(),
-                __regex::Regex::new("^(?u:THEN)").unwrap(),
-                __regex::Regex::new("^(?u:TRUE)").unwrap(),
-                __regex::Regex::new("^(?u:TYPE)").unwrap(),
-                __regex::Regex::new("^(?u:VAR)").unwrap(),
-                __regex::Regex::new("^(?u:VAR_GLOBAL)").unwrap(),
-                __regex::Regex::new("^(?u:VAR_INPUT)").unwrap(),
-                __regex::Regex::new("^(?u:VAR_IN_OUT)").unwrap(),
-                __regex::Regex::new("^(?u:VAR_OUTPUT)").unwrap(),
-                __regex::Regex::new("^(?u:WHILE)").unwrap(),
-                __regex::Regex::new("^(?u:XOR)").unwrap(),
-                __regex::Regex::new("^(?u:\\[)").unwrap(),
-                __regex::Regex::new("^(?u:\\])").unwrap(),
-            ];
-            __Matcher {
-                text: s,
-                consumed: 0,
-                regex_set: __regex_set,
-                regex_vec: __regex_vec,
-            }
-        }
-    }
-
-    impl<'input> Iterator for __Matcher<'input> {
-        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
-
-        fn next(&mut self) -> Option<Self::Item> {
-            let __text = self.text.trim_left();
-            let __whitespace = self.text.len() - __text.len();
-            let __start_offset = self.consumed + __whitespace;
-            if __text.is_empty() {
-                self.text = __text;
-                self.consumed = __start_offset;
-                None
-            } else {
-                let __matches = self.regex_set.matches(__text);
-                if !__matches.matched_any() {
-                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
-                        location: __start_offset,
-                    }))
-                } else {
-                    let mut __longest_match = 0;
-                    let mut __index = 0;
-                    for __i in 0 .. 74 {
-                        if __matches.matched(__i) {
-                            let __match = self.regex_vec[__i].find(__text).unwrap();
-                            let __len = __match.end();
-                            if __len >= __longest_match {
-                                __longest_match = __len;
-                                __index = __i;
-                            }
-                        }
-                    }
-                    let __result = &__text[..__longest_match];
-                    let __remaining = &__text[__longest_match..];
-                    let __end_offset = __start_offset + __longest_match;
-                    self.text = __remaining;
-                    self.consumed = __end_offset;
-                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
-                }
-            }
-        }
-    }
-}
-pub use self::__intern_token::Token;
-
-#[allow(unused_variables)]
-fn __action0<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, POU, usize),
-) -> POU
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action1<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, POU, usize),
-) -> POU
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action2<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, POU, usize),
-) -> POU
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action3<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, POU, usize),
-) -> POU
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action4<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, POU, usize),
-) -> POU
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action5<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, POU, usize),
-) -> POU
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action6<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, constant, _): (usize, ::std::option::Option<&'input str>, usize),
-    (_, vars, _): (usize, ::std::vec::Vec<VarDef>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> POU
-{
-    POU("".into(), POUType::Globals { vars, constant: constant.is_some() })
-}
-
-#[allow(unused_variables)]
-fn __action7<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, name, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, members, _): (usize, ::std::vec::Vec<VarDef>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> POU
-{
-    POU(name, POUType::Struct { members })
-}
-
-#[allow(unused_variables)]
-fn __action8<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, name, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, alias, _): (usize, Type, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> POU
-{
-    POU(name, POUType::Typedef { alias })
-}
-
-#[allow(unused_variables)]
-fn __action9<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, name, _): (usize, String, usize),
-    (_, vars, _): (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize),
-    (_, body, _): (usize, ::std::vec::Vec<Stmt>, usize),
-    (_, _, _): (usize, ::std::option::Option<&'input str>, usize),
-) -> POU
-{
-    POU(name, POUType::Program { body, vars: vars.into_iter().flat_map(|v| v).collect() })
-}
-
-#[allow(unused_variables)]
-fn __action10<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, name, _): (usize, String, usize),
-    (_, vars, _): (usize, Vec<VarBlock>, usize),
-    (_, body, _): (usize, ::std::vec::Vec<Stmt>, usize),
-    (_, _, _): (usize, ::std::option::Option<&'input str>, usize),
-) -> POU
-{
-    POU(name, POUType::FBlock { body, vars })
-}
-
-#[allow(unused_variables)]
-fn __action11<
-    'input,
->(
-    input: &'input str,
-    (_, vs, _): (usize, Vec<VarBlock>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, v, _): (usize, ::std::vec::Vec<VarDef>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    app(vs, VarBlock(VarType::InOut, v))
-}
-
-#[allow(unused_variables)]
-fn __action12<
-    'input,
->(
-    input: &'input str,
-    (_, vs, _): (usize, Vec<VarBlock>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, v, _): (usize, ::std::vec::Vec<VarDef>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    app(vs, VarBlock(VarType::In, v))
-}
-
-#[allow(unused_variables)]
-fn __action13<
-    'input,
->(
-    input: &'input str,
-    (_, vs, _): (usize, Vec<VarBlock>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, v, _): (usize, ::std::vec::Vec<VarDef>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    app(vs, VarBlock(VarType::Out, v))
-}
-
-#[allow(unused_variables)]
-fn __action14<
-    'input,
->(
-    input: &'input str,
-    (_, vs, _): (usize, Vec<VarBlock>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, v, _): (usize, ::std::vec::Vec<VarDef>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    app(vs, VarBlock(VarType::Local, v))
-}
-
-#[allow(unused_variables)]
-fn __action15<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
-) -> Vec<VarBlock>
-{
-    vec![]
-}
-
-#[allow(unused_variables)]
-fn __action16<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, name, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, rtype, _): (usize, Type, usize),
-    (_, vars, _): (usize, Vec<VarBlock>, usize),
-    (_, body, _): (usize, ::std::vec::Vec<Stmt>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> POU
-{
-    POU(name, POUType::Function { rtype, body, vars })
-}
-
-#[allow(unused_variables)]
-fn __action17<
-    'input,
->(
-    input: &'input str,
-    (_, vs, _): (usize, Vec<VarBlock>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, v, _): (usize, ::std::vec::Vec<VarDef>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    app(vs, VarBlock(VarType::In, v))
-}
-
-#[allow(unused_variables)]
-fn __action18<
-    'input,
->(
-    input: &'input str,
-    (_, vs, _): (usize, Vec<VarBlock>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, v, _): (usize, ::std::vec::Vec<VarDef>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    app(vs, VarBlock(VarType::Local, v))
-}
-
-#[allow(unused_variables)]
-fn __action19<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
-) -> Vec<VarBlock>
-{
-    vec![]
-}
-
-#[allow(unused_variables)]
-fn __action20<
-    'input,
->(
-    input: &'input str,
-    (_, name, _): (usize, String, usize),
-    (_, loc, _): (usize, ::std::option::Option<String>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, typ, _): (usize, Type, usize),
-    (_, default, _): (usize, ::std::option::Option<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> VarDef
-{
-    VarDef { name:name, loc:loc, typ:typ, default:default }
-}
-
-#[allow(unused_variables)]
-fn __action21<
-    'input,
->(
-    input: &'input str,
-    (_, exprs, _): (usize, Vec<Expr>, usize),
-) -> Expr
-{
-    {
-        if exprs.len() == 1 {
-            exprs.into_iter().next().unwrap()
-        } else {
-            Expr::List(exprs)
-        }
-    }
-}
-
-#[allow(unused_variables)]
-fn __action22<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> String
-{
-    String::from(__0)
-}
-
-#[allow(unused_variables)]
-fn __action23<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, l, _): (usize, (u16, i64), usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, u, _): (usize, (u16, i64), usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, inner, _): (usize, Type, usize),
-) -> Type
-{
-    Type::Array(box inner, l.1, u.1)
-}
-
-#[allow(unused_variables)]
-fn __action24<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, (u16, i64), usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Type
-{
-    Type::String(__0.1 as usize)
-}
-
-#[allow(unused_variables)]
-fn __action25<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Type
-{
-    Type::String(80)
-}
-
-#[allow(unused_variables)]
-fn __action26<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-) -> Type
-{
-    Type::Simple(__0)
-}
-
-#[allow(unused_variables)]
-fn __action27<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-    (_, __1, _): (usize, &'input str, usize),
-) -> Stmt
-{
-    Stmt::Exit
-}
-
-#[allow(unused_variables)]
-fn __action28<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, cond, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, then, _): (usize, ::std::vec::Vec<Stmt>, usize),
-    (_, else_, _): (usize, Vec<Stmt>, usize),
-) -> Stmt
-{
-    Stmt::If(box cond, then, else_)
-}
-
-#[allow(unused_variables)]
-fn __action29<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, head, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, cases, _): (usize, ::std::vec::Vec<Case>, usize),
-    (_, else_, _): (usize, Vec<Stmt>, usize),
-) -> Stmt
-{
-    Stmt::Case(box head, cases, else_)
-}
-
-#[allow(unused_variables)]
-fn __action30<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, cond, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, ::std::vec::Vec<Stmt>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Stmt
-{
-    Stmt::While(box cond, body)
-}
-
-#[allow(unused_variables)]
-fn __action31<
-    'input,
->(
-    input: &'input str,
-    (_, lval, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, rval, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Stmt
-{
-    Stmt::Assign(box lval, box rval)
-}
-
-#[allow(unused_variables)]
-fn __action32<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Stmt
-{
-    Stmt::Expr(box Expr::CallFB(__0, vec![]))
-}
-
-#[allow(unused_variables)]
-fn __action33<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Stmt
-{
-    Stmt::Expr(box __0)
-}
-
-#[allow(unused_variables)]
-fn __action34<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Stmt
-{
-    Stmt::Empty
-}
-
-#[allow(unused_variables)]
-fn __action35<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, cond, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, then, _): (usize, ::std::vec::Vec<Stmt>, usize),
-    (_, else_, _): (usize, Vec<Stmt>, usize),
-) -> Vec<Stmt>
-{
-    vec![Stmt::If(box cond, then, else_)]
-}
-
-#[allow(unused_variables)]
-fn __action36<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, ::std::vec::Vec<Stmt>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Vec<Stmt>
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action37<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Vec<Stmt>
-{
-    vec![]
-}
-
-#[allow(unused_variables)]
-fn __action38<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Vec<CaseExpr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, __1, _): (usize, ::std::vec::Vec<Stmt>, usize),
-) -> Case
-{
-    Case(__0, __1)
-}
-
-#[allow(unused_variables)]
-fn __action39<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, __1, _): (usize, Expr, usize),
-) -> CaseExpr
-{
-    CaseExpr::Range(__0, __1)
-}
-
-#[allow(unused_variables)]
-fn __action40<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> CaseExpr
-{
-    CaseExpr::Single(__0)
-}
-
-#[allow(unused_variables)]
-fn __action41<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, ::std::vec::Vec<Stmt>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Vec<Stmt>
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action42<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Vec<Stmt>
-{
-    vec![]
-}
-
-#[allow(unused_variables)]
-fn __action43<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, (u16, i64), usize),
-) -> Expr
-{
-    Expr::Lit(Lit::Int(__0.0, __0.1))
-}
-
-#[allow(unused_variables)]
-fn __action44<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, f64, usize),
-) -> Expr
-{
-    Expr::Lit(Lit::Float(__0))
-}
-
-#[allow(unused_variables)]
-fn __action45<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Expr
-{
-    Expr::Lit(Lit::Bool(true))
-}
-
-#[allow(unused_variables)]
-fn __action46<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Expr
-{
-    Expr::Lit(Lit::Bool(false))
-}
-
-#[allow(unused_variables)]
-fn __action47<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Expr
-{
-    Expr::Lit(Lit::Time(__0.into()))
-}
-
-#[allow(unused_variables)]
-fn __action48<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Expr
-{
-    Expr::Lit(Lit::Str(__0.into()))
-}
-
-#[allow(unused_variables)]
-fn __action49<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::Or, box right)
-}
-
-#[allow(unused_variables)]
-fn __action50<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::Or, box right)
-}
-
-#[allow(unused_variables)]
-fn __action51<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> Expr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action52<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::Xor, box right)
-}
-
-#[allow(unused_variables)]
-fn __action53<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> Expr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action54<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::And, box right)
-}
-
-#[allow(unused_variables)]
-fn __action55<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::And, box right)
-}
-
-#[allow(unused_variables)]
-fn __action56<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> Expr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action57<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::Eq, box right)
-}
-
-#[allow(unused_variables)]
-fn __action58<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::Neq, box right)
-}
-
-#[allow(unused_variables)]
-fn __action59<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> Expr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action60<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::Gt, box right)
-}
-
-#[allow(unused_variables)]
-fn __action61<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::Lt, box right)
-}
-
-#[allow(unused_variables)]
-fn __action62<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::Ge, box right)
-}
-
-#[allow(unused_variables)]
-fn __action63<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::Le, box right)
-}
-
-#[allow(unused_variables)]
-fn __action64<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> Expr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action65<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::Add, box right)
-}
-
-#[allow(unused_variables)]
-fn __action66<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::Sub, box right)
-}
-
-#[allow(unused_variables)]
-fn __action67<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> Expr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action68<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::Mul, box right)
-}
-
-#[allow(unused_variables)]
-fn __action69<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::Div, box right)
-}
-
-#[allow(unused_variables)]
-fn __action70<
-    'input,
->(
-    input: &'input str,
-    (_, left, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, right, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Binary(box left, BinOp::Mod, box right)
-}
-
-#[allow(unused_variables)]
-fn __action71<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> Expr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action72<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, expr, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Unary(UnOp::Neg, box expr)
-}
-
-#[allow(unused_variables)]
-fn __action73<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, expr, _): (usize, Expr, usize),
-) -> Expr
-{
-    Expr::Unary(UnOp::Neg, box expr)
-}
-
-#[allow(unused_variables)]
-fn __action74<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> Expr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action75<
-    'input,
->(
-    input: &'input str,
-    (_, expr, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, bit, _): (usize, i64, usize),
-) -> Expr
-{
-    Expr::Bit(box expr, bit as u16)
-}
-
-#[allow(unused_variables)]
-fn __action76<
-    'input,
->(
-    input: &'input str,
-    (_, expr, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, member, _): (usize, String, usize),
-) -> Expr
-{
-    Expr::Member(box expr, member)
-}
-
-#[allow(unused_variables)]
-fn __action77<
-    'input,
->(
-    input: &'input str,
-    (_, expr, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, index, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Expr
-{
-    Expr::Sub(box expr, box index)
-}
-
-#[allow(unused_variables)]
-fn __action78<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-) -> Expr
-{
-    Expr::Name(__0)
-}
-
-#[allow(unused_variables)]
-fn __action79<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, __1, _): (usize, Vec<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Expr
-{
-    Expr::Call(__0, __1)
-}
-
-#[allow(unused_variables)]
-fn __action80<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, __1, _): (usize, Vec<Kwarg>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Expr
-{
-    Expr::CallFB(__0, __1)
-}
-
-#[allow(unused_variables)]
-fn __action81<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Expr
-{
-    Expr::Call(__0, vec![])
-}
-
-#[allow(unused_variables)]
-fn __action82<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, __1, _): (usize, Expr, usize),
-) -> Kwarg
-{
-    Kwarg::In(__0, __1)
-}
-
-#[allow(unused_variables)]
-fn __action83<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Kwarg
-{
-    Kwarg::None(__0)
-}
-
-#[allow(unused_variables)]
-fn __action84<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, __1, _): (usize, Expr, usize),
-) -> Kwarg
-{
-    Kwarg::Out(__0, __1)
-}
-
-#[allow(unused_variables)]
-fn __action85<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Kwarg
-{
-    Kwarg::None(__0)
-}
-
-#[allow(unused_variables)]
-fn __action86<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> String
-{
-    String::from(__0)
-}
-
-#[allow(unused_variables)]
-fn __action87<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> (u16, i64)
-{
-    (16, i64::from_str_radix(&__0[3..], 16).unwrap())
-}
-
-#[allow(unused_variables)]
-fn __action88<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> (u16, i64)
-{
-    (8, i64::from_str_radix(&__0[2..], 8).unwrap())
-}
-
-#[allow(unused_variables)]
-fn __action89<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> (u16, i64)
-{
-    (2, i64::from_str_radix(&__0[2..], 2).unwrap())
-}
-
-#[allow(unused_variables)]
-fn __action90<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, i64, usize),
-) -> (u16, i64)
-{
-    (10, __0)
-}
-
-#[allow(unused_variables)]
-fn __action91<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> i64
-{
-    __0.parse().unwrap()
-}
-
-#[allow(unused_variables)]
-fn __action92<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> f64
-{
-    __0.parse().unwrap()
-}
-
-#[allow(unused_variables)]
-fn __action93<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> f64
-{
-    __0.parse().unwrap()
-}
-
-#[allow(unused_variables)]
-fn __action94<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> Expr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action95<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> Expr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action96<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> Expr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action97<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Expr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action98<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Vec<(String, Expr)>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Expr
-{
-    { let mut v = __0; v.reverse(); Expr::Initializer(v) }
-}
-
-#[allow(unused_variables)]
-fn __action99<
-    'input,
->(
-    input: &'input str,
-    (_, name, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, lits, _): (usize, ::std::vec::Vec<Expr>, usize),
-    (_, lit, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, more, _): (usize, Vec<(String, Expr)>, usize),
-) -> Vec<(String, Expr)>
-{
-    {
-        app(more, if lits.is_empty() {
-            (name, lit)
-        } else {
-            (name, Expr::List(app(lits, lit)))
-        })
-    }
-}
-
-#[allow(unused_variables)]
-fn __action100<
-    'input,
->(
-    input: &'input str,
-    (_, name, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, lits, _): (usize, ::std::vec::Vec<Expr>, usize),
-    (_, lit, _): (usize, Expr, usize),
-) -> Vec<(String, Expr)>
-{
-    {
-        if lits.is_empty() {
-            vec![(name, lit)]
-        } else {
-            vec![(name, Expr::List(app(lits, lit)))]
-        }
-    }
-}
-
-#[allow(unused_variables)]
-fn __action101<
-    'input,
->(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::vec::Vec<Expr>
-{
-    vec![]
-}
-
-#[allow(unused_variables)]
-fn __action102<
-    'input,
->(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<Expr>, usize),
-) -> ::std::vec::Vec<Expr>
-{
-    v
-}
-
-#[allow(unused_variables)]
-fn __action103<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Expr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action104<
-    'input,
->(
-    input: &'input str,
-    (_, xs, _): (usize, ::std::vec::Vec<Kwarg>, usize),
-    (_, x, _): (usize, Kwarg, usize),
-) -> Vec<Kwarg>
-{
-    app(xs, x)
-}
-
-#[allow(unused_variables)]
-fn __action105<
-    'input,
->(
-    input: &'input str,
-    (_, xs, _): (usize, ::std::vec::Vec<CaseExpr>, usize),
-    (_, x, _): (usize, CaseExpr, usize),
-) -> Vec<CaseExpr>
-{
-    app(xs, x)
-}
-
-#[allow(unused_variables)]
-fn __action106<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Case, usize),
-) -> ::std::vec::Vec<Case>
-{
-    vec![__0]
-}
-
-#[allow(unused_variables)]
-fn __action107<
-    'input,
->(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<Case>, usize),
-    (_, e, _): (usize, Case, usize),
-) -> ::std::vec::Vec<Case>
-{
-    { let mut v = v; v.push(e); v }
-}
-
-#[allow(unused_variables)]
-fn __action108<
-    'input,
->(
-    input: &'input str,
-    (_, xs, _): (usize, ::std::vec::Vec<Expr>, usize),
-    (_, x, _): (usize, Expr, usize),
-) -> Vec<Expr>
-{
-    app(xs, x)
-}
-
-#[allow(unused_variables)]
-fn __action109<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> ::std::option::Option<Expr>
-{
-    Some(__0)
-}
-
-#[allow(unused_variables)]
-fn __action110<
-    'input,
->(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::option::Option<Expr>
-{
-    None
-}
-
-#[allow(unused_variables)]
-fn __action111<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Expr, usize),
-) -> Expr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action112<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-) -> ::std::option::Option<String>
-{
-    Some(__0)
-}
-
-#[allow(unused_variables)]
-fn __action113<
-    'input,
->(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::option::Option<String>
-{
-    None
-}
-
-#[allow(unused_variables)]
-fn __action114<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, String, usize),
-) -> String
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action115<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> ::std::option::Option<&'input str>
-{
-    Some(__0)
-}
-
-#[allow(unused_variables)]
-fn __action116<
-    'input,
->(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::option::Option<&'input str>
-{
-    None
-}
-
-#[allow(unused_variables)]
-fn __action117<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> ::std::option::Option<&'input str>
-{
-    Some(__0)
-}
-
-#[allow(unused_variables)]
-fn __action118<
-    'input,
->(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::option::Option<&'input str>
-{
-    None
-}
-
-#[allow(unused_variables)]
-fn __action119<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Stmt, usize),
-) -> ::std::vec::Vec<Stmt>
-{
-    vec![__0]
-}
-
-#[allow(unused_variables)]
-fn __action120<
-    'input,
->(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<Stmt>, usize),
-    (_, e, _): (usize, Stmt, usize),
-) -> ::std::vec::Vec<Stmt>
-{
-    { let mut v = v; v.push(e); v }
-}
-
-#[allow(unused_variables)]
-fn __action121<
-    'input,
->(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    vec![]
-}
-
-#[allow(unused_variables)]
-fn __action122<
-    'input,
->(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    v
-}
-
-#[allow(unused_variables)]
-fn __action123<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, ::std::option::Option<&'input str>, usize),
-    (_, _, _): (usize, ::std::option::Option<&'input str>, usize),
-    (_, __0, _): (usize, ::std::vec::Vec<VarDef>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action124<
-    'input,
->(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::vec::Vec<VarDef>
-{
-    vec![]
-}
-
-#[allow(unused_variables)]
-fn __action125<
-    'input,
->(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<VarDef>, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    v
-}
-
-#[allow(unused_variables)]
-fn __action126<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> ::std::option::Option<&'input str>
-{
-    Some(__0)
-}
-
-#[allow(unused_variables)]
-fn __action127<
-    'input,
->(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::option::Option<&'input str>
-{
-    None
-}
-
-#[allow(unused_variables)]
-fn __action128<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> ::std::option::Option<&'input str>
-{
-    Some(__0)
-}
-
-#[allow(unused_variables)]
-fn __action129<
-    'input,
->(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::option::Option<&'input str>
-{
-    None
-}
-
-#[allow(unused_variables)]
-fn __action130<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, VarDef, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    vec![__0]
-}
-
-#[allow(unused_variables)]
-fn __action131<
-    'input,
->(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<VarDef>, usize),
-    (_, e, _): (usize, VarDef, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    { let mut v = v; v.push(e); v }
-}
-
-#[allow(unused_variables)]
-fn __action132<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> ::std::option::Option<&'input str>
-{
-    Some(__0)
-}
-
-#[allow(unused_variables)]
-fn __action133<
-    'input,
->(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::option::Option<&'input str>
-{
-    None
-}
-
-#[allow(unused_variables)]
-fn __action134<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, ::std::vec::Vec<VarDef>, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    vec![__0]
-}
-
-#[allow(unused_variables)]
-fn __action135<
-    'input,
->(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize),
-    (_, e, _): (usize, ::std::vec::Vec<VarDef>, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    { let mut v = v; v.push(e); v }
-}
-
-#[allow(unused_variables)]
-fn __action136<
-    'input,
->(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::vec::Vec<Expr>
-{
-    vec![]
-}
-
-#[allow(unused_variables)]
-fn __action137<
-    'input,
->(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<Expr>, usize),
-) -> ::std::vec::Vec<Expr>
-{
-    v
-}
-
-#[allow(unused_variables)]
-fn __action138<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Expr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action139<
-    'input,
->(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::vec::Vec<CaseExpr>
-{
-    vec![]
-}
-
-#[allow(unused_variables)]
-fn __action140<
-    'input,
->(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<CaseExpr>, usize),
-) -> ::std::vec::Vec<CaseExpr>
-{
-    v
-}
-
-#[allow(unused_variables)]
-fn __action141<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, CaseExpr, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> CaseExpr
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action142<
-    'input,
->(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::vec::Vec<Kwarg>
-{
-    vec![]
-}
-
-#[allow(unused_variables)]
-fn __action143<
-    'input,
->(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<Kwarg>, usize),
-) -> ::std::vec::Vec<Kwarg>
-{
-    v
-}
-
-#[allow(unused_variables)]
-fn __action144<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Kwarg, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Kwarg
-{
-    (__0)
-}
-
-#[allow(unused_variables)]
-fn __action145<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> ::std::vec::Vec<Expr>
-{
-    vec![__0]
-}
-
-#[allow(unused_variables)]
-fn __action146<
-    'input,
->(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<Expr>, usize),
-    (_, e, _): (usize, Expr, usize),
-) -> ::std::vec::Vec<Expr>
-{
-    { let mut v = v; v.push(e); v }
-}
-
-#[allow(unused_variables)]
-fn __action147<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Kwarg, usize),
-) -> ::std::vec::Vec<Kwarg>
-{
-    vec![__0]
-}
-
-#[allow(unused_variables)]
-fn __action148<
-    'input,
->(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<Kwarg>, usize),
-    (_, e, _): (usize, Kwarg, usize),
-) -> ::std::vec::Vec<Kwarg>
-{
-    { let mut v = v; v.push(e); v }
-}
-
-#[allow(unused_variables)]
-fn __action149<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, CaseExpr, usize),
-) -> ::std::vec::Vec<CaseExpr>
-{
-    vec![__0]
-}
-
-#[allow(unused_variables)]
-fn __action150<
-    'input,
->(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<CaseExpr>, usize),
-    (_, e, _): (usize, CaseExpr, usize),
-) -> ::std::vec::Vec<CaseExpr>
-{
-    { let mut v = v; v.push(e); v }
-}
-
-#[allow(unused_variables)]
-fn __action151<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> ::std::vec::Vec<Expr>
-{
-    vec![__0]
-}
-
-#[allow(unused_variables)]
-fn __action152<
-    'input,
->(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<Expr>, usize),
-    (_, e, _): (usize, Expr, usize),
-) -> ::std::vec::Vec<Expr>
-{
-    { let mut v = v; v.push(e); v }
-}
-
-#[allow(unused_variables)]
-fn __action153<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<VarDef>, usize),
-    __3: (usize, &'input str, usize),
-) -> POU
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action132(
-        input,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action6(
-        input,
-        __0,
-        __temp0,
-        __2,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action154<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, ::std::vec::Vec<VarDef>, usize),
-    __2: (usize, &'input str, usize),
-) -> POU
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action133(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action6(
-        input,
-        __0,
-        __temp0,
-        __1,
-        __2,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action155<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, Vec<VarBlock>, usize),
-    __3: (usize, ::std::vec::Vec<Stmt>, usize),
-    __4: (usize, &'input str, usize),
-) -> POU
-{
-    let __start0 = __4.0.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action115(
-        input,
-        __4,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action10(
-        input,
-        __0,
-        __1,
-        __2,
-        __3,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action156<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, Vec<VarBlock>, usize),
-    __3: (usize, ::std::vec::Vec<Stmt>, usize),
-) -> POU
-{
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action116(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action10(
-        input,
-        __0,
-        __1,
-        __2,
-        __3,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action157<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize),
-    __3: (usize, ::std::vec::Vec<Stmt>, usize),
-    __4: (usize, &'input str, usize),
-) -> POU
-{
-    let __start0 = __4.0.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action117(
-        input,
-        __4,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action9(
-        input,
-        __0,
-        __1,
-        __2,
-        __3,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action158<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize),
-    __3: (usize, ::std::vec::Vec<Stmt>, usize),
-) -> POU
-{
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action118(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action9(
-        input,
-        __0,
-        __1,
-        __2,
-        __3,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action159<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, ::std::option::Option<&'input str>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, ::std::vec::Vec<VarDef>, usize),
-    __4: (usize, &'input str, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action126(
-        input,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action123(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __3,
-        __4,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action160<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, ::std::option::Option<&'input str>, usize),
-    __2: (usize, ::std::vec::Vec<VarDef>, usize),
-    __3: (usize, &'input str, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action127(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action123(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __2,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action161<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, ::std::vec::Vec<VarDef>, usize),
-    __4: (usize, &'input str, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action128(
-        input,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action159(
-        input,
-        __0,
-        __temp0,
-        __2,
-        __3,
-        __4,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action162<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<VarDef>, usize),
-    __3: (usize, &'input str, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action129(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action159(
-        input,
-        __0,
-        __temp0,
-        __1,
-        __2,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action163<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<VarDef>, usize),
-    __3: (usize, &'input str, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action128(
-        input,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action160(
-        input,
-        __0,
-        __temp0,
-        __2,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action164<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, ::std::vec::Vec<VarDef>, usize),
-    __2: (usize, &'input str, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action129(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action160(
-        input,
-        __0,
-        __temp0,
-        __1,
-        __2,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action165<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Expr, usize),
-) -> ::std::option::Option<Expr>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action111(
-        input,
-        __0,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action109(
-        input,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action166<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, ::std::option::Option<String>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Type, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, Expr, usize),
-    __6: (usize, &'input str, usize),
-) -> VarDef
-{
-    let __start0 = __4.0.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action165(
-        input,
-        __4,
-        __5,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action20(
-        input,
-        __0,
-        __1,
-        __2,
-        __3,
-        __temp0,
-        __6,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action167<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, ::std::option::Option<String>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Type, usize),
-    __4: (usize, &'input str, usize),
-) -> VarDef
-{
-    let __start0 = __3.2.clone();
-    let __end0 = __4.0.clone();
-    let __temp0 = __action110(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action20(
-        input,
-        __0,
-        __1,
-        __2,
-        __3,
-        __temp0,
-        __4,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action168<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-) -> ::std::option::Option<String>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action114(
-        input,
-        __0,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action112(
-        input,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action169<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, String, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, Type, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Expr, usize),
-    __7: (usize, &'input str, usize),
-) -> VarDef
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action168(
-        input,
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action166(
-        input,
-        __0,
-        __temp0,
-        __3,
-        __4,
-        __5,
-        __6,
-        __7,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action170<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Type, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, Expr, usize),
-    __5: (usize, &'input str, usize),
-) -> VarDef
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action113(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action166(
-        input,
-        __0,
-        __temp0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __5,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action171<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, String, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, Type, usize),
-    __5: (usize, &'input str, usize),
-) -> VarDef
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action168(
-        input,
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action167(
-        input,
-        __0,
-        __temp0,
-        __3,
-        __4,
-        __5,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action172<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Type, usize),
-    __3: (usize, &'input str, usize),
-) -> VarDef
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action113(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action167(
-        input,
-        __0,
-        __temp0,
-        __1,
-        __2,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action173<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, &'input str, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    let __start0 = __2.2.clone();
-    let __end0 = __3.0.clone();
-    let __temp0 = __action124(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action161(
-        input,
-        __0,
-        __1,
-        __2,
-        __temp0,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action174<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, ::std::vec::Vec<VarDef>, usize),
-    __4: (usize, &'input str, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    let __start0 = __3.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action125(
-        input,
-        __3,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action161(
-        input,
-        __0,
-        __1,
-        __2,
-        __temp0,
-        __4,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action175<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action124(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action162(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __2,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action176<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<VarDef>, usize),
-    __3: (usize, &'input str, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action125(
-        input,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action162(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action177<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action124(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action163(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __2,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action178<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<VarDef>, usize),
-    __3: (usize, &'input str, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action125(
-        input,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action163(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action179<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action124(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action164(
-        input,
-        __0,
-        __temp0,
-        __1,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action180<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, ::std::vec::Vec<VarDef>, usize),
-    __2: (usize, &'input str, usize),
-) -> ::std::vec::Vec<VarDef>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action125(
-        input,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action164(
-        input,
-        __0,
-        __temp0,
-        __2,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action181<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Vec<VarBlock>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action124(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action11(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __2,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action182<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Vec<VarBlock>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<VarDef>, usize),
-    __3: (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action125(
-        input,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action11(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action183<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Vec<VarBlock>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action124(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action12(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __2,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action184<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Vec<VarBlock>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<VarDef>, usize),
-    __3: (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action125(
-        input,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action12(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action185<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Vec<VarBlock>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action124(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action13(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __2,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action186<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Vec<VarBlock>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<VarDef>, usize),
-    __3: (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action125(
-        input,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action13(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action187<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Vec<VarBlock>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action124(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action14(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __2,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action188<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Vec<VarBlock>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<VarDef>, usize),
-    __3: (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action125(
-        input,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action14(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action189<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Vec<VarBlock>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action124(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action17(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __2,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action190<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Vec<VarBlock>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<VarDef>, usize),
-    __3: (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action125(
-        input,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action17(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action191<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Vec<VarBlock>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action124(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action18(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __2,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action192<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Vec<VarBlock>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<VarDef>, usize),
-    __3: (usize, &'input str, usize),
-) -> Vec<VarBlock>
-{
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action125(
-        input,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action18(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action193<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action173(
-        input,
-        __0,
-        __1,
-        __2,
-        __3,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action134(
-        input,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action194<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, ::std::vec::Vec<VarDef>, usize),
-    __4: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action174(
-        input,
-        __0,
-        __1,
-        __2,
-        __3,
-        __4,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action134(
-        input,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action195<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action175(
-        input,
-        __0,
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action134(
-        input,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action196<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<VarDef>, usize),
-    __3: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action176(
-        input,
-        __0,
-        __1,
-        __2,
-        __3,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action134(
-        input,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action197<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action177(
-        input,
-        __0,
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action134(
-        input,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action198<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<VarDef>, usize),
-    __3: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action178(
-        input,
-        __0,
-        __1,
-        __2,
-        __3,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action134(
-        input,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action199<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action179(
-        input,
-        __0,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action134(
-        input,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action200<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, ::std::vec::Vec<VarDef>, usize),
-    __2: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action180(
-        input,
-        __0,
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action134(
-        input,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action201<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action173(
-        input,
-        __1,
-        __2,
-        __3,
-        __4,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action135(
-        input,
-        __0,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action202<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, ::std::vec::Vec<VarDef>, usize),
-    __5: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action174(
-        input,
-        __1,
-        __2,
-        __3,
-        __4,
-        __5,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action135(
-        input,
-        __0,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action203<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action175(
-        input,
-        __1,
-        __2,
-        __3,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action135(
-        input,
-        __0,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action204<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, ::std::vec::Vec<VarDef>, usize),
-    __4: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action176(
-        input,
-        __1,
-        __2,
-        __3,
-        __4,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action135(
-        input,
-        __0,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action205<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action177(
-        input,
-        __1,
-        __2,
-        __3,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action135(
-        input,
-        __0,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action206<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, ::std::vec::Vec<VarDef>, usize),
-    __4: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action178(
-        input,
-        __1,
-        __2,
-        __3,
-        __4,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action135(
-        input,
-        __0,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action207<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action179(
-        input,
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action135(
-        input,
-        __0,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action208<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<VarDef>, usize),
-    __3: (usize, &'input str, usize),
-) -> ::std::vec::Vec<::std::vec::Vec<VarDef>>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action180(
-        input,
-        __1,
-        __2,
-        __3,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action135(
-        input,
-        __0,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action209<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, ::std::vec::Vec<Stmt>, usize),
-    __3: (usize, &'input str, usize),
-) -> POU
-{
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action121(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action157(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __2,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action210<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize),
-    __3: (usize, ::std::vec::Vec<Stmt>, usize),
-    __4: (usize, &'input str, usize),
-) -> POU
-{
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action122(
-        input,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action157(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __3,
-        __4,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action211<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, ::std::vec::Vec<Stmt>, usize),
-) -> POU
-{
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action121(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action158(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __2,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action212<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, ::std::vec::Vec<::std::vec::Vec<VarDef>>, usize),
-    __3: (usize, ::std::vec::Vec<Stmt>, usize),
-) -> POU
-{
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action122(
-        input,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action158(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __3,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action213<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Kwarg, usize),
-    __1: (usize, &'input str, usize),
-) -> ::std::vec::Vec<Kwarg>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action144(
-        input,
-        __0,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action147(
-        input,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action214<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, ::std::vec::Vec<Kwarg>, usize),
-    __1: (usize, Kwarg, usize),
-    __2: (usize, &'input str, usize),
-) -> ::std::vec::Vec<Kwarg>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action144(
-        input,
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action148(
-        input,
-        __0,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action215<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Kwarg, usize),
-) -> Vec<Kwarg>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action142(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action104(
-        input,
-        __temp0,
-        __0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action216<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, ::std::vec::Vec<Kwarg>, usize),
-    __1: (usize, Kwarg, usize),
-) -> Vec<Kwarg>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action143(
-        input,
-        __0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action104(
-        input,
-        __temp0,
-        __1,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action217<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, CaseExpr, usize),
-    __1: (usize, &'input str, usize),
-) -> ::std::vec::Vec<CaseExpr>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action141(
-        input,
-        __0,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action149(
-        input,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action218<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, ::std::vec::Vec<CaseExpr>, usize),
-    __1: (usize, CaseExpr, usize),
-    __2: (usize, &'input str, usize),
-) -> ::std::vec::Vec<CaseExpr>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action141(
-        input,
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action150(
-        input,
-        __0,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action219<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, CaseExpr, usize),
-) -> Vec<CaseExpr>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action139(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action105(
-        input,
-        __temp0,
-        __0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action220<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, ::std::vec::Vec<CaseExpr>, usize),
-    __1: (usize, CaseExpr, usize),
-) -> Vec<CaseExpr>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action140(
-        input,
-        __0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action105(
-        input,
-        __temp0,
-        __1,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action221<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Expr, usize),
-    __1: (usize, &'input str, usize),
-) -> ::std::vec::Vec<Expr>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action138(
-        input,
-        __0,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action151(
-        input,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action222<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, ::std::vec::Vec<Expr>, usize),
-    __1: (usize, Expr, usize),
-    __2: (usize, &'input str, usize),
-) -> ::std::vec::Vec<Expr>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action138(
-        input,
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action152(
-        input,
-        __0,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action223<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Expr, usize),
-) -> Vec<Expr>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action136(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action108(
-        input,
-        __temp0,
-        __0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action224<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, ::std::vec::Vec<Expr>, usize),
-    __1: (usize, Expr, usize),
-) -> Vec<Expr>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action137(
-        input,
-        __0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action108(
-        input,
-        __temp0,
-        __1,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action225<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, Expr, usize),
-    __1: (usize, &'input str, usize),
-) -> ::std::vec::Vec<Expr>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action103(
-        input,
-        __0,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action145(
-        input,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action226<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, ::std::vec::Vec<Expr>, usize),
-    __1: (usize, Expr, usize),
-    __2: (usize, &'input str, usize),
-) -> ::std::vec::Vec<Expr>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action103(
-        input,
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action146(
-        input,
-        __0,
-        __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action227<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Expr, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, Vec<(String, Expr)>, usize),
-) -> Vec<(String, Expr)>
-{
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action101(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action99(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __2,
-        __3,
-        __4,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action228<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<Expr>, usize),
-    __3: (usize, Expr, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, Vec<(String, Expr)>, usize),
-) -> Vec<(String, Expr)>
-{
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action102(
-        input,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action99(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __3,
-        __4,
-        __5,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action229<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Expr, usize),
-) -> Vec<(String, Expr)>
-{
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action101(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action100(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __2,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action230<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<Expr>, usize),
-    __3: (usize, Expr, usize),
-) -> Vec<(String, Expr)>
-{
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action102(
-        input,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action100(
-        input,
-        __0,
-        __1,
-        __temp0,
-        __3,
-    )
-}
-
-pub trait __ToTriple<'input, > {
-    type Error;
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
-}
-
-impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
-    type Error = &'static str;
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
-        Ok(value)
-    }
-}
-impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
-    type Error = &'static str;
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
-        value
-    }
-}
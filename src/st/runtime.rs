@@ -24,106 +24,495 @@
 #![allow(dead_code, unused_variables)]
 
 use std::collections::HashMap;
-use byteorder::{LE, ByteOrder};
+use std::time::Duration;
+use failure::Error;
+
+use st::ast;
+pub use st::value::{Value, ValType};
+pub use st::symbols::{SymbolTable, Location};
 
 
 /// Represents a whole PLC runtime.
 pub struct Runtime {
+    program: Program,
+    memory: Box<[u8]>,
     tasks: Vec<Task>,
+    /// Simulated time since startup.
+    now: Duration,
 }
 
 pub type Var = usize;
 pub type Func = usize;
 
+/// Configuration of a PLC task, as read from the project.
+pub type TaskConfig = ast::Task;
+
 /// Represents a single PLC task at runtime.
 pub struct Task {
-    program: Program,
-    stack: Vec<Data>,
-    memory: Box<[u8]>,
+    config: TaskConfig,
+    entries: Vec<Func>,
+    stack: Vec<Value>,
+    cycles: u64,
+    next_run: Duration,
 }
 
 /// Representation of a PLC program (collection of functions).
 pub struct Program {
-    vars: Vec<VarAlloc>,
-    functions: Vec<Function>,
-    var_names: HashMap<Var, String>,
-    func_names: HashMap<Func, String>,
+    pub(crate) vars: Vec<VarAlloc>,
+    pub(crate) functions: Vec<Function>,
+    pub(crate) var_names: HashMap<Var, String>,
+    pub(crate) func_names: HashMap<Func, String>,
+    /// Function that sets up initial values, run once at startup.
+    pub(crate) init: Func,
+    /// Functions that implement PROGRAMs, which can be called by tasks.
+    pub(crate) programs: Vec<Func>,
+    pub(crate) symbols: SymbolTable,
+    /// Method implementations by class id and method number.
+    pub(crate) methods: HashMap<(u32, usize), Func>,
+    /// Memory ranges (start and length) of RETAIN variables.
+    pub(crate) retain: Vec<(usize, usize)>,
+    /// Memory ranges (start and length) of PERSISTENT variables.
+    pub(crate) persistent: Vec<(usize, usize)>,
+    /// Total size of task memory needed.
+    pub(crate) memsize: usize,
+}
+
+/// Kinds of reset, which differ in the variables that keep their values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reset {
+    /// RETAIN and PERSISTENT variables keep their values.
+    Warm,
+    /// Only PERSISTENT variables keep their values.
+    Cold,
+    /// All variables get their initial values.
+    Origin,
 }
 
 /// Representation of a PLC function (block).
 pub struct Function {
     pub code: Vec<Instr>,
+    /// Base address for code that is not called on an instance.
+    pub frame: usize,
 }
 
 /// A variable allocation.
 pub struct VarAlloc {
     pub offset: usize,
-    pub size: usize,
+    pub typ: ValType,
 }
 
-/// A piece of data.
-pub struct Data(u32);
+/// Implementation of a binary operation.
+pub type BinOpFn = fn(Value, Value) -> Result<Value, Error>;
+/// Implementation of a unary operation.
+pub type UnOpFn = fn(Value) -> Result<Value, Error>;
 
 /// An instruction.
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub enum Instr {
+    /// Push a constant.
+    Const(Value),
+    /// Push the base address of the current instance.
+    Base,
     Store(Var),
     StoreBit(Var, usize),
     Load(Var),
     LoadBit(Var, usize),
-    BinOp(fn(Data, Data) -> Data),
-    UnOp(fn(Data) -> Data),
+    /// Like the non-indirect versions, but the variable offset is relative
+    /// to an address popped from the stack.
+    StoreInd(Var),
+    StoreBitInd(Var, usize),
+    LoadInd(Var),
+    LoadBitInd(Var, usize),
+    Dup,
+    Pop,
+    Swap,
+    /// Convert the value on top of the stack.
+    Conv(ValType),
+    /// Pop an array index and an address, and push the address of the
+    /// element (with given lower and upper bound and element size).
+    Index(i64, i64, usize),
+    /// Check that the integer on top of the stack is within the given bounds,
+    /// for storing it in a subrange variable.
+    CheckRange(i64, i64),
+    BinOp(BinOpFn),
+    UnOp(UnOpFn),
     Jump(usize),
     JumpIf(usize),
+    JumpIfNot(usize),
+    /// Call a function with its static frame as base.
     Call(Func),
+    /// Call a function block body on the instance address popped from the stack.
+    CallInst(Func),
+    /// Call a method on the instance address popped from the stack, selecting
+    /// the implementation by the class id stored at the start of the instance.
+    CallMethod(usize),
     Return,
 }
 
+impl Runtime {
+    /// Create a new runtime for the given program and task configuration.
+    ///
+    /// The initial values of all variables are set up immediately.
+    pub fn new(program: Program, config: &[TaskConfig]) -> Result<Runtime, Error> {
+        let mut tasks = Vec::with_capacity(config.len());
+        for task in config {
+            let mut entries = vec![];
+            for name in &task.programs {
+                match program.find_program(name) {
+                    Some(func) => entries.push(func),
+                    None => bail!("Program {} for task {} not found", name, task.name),
+                }
+            }
+            if task.cycle_time == Duration::new(0, 0) {
+                bail!("Task {} has no cycle time", task.name);
+            }
+            tasks.push(Task { config: task.clone(), entries, stack: vec![],
+                              cycles: 0, next_run: Duration::new(0, 0) });
+        }
+        let memory = vec![0; program.memsize].into_boxed_slice();
+        let mut rt = Runtime { program, memory, tasks, now: Duration::new(0, 0) };
+        rt.init()?;
+        Ok(rt)
+    }
+
+    /// Set up the initial values of all variables in zeroed memory.
+    fn init(&mut self) -> Result<(), Error> {
+        let init = self.program.init;
+        Machine { program: &self.program, memory: &mut self.memory, stack: &mut vec![] }
+            .run_function(init, 0)
+    }
+
+    /// Reset the variables to their initial values, except for those that
+    /// are retained by this kind of reset.  All tasks are due to run next.
+    pub fn reset(&mut self, kind: Reset) -> Result<(), Error> {
+        let kept = match kind {
+            Reset::Warm => self.program.retain.iter().chain(&self.program.persistent)
+                                                      .cloned().collect(),
+            Reset::Cold => self.program.persistent.clone(),
+            Reset::Origin => vec![],
+        };
+        let saved = kept.into_iter()
+                        .map(|(start, len)| (start, self.memory[start..][..len].to_vec()))
+                        .collect::<Vec<_>>();
+        for byte in self.memory.iter_mut() {
+            *byte = 0;
+        }
+        self.init()?;
+        for (start, data) in saved {
+            self.memory[start..][..data.len()].copy_from_slice(&data);
+        }
+        for task in &mut self.tasks {
+            task.stack.clear();
+            task.next_run = self.now;
+        }
+        Ok(())
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    pub fn tasks(&self) -> &[Task] {
+        &self.tasks
+    }
+
+    /// Find the index of a task by name, which is case-insensitive.
+    pub fn task_index(&self, name: &str) -> Option<usize> {
+        self.tasks.iter().position(|t| t.config.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Simulated time since startup.
+    pub fn now(&self) -> Duration {
+        self.now
+    }
+
+    /// Resolve a symbolic variable path like `MAIN.fb.stStatus.bError` or
+    /// `GVL.aValues[3]` to a memory location.
+    pub fn lookup(&self, path: &str) -> Result<Location, Error> {
+        let loc = self.program.symbols.resolve(path, &self.memory)?;
+        if loc.offset + loc.typ.size() > self.memory.len() {
+            bail!("Invalid memory access at {}", loc.offset);
+        }
+        Ok(loc)
+    }
+
+    /// Read the current value of a variable.
+    ///
+    /// Structured values are returned as raw bytes.
+    pub fn read(&self, path: &str) -> Result<Value, Error> {
+        let loc = self.lookup(path)?;
+        let value = loc.typ.load(&self.memory[loc.offset..]);
+        match loc.bit {
+            Some(bit) => value.bit(bit),
+            None => Ok(value),
+        }
+    }
+
+    /// Write a new value to a variable.
+    ///
+    /// The value is converted to the type of the variable if necessary.
+    pub fn write(&mut self, path: &str, value: Value) -> Result<(), Error> {
+        let loc = self.lookup(path)?;
+        let value = match loc.bit {
+            Some(bit) => {
+                let b = value.as_bool()?;
+                loc.typ.load(&self.memory[loc.offset..]).set_bit(bit, b)?
+            }
+            None => match loc.typ {
+                ValType::Raw(_) => value,
+                typ => value.convert(typ)?,
+            }
+        };
+        value.store(loc.typ, &mut self.memory[loc.offset..])
+    }
+
+    /// Run a single cycle of the given task, regardless of its cycle time.
+    pub fn run_task(&mut self, idx: usize) -> Result<(), Error> {
+        let task = &mut self.tasks[idx];
+        for &entry in &task.entries {
+            task.stack.clear();
+            Machine { program: &self.program, memory: &mut self.memory, stack: &mut task.stack }
+                .run_function(entry, self.program.functions[entry].frame)
+                .map_err(|e| format_err!("Error in task {}: {}", task.config.name, e))?;
+        }
+        task.cycles += 1;
+        Ok(())
+    }
+
+    /// Run a single cycle of every task, in order of priority.
+    pub fn run_cycle(&mut self) -> Result<(), Error> {
+        for idx in self.priority_order() {
+            self.run_task(idx)?;
+        }
+        Ok(())
+    }
+
+    /// Advance the simulated time, running each task whenever its cycle is due.
+    pub fn run_for(&mut self, duration: Duration) -> Result<(), Error> {
+        let end = self.now + duration;
+        loop {
+            let next = match self.tasks.iter().map(|t| t.next_run).min() {
+                Some(next) if next < end => next,
+                _ => break,
+            };
+            self.now = next;
+            for idx in self.priority_order() {
+                if self.tasks[idx].next_run == next {
+                    self.run_task(idx)?;
+                    let task = &mut self.tasks[idx];
+                    task.next_run = next + task.config.cycle_time;
+                }
+            }
+        }
+        self.now = end;
+        Ok(())
+    }
+
+    fn priority_order(&self) -> Vec<usize> {
+        let mut order = (0..self.tasks.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| self.tasks[i].config.priority);
+        order
+    }
+}
+
 impl Task {
-    pub fn run_cycle(&mut self) {
-        self.run_function(0)
+    pub fn name(&self) -> &str {
+        &self.config.name
+    }
+
+    pub fn config(&self) -> &TaskConfig {
+        &self.config
+    }
+
+    /// Number of cycles run so far.
+    pub fn cycles(&self) -> u64 {
+        self.cycles
+    }
+}
+
+impl Program {
+    /// The table of symbols accessible from outside.
+    pub fn symbols(&self) -> &SymbolTable {
+        &self.symbols
+    }
+
+    /// Find a PROGRAM that can be called by a task.
+    pub fn find_program(&self, name: &str) -> Option<Func> {
+        self.programs.iter().cloned().find(|f| self.func_names[f].eq_ignore_ascii_case(name))
+    }
+}
+
+/// Executes code on the memory of a runtime.
+struct Machine<'a> {
+    program: &'a Program,
+    memory: &'a mut [u8],
+    stack: &'a mut Vec<Value>,
+}
+
+impl<'a> Machine<'a> {
+    fn pop(&mut self) -> Value {
+        self.stack.pop().expect("stack underflow")
+    }
+
+    fn pop_addr(&mut self) -> Result<usize, Error> {
+        match self.pop() {
+            Value::ULInt(0) => bail!("Null pointer dereference"),
+            Value::ULInt(addr) => Ok(addr as usize),
+            v => bail!("Invalid address {:?}", v),
+        }
+    }
+
+    fn load(&self, var: Var, base: usize) -> Result<Value, Error> {
+        let alloc = &self.program.vars[var];
+        let offset = base + alloc.offset;
+        if offset + alloc.typ.size() > self.memory.len() {
+            bail!("Invalid memory access at {}", offset);
+        }
+        Ok(alloc.typ.load(&self.memory[offset..]))
     }
 
-    fn run_function(&mut self, idx: usize) {
+    fn store(&mut self, var: Var, base: usize, value: Value) -> Result<(), Error> {
+        let alloc = &self.program.vars[var];
+        let offset = base + alloc.offset;
+        if offset + alloc.typ.size() > self.memory.len() {
+            bail!("Invalid memory access at {}", offset);
+        }
+        value.store(alloc.typ, &mut self.memory[offset..])
+    }
+
+    fn run_function(&mut self, idx: usize, base: usize) -> Result<(), Error> {
+        let program = self.program;
+        let code = &program.functions[idx].code;
         let mut pc = 0;
         loop {
-            let instr = self.program.functions[idx].code[pc];
-            match instr {
+            match code[pc] {
+                Instr::Const(ref v) => {
+                    self.stack.push(v.clone());
+                }
+                Instr::Base => {
+                    self.stack.push(Value::ULInt(base as u64));
+                }
                 Instr::Load(var) => {
-                    self.stack.push(self.program.vars[var].load(&self.memory));
+                    let v = self.load(var, 0)?;
+                    self.stack.push(v);
                 }
                 Instr::LoadBit(var, bit) => {
-                    let mut v = self.program.vars[var].load(&self.memory);
-                    self.stack.push(v.bit(bit));
+                    let v = self.load(var, 0)?;
+                    self.stack.push(v.bit(bit)?);
                 }
                 Instr::Store(var) => {
-                    let v = self.stack.pop().unwrap();
-                    self.program.vars[var].store(&mut self.memory, v);
+                    let v = self.pop();
+                    self.store(var, 0, v)?;
                 }
                 Instr::StoreBit(var, bit) => {
-                    let b = self.stack.pop().unwrap();
-                    let mut v = self.program.vars[var].load(&self.memory);
-                    v.set_bit(bit, b);
-                    self.program.vars[var].store(&mut self.memory, v);
+                    let b = self.pop().as_bool()?;
+                    let v = self.load(var, 0)?.set_bit(bit, b)?;
+                    self.store(var, 0, v)?;
+                }
+                Instr::LoadInd(var) => {
+                    let addr = self.pop_addr()?;
+                    let v = self.load(var, addr)?;
+                    self.stack.push(v);
+                }
+                Instr::LoadBitInd(var, bit) => {
+                    let addr = self.pop_addr()?;
+                    let v = self.load(var, addr)?;
+                    self.stack.push(v.bit(bit)?);
+                }
+                Instr::StoreInd(var) => {
+                    let v = self.pop();
+                    let addr = self.pop_addr()?;
+                    self.store(var, addr, v)?;
+                }
+                Instr::StoreBitInd(var, bit) => {
+                    let b = self.pop().as_bool()?;
+                    let addr = self.pop_addr()?;
+                    let v = self.load(var, addr)?.set_bit(bit, b)?;
+                    self.store(var, addr, v)?;
+                }
+                Instr::Dup => {
+                    let v = self.stack.last().expect("stack underflow").clone();
+                    self.stack.push(v);
+                }
+                Instr::Pop => {
+                    self.pop();
+                }
+                Instr::Swap => {
+                    let n = self.stack.len();
+                    self.stack.swap(n - 1, n - 2);
+                }
+                Instr::Conv(typ) => {
+                    let v = self.pop().convert(typ)?;
+                    self.stack.push(v);
+                }
+                Instr::Index(lo, hi, size) => {
+                    let index = match self.pop().as_int() {
+                        Some(i) => i,
+                        None => bail!("Invalid array index"),
+                    };
+                    if index < lo as i128 || index > hi as i128 {
+                        bail!("Array index {} out of bounds {}..{}", index, lo, hi);
+                    }
+                    let addr = self.pop_addr()?;
+                    let addr = addr + (index - lo as i128) as usize * size;
+                    self.stack.push(Value::ULInt(addr as u64));
+                }
+                Instr::CheckRange(lo, hi) => {
+                    match self.stack.last().and_then(Value::as_int) {
+                        Some(i) if i >= lo as i128 && i <= hi as i128 => {}
+                        Some(i) => bail!("Value {} out of subrange {}..{}", i, lo, hi),
+                        None => bail!("Invalid value for subrange"),
+                    }
                 }
                 Instr::BinOp(func) => {
-                    let v = self.stack.pop().unwrap();
-                    let w = self.stack.pop().unwrap();
-                    self.stack.push(func(v, w));
+                    let w = self.pop();
+                    let v = self.pop();
+                    self.stack.push(func(v, w)?);
                 }
                 Instr::UnOp(func) => {
-                    let v = self.stack.pop().unwrap();
-                    self.stack.push(func(v));
+                    let v = self.pop();
+                    self.stack.push(func(v)?);
                 }
                 Instr::Jump(new_pc) => {
                     pc = new_pc;
                     continue;
                 }
-                Instr::Call(fb) => {
-                    self.run_function(fb);
+                Instr::JumpIf(new_pc) => {
+                    if self.pop().as_bool()? {
+                        pc = new_pc;
+                        continue;
+                    }
+                }
+                Instr::JumpIfNot(new_pc) => {
+                    if !self.pop().as_bool()? {
+                        pc = new_pc;
+                        continue;
+                    }
+                }
+                Instr::Call(func) => {
+                    let frame = program.functions[func].frame;
+                    self.run_function(func, frame)?;
+                }
+                Instr::CallInst(fb) => {
+                    let addr = self.pop_addr()?;
+                    self.run_function(fb, addr)?;
+                }
+                Instr::CallMethod(method) => {
+                    let addr = self.pop_addr()?;
+                    if addr + 4 > self.memory.len() {
+                        bail!("Invalid memory access at {}", addr);
+                    }
+                    let func = match ValType::UDInt.load(&self.memory[addr..]) {
+                        Value::UDInt(class) => program.methods.get(&(class, method)).cloned(),
+                        _ => None,
+                    };
+                    match func {
+                        Some(func) => self.run_function(func, addr)?,
+                        None => bail!("Method not implemented by instance at {}", addr),
+                    }
                 }
                 Instr::Return => {
-                    return;
+                    return Ok(());
                 }
             }
             pc += 1;
@@ -131,36 +520,60 @@ impl Task {
     }
 }
 
-impl VarAlloc {
-    fn load(&self, mem: &[u8]) -> Data {
-        Data(match self.size {
-            1 => mem[self.offset] as u32,
-            2 => LE::read_u16(&mem[self.offset..]) as u32,
-            4 => LE::read_u32(&mem[self.offset..]),
-            _ => panic!("Variable too large to load")
-        })
-    }
-
-    fn store(&self, mem: &mut [u8], data: Data) {
-        match self.size {
-            1 => mem[self.offset] = data.0 as u8,
-            2 => LE::write_u16(&mut mem[self.offset..], data.0 as u16),
-            4 => LE::write_u32(&mut mem[self.offset..], data.0),
-            _ => panic!("Variable too large to store")
-        }
-    }
-}
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use st::compile::tests::compile;
+    use super::*;
+
+    #[test]
+    fn run_tasks() {
+        let source = "PROGRAM MAIN VAR n : INT; END_VAR n := n + 1; END_PROGRAM";
+        let task = |program: &str, millis, priority| TaskConfig {
+            name: format!("{}Task", program), cycle_time: Duration::from_millis(millis),
+            priority, programs: vec![program.into()]
+        };
+        let program = compile(&[source, "PROGRAM Slow VAR n : INT; END_VAR n := n + 1; \
+                                         END_PROGRAM"]).unwrap();
+        let mut rt = Runtime::new(program, &[task("MAIN", 10, 20), task("Slow", 25, 10)])
+            .unwrap();
+        assert_eq!(rt.task_index("SlowTask"), Some(1));
+        assert_eq!(rt.task_index("Other"), None);
+        rt.run_task(0).unwrap();
+        rt.run_cycle().unwrap();
+        assert_eq!(rt.tasks()[0].cycles(), 2);
+        assert_eq!(rt.tasks()[1].cycles(), 1);
+        assert_eq!(rt.tasks()[0].name(), "MAINTask");
+        // the first cycle runs at time 0
+        rt.run_for(Duration::from_millis(30)).unwrap();
+        assert_eq!(rt.tasks()[0].cycles(), 5);
+        assert_eq!(rt.tasks()[1].cycles(), 3);
+        assert_eq!(rt.now(), Duration::from_millis(30));
 
-impl Data {
-    fn bit(&self, bit: usize) -> Data {
-        Data((self.0 >> bit) & 1)
+        let err = Runtime::new(compile(&[source]).unwrap(), &[task("Other", 10, 20)])
+            .err().unwrap();
+        assert_eq!(err.to_string(), "Program Other for task OtherTask not found");
+        let err = Runtime::new(compile(&[source]).unwrap(), &[task("MAIN", 0, 20)])
+            .err().unwrap();
+        assert_eq!(err.to_string(), "Task MAINTask has no cycle time");
     }
 
-    fn set_bit(&mut self, bit: usize, val: Data) {
-        if val.0 != 0 {
-            *self = Data(self.0 | (1 << bit));
-        } else {
-            *self = Data(self.0 & !(1 << bit));
-        }
+    #[test]
+    fn tasks_from_project() {
+        let program = compile(&["PROGRAM Fast VAR n : INT; END_VAR n := n + 1; END_PROGRAM",
+                                "PROGRAM Slow VAR n : INT; END_VAR n := n + 1; END_PROGRAM"])
+            .unwrap();
+        let tasks = vec![
+            ast::Task { name: "FastTask".into(), cycle_time: Duration::from_millis(1),
+                        priority: 10, programs: vec!["Fast".into()] },
+            ast::Task { name: "SlowTask".into(), cycle_time: Duration::from_millis(10),
+                        priority: 20, programs: vec!["Slow".into()] },
+        ];
+        let mut rt = Runtime::new(program, &tasks).unwrap();
+        assert_eq!(rt.task_index("slowtask"), Some(1));
+        assert_eq!(rt.task_index("Other"), None);
+        rt.run_for(Duration::from_millis(10)).unwrap();
+        assert_eq!(rt.read("Fast.n").unwrap(), Value::Int(10));
+        assert_eq!(rt.read("Slow.n").unwrap(), Value::Int(1));
     }
 }
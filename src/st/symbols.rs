@@ -0,0 +1,270 @@
+// *****************************************************************************
+// Charon: Beckhoff TwinCat/ST testing and simulation tools
+// Copyright (c) 2017 by the contributors (see AUTHORS)
+//
+// This program is free software; you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation; either version 2 of the License, or (at your option) any later
+// version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE.  See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program; if not, write to the Free Software Foundation, Inc.,
+// 59 Temple Place, Suite 330, Boston, MA  02111-1307  USA
+//
+// Module authors:
+//   Georg Brandl <g.brandl@fz-juelich.de>
+//
+// *****************************************************************************
+
+//! Symbol table for accessing variables by name from outside the PLC program.
+
+use std::collections::HashMap;
+use byteorder::{ByteOrder, LE};
+use failure::Error;
+
+use st::value::ValType;
+
+/// Type of a symbol, as far as needed to resolve accesses.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SymType {
+    /// An elementary value.
+    Value(ValType),
+    /// An array with element type, bounds and element size.
+    Array(Box<SymType>, i64, i64, usize),
+    /// A struct or function block instance, with the name of the type.
+    Struct(String),
+    /// A reference, stored as an address (VAR_IN_OUT).
+    Ref(Box<SymType>),
+}
+
+/// A member of a structured type.
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub name: String,
+    pub offset: usize,
+    pub typ: SymType,
+}
+
+/// The resolved memory location of a symbol.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Location {
+    pub offset: usize,
+    pub typ: ValType,
+    /// For bit accesses, the bit number within the value.
+    pub bit: Option<usize>,
+}
+
+/// Maps symbolic names like `MAIN.fb.stStatus.bError` to memory locations.
+///
+/// The first component of a path is a root symbol: a PROGRAM, a FUNCTION's
+/// static frame or a global variable list, which are all treated like
+/// structured values.
+#[derive(Default)]
+pub struct SymbolTable {
+    roots: HashMap<String, (usize, SymType)>,
+    types: HashMap<String, Vec<Member>>,
+}
+
+/// One component of a symbol path.
+#[derive(Debug, PartialEq)]
+enum Component<'a> {
+    Name(&'a str),
+    Index(i64),
+    Bit(usize),
+}
+
+impl SymbolTable {
+    pub(crate) fn add_root(&mut self, name: &str, offset: usize, typ: SymType) {
+        self.roots.insert(name.into(), (offset, typ));
+    }
+
+    pub(crate) fn add_type(&mut self, name: &str, members: Vec<Member>) {
+        self.types.insert(name.into(), members);
+    }
+
+    /// Names of all root symbols.
+    pub fn roots(&self) -> Vec<&str> {
+        let mut names = self.roots.keys().map(|k| &k[..]).collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    fn root(&self, name: &str) -> Option<&(usize, SymType)> {
+        self.roots.iter().find(|r| r.0.eq_ignore_ascii_case(name)).map(|r| r.1)
+    }
+
+    /// Members of a structured type.
+    pub fn members(&self, typ: &str) -> Option<&[Member]> {
+        self.types.get(typ).map(|m| &m[..])
+    }
+
+    /// Resolve a path to a memory location.  Names are case-insensitive.
+    ///
+    /// The memory is needed to follow references.
+    pub fn resolve(&self, path: &str, memory: &[u8]) -> Result<Location, Error> {
+        let components = parse_path(path)?;
+        let (mut offset, mut typ) = match components[0] {
+            Component::Name(name) => match self.root(name) {
+                Some(&(offset, ref typ)) => (offset, typ),
+                None => bail!("Unknown symbol {}", name),
+            },
+            _ => bail!("Invalid symbol path {}", path),
+        };
+        let mut bit = None;
+        for comp in &components[1..] {
+            let (o, t) = deref(path, offset, typ, memory)?;
+            offset = o;
+            typ = t;
+            if bit.is_some() {
+                bail!("Invalid access after bit access in {}", path);
+            }
+            match (comp, typ) {
+                (&Component::Name(name), SymType::Struct(sname)) => {
+                    let members = match self.types.get(sname) {
+                        Some(members) => members,
+                        None => bail!("Unknown type {}", sname),
+                    };
+                    match members.iter().find(|m| m.name.eq_ignore_ascii_case(name)) {
+                        Some(member) => {
+                            offset += member.offset;
+                            typ = &member.typ;
+                        }
+                        None => bail!("{} has no member {}", sname, name),
+                    }
+                }
+                (&Component::Index(i), &SymType::Array(ref inner, lo, hi, size)) => {
+                    if i < lo || i > hi {
+                        bail!("Array index {} out of bounds {}..{}", i, lo, hi);
+                    }
+                    offset += (i - lo) as usize * size;
+                    typ = inner;
+                }
+                (&Component::Bit(b), &SymType::Value(vt)) if vt.is_integer() => {
+                    if b >= 8 * vt.size() {
+                        bail!("Bit {} out of range for {:?}", b, vt);
+                    }
+                    bit = Some(b);
+                }
+                (comp, typ) => bail!("Invalid access {:?} on value of type {:?}", comp, typ),
+            }
+        }
+        let (offset, typ) = deref(path, offset, typ, memory)?;
+        let typ = match *typ {
+            SymType::Value(vt) => vt,
+            _ => ValType::Raw(self.size(typ)?),
+        };
+        Ok(Location { offset, typ, bit })
+    }
+
+    fn size(&self, typ: &SymType) -> Result<usize, Error> {
+        Ok(match *typ {
+            SymType::Value(vt) => vt.size(),
+            SymType::Array(_, lo, hi, size) => (hi - lo + 1) as usize * size,
+            SymType::Ref(_) => 8,
+            SymType::Struct(ref name) => match self.types.get(name) {
+                Some(members) => {
+                    let mut size = 0;
+                    for member in members {
+                        size = size.max(member.offset + self.size(&member.typ)?);
+                    }
+                    size
+                }
+                None => bail!("Unknown type {}", name),
+            },
+        })
+    }
+}
+
+/// Follow references, which are followed implicitly when accessing symbols.
+fn deref<'a>(path: &str, mut offset: usize, mut typ: &'a SymType, memory: &[u8])
+             -> Result<(usize, &'a SymType), Error> {
+    while let SymType::Ref(ref inner) = *typ {
+        if offset + 8 > memory.len() {
+            bail!("Invalid memory access at {}", offset);
+        }
+        offset = LE::read_u64(&memory[offset..]) as usize;
+        if offset == 0 {
+            bail!("Null reference dereference in {}", path);
+        }
+        typ = inner;
+    }
+    Ok((offset, typ))
+}
+
+/// Split a path like `GVL.aValues[3, 4].x.0` into components.
+fn parse_path<'a>(path: &'a str) -> Result<Vec<Component<'a>>, Error> {
+    let mut result = vec![];
+    let mut rest = path.trim();
+    let ident_end = |s: &str| s.find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                               .unwrap_or(s.len());
+    loop {
+        let n = ident_end(rest);
+        let part = &rest[..n];
+        if part.is_empty() {
+            bail!("Invalid symbol path {}", path);
+        }
+        if result.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) {
+            if part.chars().next().is_some_and(|c| c.is_ascii_digit()) {
+                bail!("Invalid symbol path {}", path);
+            }
+            result.push(Component::Name(part));
+        } else {
+            result.push(Component::Bit(part.parse()?));
+        }
+        rest = &rest[n..];
+        while rest.starts_with('[') {
+            let end = match rest.find(']') {
+                Some(end) => end,
+                None => bail!("Invalid symbol path {}", path),
+            };
+            for index in rest[1..end].split(',') {
+                match index.trim().parse() {
+                    Ok(i) => result.push(Component::Index(i)),
+                    Err(_) => bail!("Invalid array index {} in {}", index, path),
+                }
+            }
+            rest = &rest[end+1..];
+        }
+        if rest.is_empty() {
+            return Ok(result);
+        }
+        if !rest.starts_with('.') {
+            bail!("Invalid symbol path {}", path);
+        }
+        rest = &rest[1..];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use st::compile::tests::run;
+    use st::runtime::Value;
+
+    #[test]
+    fn resolve_paths() {
+        let mut rt = run(&["TYPE Point : STRUCT x : INT; y : INT; END_STRUCT END_TYPE",
+                           "PROGRAM MAIN VAR p : ARRAY[1..3] OF Point; w : WORD := 16#8001; \
+                            r : REFERENCE TO Point; n : REFERENCE TO INT; END_VAR \
+                            p[2].y := 7; r REF= p[2]; END_PROGRAM"]);
+        assert_eq!(rt.read("MAIN.p[2].y").unwrap(), Value::Int(7));
+        assert_eq!(rt.read("main.P[2].Y").unwrap(), Value::Int(7));
+        assert_eq!(rt.read("MAIN.r.y").unwrap(), Value::Int(7));
+        assert_eq!(rt.read("MAIN.w.0").unwrap(), Value::Bool(true));
+        assert_eq!(rt.read("MAIN.w.15").unwrap(), Value::Bool(true));
+        assert_eq!(rt.read("MAIN.w.1").unwrap(), Value::Bool(false));
+        rt.write("MAIN.w.1", Value::Bool(true)).unwrap();
+        assert_eq!(rt.read("MAIN.w").unwrap(), Value::Word(0x8003));
+        rt.write("MAIN.r.x", Value::Int(3)).unwrap();
+        assert_eq!(rt.read("MAIN.p[2].x").unwrap(), Value::Int(3));
+        for path in &["MAIN.p[4]", "MAIN.w.16", "MAIN.w.0.1", "MAIN.q", "MAIN.p[1].z", "MAIN."] {
+            assert!(rt.read(path).is_err(), "{}", path);
+        }
+        let err = rt.read("MAIN.n").err().unwrap();
+        assert_eq!(err.to_string(), "Null reference dereference in MAIN.n");
+    }
+}
@@ -0,0 +1,644 @@
+// *****************************************************************************
+// Charon: Beckhoff TwinCat/ST testing and simulation tools
+// Copyright (c) 2017 by the contributors (see AUTHORS)
+//
+// This program is free software; you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation; either version 2 of the License, or (at your option) any later
+// version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE.  See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program; if not, write to the Free Software Foundation, Inc.,
+// 59 Temple Place, Suite 330, Boston, MA  02111-1307  USA
+//
+// Module authors:
+//   Georg Brandl <g.brandl@fz-juelich.de>
+//
+// *****************************************************************************
+
+//! Typed values as handled by the runtime.
+
+use std::fmt;
+use byteorder::{LE, ByteOrder};
+use failure::Error;
+
+/// The type of a value stored in task memory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ValType {
+    Bool,
+    SInt,
+    Int,
+    DInt,
+    LInt,
+    USInt,
+    UInt,
+    UDInt,
+    ULInt,
+    Byte,
+    Word,
+    DWord,
+    LWord,
+    Real,
+    LReal,
+    /// Milliseconds.
+    Time,
+    /// Nanoseconds.
+    LTime,
+    /// Seconds since 1970-01-01.
+    Date,
+    /// Milliseconds since midnight.
+    Tod,
+    /// Seconds since 1970-01-01.
+    Dt,
+    /// String with maximum length in bytes.
+    String(usize),
+    /// UTF-16 string with maximum length in code units.
+    WString(usize),
+    /// Structured data of the given size, only copied as a whole.
+    Raw(usize),
+}
+
+/// A typed piece of data.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    SInt(i8),
+    Int(i16),
+    DInt(i32),
+    LInt(i64),
+    USInt(u8),
+    UInt(u16),
+    UDInt(u32),
+    ULInt(u64),
+    Byte(u8),
+    Word(u16),
+    DWord(u32),
+    LWord(u64),
+    Real(f32),
+    LReal(f64),
+    Time(u32),
+    LTime(u64),
+    Date(u32),
+    Tod(u32),
+    Dt(u32),
+    String(String),
+    WString(String),
+    Raw(Vec<u8>),
+}
+
+impl ValType {
+    /// Size of a value of this type in memory.
+    pub fn size(&self) -> usize {
+        match *self {
+            ValType::Bool | ValType::SInt | ValType::USInt | ValType::Byte => 1,
+            ValType::Int | ValType::UInt | ValType::Word => 2,
+            ValType::DInt | ValType::UDInt | ValType::DWord | ValType::Real |
+            ValType::Time | ValType::Date | ValType::Tod | ValType::Dt => 4,
+            ValType::LInt | ValType::ULInt | ValType::LWord | ValType::LReal |
+            ValType::LTime => 8,
+            ValType::String(len) => len + 1,
+            ValType::WString(len) => 2 * (len + 1),
+            ValType::Raw(size) => size,
+        }
+    }
+
+    pub fn is_integer(&self) -> bool {
+        matches!(*self,
+                 ValType::SInt | ValType::Int | ValType::DInt | ValType::LInt |
+                 ValType::USInt | ValType::UInt | ValType::UDInt | ValType::ULInt |
+                 ValType::Byte | ValType::Word | ValType::DWord | ValType::LWord)
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(*self,
+                 ValType::SInt | ValType::Int | ValType::DInt | ValType::LInt |
+                 ValType::Real | ValType::LReal)
+    }
+
+    pub fn is_float(&self) -> bool {
+        *self == ValType::Real || *self == ValType::LReal
+    }
+
+    pub fn is_time(&self) -> bool {
+        matches!(*self, ValType::Time | ValType::LTime | ValType::Date | ValType::Tod | ValType::Dt)
+    }
+
+    /// Read a value of this type from memory.
+    pub fn load(&self, mem: &[u8]) -> Value {
+        match *self {
+            ValType::Bool => Value::Bool(mem[0] != 0),
+            ValType::SInt => Value::SInt(mem[0] as i8),
+            ValType::USInt => Value::USInt(mem[0]),
+            ValType::Byte => Value::Byte(mem[0]),
+            ValType::Int => Value::Int(LE::read_i16(mem)),
+            ValType::UInt => Value::UInt(LE::read_u16(mem)),
+            ValType::Word => Value::Word(LE::read_u16(mem)),
+            ValType::DInt => Value::DInt(LE::read_i32(mem)),
+            ValType::UDInt => Value::UDInt(LE::read_u32(mem)),
+            ValType::DWord => Value::DWord(LE::read_u32(mem)),
+            ValType::LInt => Value::LInt(LE::read_i64(mem)),
+            ValType::ULInt => Value::ULInt(LE::read_u64(mem)),
+            ValType::LWord => Value::LWord(LE::read_u64(mem)),
+            ValType::Real => Value::Real(LE::read_f32(mem)),
+            ValType::LReal => Value::LReal(LE::read_f64(mem)),
+            ValType::Time => Value::Time(LE::read_u32(mem)),
+            ValType::LTime => Value::LTime(LE::read_u64(mem)),
+            ValType::Date => Value::Date(LE::read_u32(mem)),
+            ValType::Tod => Value::Tod(LE::read_u32(mem)),
+            ValType::Dt => Value::Dt(LE::read_u32(mem)),
+            ValType::String(len) => Value::String(
+                mem[..len].iter().take_while(|&&b| b != 0).map(|&b| b as char).collect()),
+            ValType::WString(len) => {
+                let units = (0..len).map(|i| LE::read_u16(&mem[2*i..]))
+                                    .take_while(|&u| u != 0).collect::<Vec<_>>();
+                Value::WString(String::from_utf16_lossy(&units))
+            }
+            ValType::Raw(size) => Value::Raw(mem[..size].to_vec()),
+        }
+    }
+
+    /// The initial value of variables of this type.
+    pub fn default_value(&self) -> Value {
+        match *self {
+            ValType::String(_) => Value::String(String::new()),
+            ValType::WString(_) => Value::WString(String::new()),
+            _ => self.load(&vec![0; self.size()]),
+        }
+    }
+}
+
+macro_rules! int_variants {
+    ($a:expr, $b:expr, |$x:ident, $y:ident| $res:expr, $other:expr) => {
+        match ($a, $b) {
+            (Value::SInt($x), Value::SInt($y)) => Value::SInt($res),
+            (Value::Int($x), Value::Int($y)) => Value::Int($res),
+            (Value::DInt($x), Value::DInt($y)) => Value::DInt($res),
+            (Value::LInt($x), Value::LInt($y)) => Value::LInt($res),
+            (Value::USInt($x), Value::USInt($y)) => Value::USInt($res),
+            (Value::UInt($x), Value::UInt($y)) => Value::UInt($res),
+            (Value::UDInt($x), Value::UDInt($y)) => Value::UDInt($res),
+            (Value::ULInt($x), Value::ULInt($y)) => Value::ULInt($res),
+            (Value::Byte($x), Value::Byte($y)) => Value::Byte($res),
+            (Value::Word($x), Value::Word($y)) => Value::Word($res),
+            (Value::DWord($x), Value::DWord($y)) => Value::DWord($res),
+            (Value::LWord($x), Value::LWord($y)) => Value::LWord($res),
+            (Value::Time($x), Value::Time($y)) => Value::Time($res),
+            (Value::LTime($x), Value::LTime($y)) => Value::LTime($res),
+            (Value::Date($x), Value::Date($y)) => Value::Date($res),
+            (Value::Tod($x), Value::Tod($y)) => Value::Tod($res),
+            (Value::Dt($x), Value::Dt($y)) => Value::Dt($res),
+            (a, b) => ($other)(a, b)?,
+        }
+    };
+}
+
+macro_rules! float_variants {
+    ($a:expr, $b:expr, |$x:ident, $y:ident| $res:expr, $other:expr) => {
+        match ($a, $b) {
+            (Value::Real($x), Value::Real($y)) => Value::Real($res),
+            (Value::LReal($x), Value::LReal($y)) => Value::LReal($res),
+            (a, b) => ($other)(a, b)?,
+        }
+    };
+}
+
+macro_rules! arith {
+    ($name:expr, $a:expr, $b:expr, |$x:ident, $y:ident| int: $ires:expr, float: $fres:expr) => {
+        Ok(int_variants!($a, $b, |$x, $y| $ires, |a, b| -> Result<Value, Error> {
+            Ok(float_variants!(a, b, |$x, $y| $fres, |a, b| type_error($name, a, b)))
+        }))
+    };
+}
+
+macro_rules! compare {
+    ($name:expr, $a:expr, $b:expr, |$x:ident, $y:ident| $res:expr) => {
+        Ok(Value::Bool(match ($a, $b) {
+            (Value::Bool($x), Value::Bool($y)) => $res,
+            (Value::SInt($x), Value::SInt($y)) => $res,
+            (Value::Int($x), Value::Int($y)) => $res,
+            (Value::DInt($x), Value::DInt($y)) => $res,
+            (Value::LInt($x), Value::LInt($y)) => $res,
+            (Value::USInt($x), Value::USInt($y)) => $res,
+            (Value::UInt($x), Value::UInt($y)) => $res,
+            (Value::UDInt($x), Value::UDInt($y)) => $res,
+            (Value::ULInt($x), Value::ULInt($y)) => $res,
+            (Value::Byte($x), Value::Byte($y)) => $res,
+            (Value::Word($x), Value::Word($y)) => $res,
+            (Value::DWord($x), Value::DWord($y)) => $res,
+            (Value::LWord($x), Value::LWord($y)) => $res,
+            (Value::Real($x), Value::Real($y)) => $res,
+            (Value::LReal($x), Value::LReal($y)) => $res,
+            (Value::Time($x), Value::Time($y)) => $res,
+            (Value::LTime($x), Value::LTime($y)) => $res,
+            (Value::Date($x), Value::Date($y)) => $res,
+            (Value::Tod($x), Value::Tod($y)) => $res,
+            (Value::Dt($x), Value::Dt($y)) => $res,
+            (Value::String($x), Value::String($y)) => $res,
+            (Value::WString($x), Value::WString($y)) => $res,
+            (a, b) => return type_error($name, a, b),
+        }))
+    };
+}
+
+macro_rules! bitwise {
+    ($name:expr, $a:expr, $b:expr, |$x:ident, $y:ident| $res:expr) => {
+        Ok(match ($a, $b) {
+            (Value::Bool($x), Value::Bool($y)) => Value::Bool($res),
+            (a, b) => int_variants!(a, b, |$x, $y| $res, |a, b| type_error($name, a, b)),
+        })
+    };
+}
+
+fn type_error(op: &str, a: Value, b: Value) -> Result<Value, Error> {
+    bail!("Invalid operand types for {}: {:?} and {:?}", op, a, b)
+}
+
+fn div_by_zero() -> Error {
+    format_err!("Division by zero")
+}
+
+// Operations for the BinOp and UnOp instructions.  The compiler makes sure
+// that both operands have the same type.  These are not operator trait
+// implementations, since the instructions need them as plain function
+// pointers returning a Result.
+
+#[allow(clippy::should_implement_trait, clippy::bool_comparison)]
+impl Value {
+    pub fn add(self, other: Value) -> Result<Value, Error> {
+        arith!("+", self, other, |x, y| int: x.wrapping_add(y), float: x + y)
+    }
+
+    pub fn sub(self, other: Value) -> Result<Value, Error> {
+        arith!("-", self, other, |x, y| int: x.wrapping_sub(y), float: x - y)
+    }
+
+    pub fn mul(self, other: Value) -> Result<Value, Error> {
+        arith!("*", self, other, |x, y| int: x.wrapping_mul(y), float: x * y)
+    }
+
+    pub fn div(self, other: Value) -> Result<Value, Error> {
+        arith!("/", self, other,
+               |x, y| int: x.checked_div(y).ok_or_else(div_by_zero)?, float: x / y)
+    }
+
+    pub fn rem(self, other: Value) -> Result<Value, Error> {
+        arith!("MOD", self, other,
+               |x, y| int: x.checked_rem(y).ok_or_else(div_by_zero)?, float: x % y)
+    }
+
+    pub fn and(self, other: Value) -> Result<Value, Error> {
+        bitwise!("AND", self, other, |x, y| x & y)
+    }
+
+    pub fn or(self, other: Value) -> Result<Value, Error> {
+        bitwise!("OR", self, other, |x, y| x | y)
+    }
+
+    pub fn xor(self, other: Value) -> Result<Value, Error> {
+        bitwise!("XOR", self, other, |x, y| x ^ y)
+    }
+
+    pub fn pow(self, other: Value) -> Result<Value, Error> {
+        Ok(float_variants!(self, other, |x, y| x.powf(y), |a, b| type_error("**", a, b)))
+    }
+
+    pub fn eq(self, other: Value) -> Result<Value, Error> {
+        compare!("=", self, other, |x, y| x == y)
+    }
+
+    pub fn ne(self, other: Value) -> Result<Value, Error> {
+        compare!("<>", self, other, |x, y| x != y)
+    }
+
+    pub fn lt(self, other: Value) -> Result<Value, Error> {
+        compare!("<", self, other, |x, y| x < y)
+    }
+
+    pub fn le(self, other: Value) -> Result<Value, Error> {
+        compare!("<=", self, other, |x, y| x <= y)
+    }
+
+    pub fn gt(self, other: Value) -> Result<Value, Error> {
+        compare!(">", self, other, |x, y| x > y)
+    }
+
+    pub fn ge(self, other: Value) -> Result<Value, Error> {
+        compare!(">=", self, other, |x, y| x >= y)
+    }
+
+    pub fn neg(self) -> Result<Value, Error> {
+        Ok(match self {
+            Value::SInt(x) => Value::SInt(x.wrapping_neg()),
+            Value::Int(x) => Value::Int(x.wrapping_neg()),
+            Value::DInt(x) => Value::DInt(x.wrapping_neg()),
+            Value::LInt(x) => Value::LInt(x.wrapping_neg()),
+            Value::Real(x) => Value::Real(-x),
+            Value::LReal(x) => Value::LReal(-x),
+            v => bail!("Invalid operand type for negation: {:?}", v),
+        })
+    }
+
+    pub fn not(self) -> Result<Value, Error> {
+        Ok(match self {
+            Value::Bool(x) => Value::Bool(!x),
+            Value::SInt(x) => Value::SInt(!x),
+            Value::Int(x) => Value::Int(!x),
+            Value::DInt(x) => Value::DInt(!x),
+            Value::LInt(x) => Value::LInt(!x),
+            Value::USInt(x) => Value::USInt(!x),
+            Value::UInt(x) => Value::UInt(!x),
+            Value::UDInt(x) => Value::UDInt(!x),
+            Value::ULInt(x) => Value::ULInt(!x),
+            Value::Byte(x) => Value::Byte(!x),
+            Value::Word(x) => Value::Word(!x),
+            Value::DWord(x) => Value::DWord(!x),
+            Value::LWord(x) => Value::LWord(!x),
+            v => bail!("Invalid operand type for NOT: {:?}", v),
+        })
+    }
+}
+
+impl Value {
+    pub fn val_type(&self) -> ValType {
+        match *self {
+            Value::Bool(_) => ValType::Bool,
+            Value::SInt(_) => ValType::SInt,
+            Value::Int(_) => ValType::Int,
+            Value::DInt(_) => ValType::DInt,
+            Value::LInt(_) => ValType::LInt,
+            Value::USInt(_) => ValType::USInt,
+            Value::UInt(_) => ValType::UInt,
+            Value::UDInt(_) => ValType::UDInt,
+            Value::ULInt(_) => ValType::ULInt,
+            Value::Byte(_) => ValType::Byte,
+            Value::Word(_) => ValType::Word,
+            Value::DWord(_) => ValType::DWord,
+            Value::LWord(_) => ValType::LWord,
+            Value::Real(_) => ValType::Real,
+            Value::LReal(_) => ValType::LReal,
+            Value::Time(_) => ValType::Time,
+            Value::LTime(_) => ValType::LTime,
+            Value::Date(_) => ValType::Date,
+            Value::Tod(_) => ValType::Tod,
+            Value::Dt(_) => ValType::Dt,
+            Value::String(ref s) => ValType::String(s.len()),
+            Value::WString(ref s) => ValType::WString(s.encode_utf16().count()),
+            Value::Raw(ref v) => ValType::Raw(v.len()),
+        }
+    }
+
+    /// Write the value to memory, in the representation of the given type.
+    ///
+    /// Strings are truncated to the maximum length.
+    pub fn store(&self, typ: ValType, mem: &mut [u8]) -> Result<(), Error> {
+        match (self, typ) {
+            (&Value::Bool(x), ValType::Bool) => mem[0] = x as u8,
+            (&Value::SInt(x), ValType::SInt) => mem[0] = x as u8,
+            (&Value::USInt(x), ValType::USInt) |
+            (&Value::Byte(x), ValType::Byte) => mem[0] = x,
+            (&Value::Int(x), ValType::Int) => LE::write_i16(mem, x),
+            (&Value::UInt(x), ValType::UInt) |
+            (&Value::Word(x), ValType::Word) => LE::write_u16(mem, x),
+            (&Value::DInt(x), ValType::DInt) => LE::write_i32(mem, x),
+            (&Value::UDInt(x), ValType::UDInt) |
+            (&Value::DWord(x), ValType::DWord) |
+            (&Value::Time(x), ValType::Time) |
+            (&Value::Date(x), ValType::Date) |
+            (&Value::Tod(x), ValType::Tod) |
+            (&Value::Dt(x), ValType::Dt) => LE::write_u32(mem, x),
+            (&Value::LInt(x), ValType::LInt) => LE::write_i64(mem, x),
+            (&Value::ULInt(x), ValType::ULInt) |
+            (&Value::LWord(x), ValType::LWord) |
+            (&Value::LTime(x), ValType::LTime) => LE::write_u64(mem, x),
+            (&Value::Real(x), ValType::Real) => LE::write_f32(mem, x),
+            (&Value::LReal(x), ValType::LReal) => LE::write_f64(mem, x),
+            (Value::String(s), ValType::String(len)) => {
+                let mut n = 0;
+                for ch in s.chars().take(len) {
+                    mem[n] = if (ch as u32) < 256 { ch as u8 } else { b'?' };
+                    n += 1;
+                }
+                mem[n] = 0;
+            }
+            (Value::WString(s), ValType::WString(len)) => {
+                let mut n = 0;
+                for unit in s.encode_utf16().take(len) {
+                    LE::write_u16(&mut mem[2*n..], unit);
+                    n += 1;
+                }
+                LE::write_u16(&mut mem[2*n..], 0);
+            }
+            (Value::Raw(v), ValType::Raw(size)) if v.len() == size => {
+                mem[..size].copy_from_slice(v);
+            }
+            (v, t) => bail!("Cannot store value {:?} as {:?}", v, t),
+        }
+        Ok(())
+    }
+
+    pub fn as_bool(&self) -> Result<bool, Error> {
+        match *self {
+            Value::Bool(b) => Ok(b),
+            ref v => bail!("Expected a boolean value, got {:?}", v)
+        }
+    }
+
+    /// Get the value of an integer (or time) value.
+    pub fn as_int(&self) -> Option<i128> {
+        Some(match *self {
+            Value::Bool(x) => x as i128,
+            Value::SInt(x) => x as i128,
+            Value::Int(x) => x as i128,
+            Value::DInt(x) => x as i128,
+            Value::LInt(x) => x as i128,
+            Value::USInt(x) | Value::Byte(x) => x as i128,
+            Value::UInt(x) | Value::Word(x) => x as i128,
+            Value::UDInt(x) | Value::DWord(x) | Value::Time(x) |
+            Value::Date(x) | Value::Tod(x) | Value::Dt(x) => x as i128,
+            Value::ULInt(x) | Value::LWord(x) | Value::LTime(x) => x as i128,
+            _ => return None
+        })
+    }
+
+    fn as_float(&self) -> Option<f64> {
+        match *self {
+            Value::Real(x) => Some(x as f64),
+            Value::LReal(x) => Some(x),
+            _ => self.as_int().map(|i| i as f64),
+        }
+    }
+
+    /// Create an integer-like value, wrapping around if out of range.
+    pub fn from_int(typ: ValType, i: i128) -> Result<Value, Error> {
+        Ok(match typ {
+            ValType::Bool => Value::Bool(i != 0),
+            ValType::SInt => Value::SInt(i as i8),
+            ValType::Int => Value::Int(i as i16),
+            ValType::DInt => Value::DInt(i as i32),
+            ValType::LInt => Value::LInt(i as i64),
+            ValType::USInt => Value::USInt(i as u8),
+            ValType::UInt => Value::UInt(i as u16),
+            ValType::UDInt => Value::UDInt(i as u32),
+            ValType::ULInt => Value::ULInt(i as u64),
+            ValType::Byte => Value::Byte(i as u8),
+            ValType::Word => Value::Word(i as u16),
+            ValType::DWord => Value::DWord(i as u32),
+            ValType::LWord => Value::LWord(i as u64),
+            ValType::Real => Value::Real(i as f32),
+            ValType::LReal => Value::LReal(i as f64),
+            ValType::Time => Value::Time(i as u32),
+            ValType::LTime => Value::LTime(i as u64),
+            ValType::Date => Value::Date(i as u32),
+            ValType::Tod => Value::Tod(i as u32),
+            ValType::Dt => Value::Dt(i as u32),
+            ValType::String(_) => Value::String(i.to_string()),
+            ValType::WString(_) => Value::WString(i.to_string()),
+            ValType::Raw(_) => bail!("Cannot convert integer to structured value"),
+        })
+    }
+
+    /// Convert the value to another type, as the `X_TO_Y` functions do.
+    pub fn convert(self, typ: ValType) -> Result<Value, Error> {
+        if self.val_type() == typ {
+            return Ok(self);
+        }
+        // time types have different units, so go through nanoseconds
+        let time_scale = |t: ValType| match t {
+            ValType::Time | ValType::Tod => Some(1_000_000),
+            ValType::LTime => Some(1),
+            ValType::Date | ValType::Dt => Some(1_000_000_000),
+            _ => None
+        };
+        if let (Some(from), Some(to)) = (time_scale(self.val_type()), time_scale(typ)) {
+            let ns = self.as_int().unwrap() * from;
+            return Value::from_int(typ, ns / to);
+        }
+        Ok(match self {
+            Value::String(s) | Value::WString(s) => match typ {
+                ValType::String(_) => Value::String(s),
+                ValType::WString(_) => Value::WString(s),
+                ValType::Bool => Value::Bool(s.trim() == "TRUE" || s.trim() == "1"),
+                ValType::Real => Value::Real(s.trim().parse().unwrap_or(0.)),
+                ValType::LReal => Value::LReal(s.trim().parse().unwrap_or(0.)),
+                _ => Value::from_int(typ, s.trim().parse().unwrap_or(0))?,
+            },
+            Value::Raw(_) => bail!("Cannot convert structured value"),
+            ref v if v.val_type().is_float() => {
+                let f = v.as_float().unwrap();
+                match typ {
+                    ValType::Real => Value::Real(f as f32),
+                    ValType::LReal => Value::LReal(f),
+                    ValType::String(_) => Value::String(format!("{}", f)),
+                    ValType::WString(_) => Value::WString(format!("{}", f)),
+                    // conversion to integer rounds
+                    _ => Value::from_int(typ, f.round() as i128)?,
+                }
+            }
+            ref v => {
+                if let ValType::String(_) = typ {
+                    return Ok(Value::String(format!("{}", v)));
+                }
+                Value::from_int(typ, v.as_int().unwrap())?
+            }
+        })
+    }
+
+    /// Extract a single bit.
+    pub fn bit(&self, bit: usize) -> Result<Value, Error> {
+        match self.as_int() {
+            Some(i) => Ok(Value::Bool((i >> bit) & 1 != 0)),
+            None => bail!("Invalid bit access on {:?}", self),
+        }
+    }
+
+    /// Set a single bit.
+    pub fn set_bit(self, bit: usize, val: bool) -> Result<Value, Error> {
+        let typ = self.val_type();
+        match self.as_int() {
+            Some(i) => {
+                let i = if val { i | (1 << bit) } else { i & !(1 << bit) };
+                Value::from_int(typ, i)
+            }
+            None => bail!("Invalid bit access on {:?}", self),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Value::Bool(b) => f.write_str(if b { "TRUE" } else { "FALSE" }),
+            Value::Real(x) => write!(f, "{}", x),
+            Value::LReal(x) => write!(f, "{}", x),
+            Value::Time(x) => write!(f, "T#{}ms", x),
+            Value::LTime(x) => write!(f, "LTIME#{}ns", x),
+            Value::String(ref s) => write!(f, "'{}'", s),
+            Value::WString(ref s) => write!(f, "\"{}\"", s),
+            Value::Raw(ref v) => write!(f, "<{} bytes>", v.len()),
+            ref v => write!(f, "{}", v.as_int().unwrap()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(Value::SInt(127).add(Value::SInt(1)).unwrap(), Value::SInt(-128));
+        assert_eq!(Value::UInt(0).sub(Value::UInt(1)).unwrap(), Value::UInt(65535));
+        assert_eq!(Value::DInt(-7).div(Value::DInt(2)).unwrap(), Value::DInt(-3));
+        assert_eq!(Value::DInt(-7).rem(Value::DInt(2)).unwrap(), Value::DInt(-1));
+        assert_eq!(Value::LReal(1.0).div(Value::LReal(4.0)).unwrap(), Value::LReal(0.25));
+        assert_eq!(Value::Time(1000).add(Value::Time(500)).unwrap(), Value::Time(1500));
+        assert_eq!(Value::Word(0xff0f).and(Value::Word(0x0ff0)).unwrap(), Value::Word(0x0f00));
+        assert_eq!(Value::Bool(true).xor(Value::Bool(true)).unwrap(), Value::Bool(false));
+        assert_eq!(Value::Int(3).lt(Value::Int(4)).unwrap(), Value::Bool(true));
+        assert_eq!(Value::String("a".into()).eq(Value::String("a".into())).unwrap(),
+                   Value::Bool(true));
+        assert_eq!(Value::DInt(1).div(Value::DInt(0)).unwrap_err().to_string(),
+                   "Division by zero");
+        assert!(Value::Int(1).add(Value::DInt(1)).is_err());
+        assert!(Value::Bool(true).add(Value::Bool(true)).is_err());
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Value::DInt(300).convert(ValType::USInt).unwrap(), Value::USInt(44));
+        assert_eq!(Value::LReal(2.5).convert(ValType::Int).unwrap(), Value::Int(3));
+        assert_eq!(Value::Int(-1).convert(ValType::Word).unwrap(), Value::Word(0xffff));
+        assert_eq!(Value::Time(1500).convert(ValType::LTime).unwrap(),
+                   Value::LTime(1_500_000_000));
+        assert_eq!(Value::Dt(86_400 + 60).convert(ValType::Tod).unwrap(), Value::Tod(86_460_000));
+        assert_eq!(Value::Int(42).convert(ValType::String(80)).unwrap(),
+                   Value::String("42".into()));
+        assert_eq!(Value::String(" 12".into()).convert(ValType::DInt).unwrap(), Value::DInt(12));
+        assert_eq!(Value::UDInt(0x8000_0001).bit(31).unwrap(), Value::Bool(true));
+        assert_eq!(Value::Byte(1).set_bit(7, true).unwrap(), Value::Byte(0x81));
+    }
+
+    #[test]
+    fn memory_representation() {
+        let mut mem = [0xffu8; 12];
+        Value::Int(-2).store(ValType::Int, &mut mem).unwrap();
+        assert_eq!(&mem[..3], &[0xfe, 0xff, 0xff]);
+        assert_eq!(ValType::Int.load(&mem), Value::Int(-2));
+        Value::String("abcdef".into()).store(ValType::String(3), &mut mem).unwrap();
+        assert_eq!(&mem[..5], b"abc\0\xff");
+        assert_eq!(ValType::String(3).load(&mem), Value::String("abc".into()));
+        Value::WString("\u{20ac}".into()).store(ValType::WString(4), &mut mem).unwrap();
+        assert_eq!(&mem[..4], &[0xac, 0x20, 0, 0]);
+        assert_eq!(ValType::WString(4).load(&mem), Value::WString("\u{20ac}".into()));
+        Value::LReal(0.5).store(ValType::LReal, &mut mem).unwrap();
+        assert_eq!(ValType::LReal.load(&mem), Value::LReal(0.5));
+        assert!(Value::Int(1).store(ValType::DInt, &mut mem).is_err());
+        assert_eq!(ValType::String(80).size(), 81);
+        assert_eq!(ValType::WString(80).size(), 162);
+    }
+}